@@ -0,0 +1,248 @@
+# shellchecker のドイツ語メッセージ
+
+## Severity levels
+severity-error = FEHLER
+severity-warning = WARNUNG
+severity-info = HINWEIS
+
+## Summary
+no-issues = ✓ Keine Probleme gefunden
+summary = Zusammenfassung: { $errors } Fehler, { $warnings ->
+        [one] { $warnings } Warnung
+       *[other] { $warnings } Warnungen
+    }, { $infos ->
+        [one] { $infos } Hinweis
+       *[other] { $infos } Hinweise
+    }
+
+## Categories
+cat-syntax = Syntax
+cat-best-practice = Bewährte Praxis
+cat-security = Sicherheit
+cat-style = Stil
+
+## Syntax
+msg-missing-shebang = Shebang-Zeile fehlt (#!/bin/bash oder #!/bin/sh)
+msg-invalid-shebang = Die Shebang-Zeile gibt weder bash noch sh an
+msg-unmatched-bracket = Schließende Klammer ']' ohne Gegenstück
+msg-unclosed-bracket = Nicht geschlossene Klammer '['
+msg-unclosed-brace = Nicht geschlossene geschweifte Klammer {"'{'"}
+msg-unclosed-paren = Nicht geschlossene runde Klammer '('
+msg-unclosed-single-quote = Nicht geschlossenes einfaches Anführungszeichen
+msg-unclosed-double-quote = Nicht geschlossenes doppeltes Anführungszeichen
+msg-unclosed-var-expansion = Nicht geschlossene Variablenexpansion {"${...}"}
+msg-unclosed-cmd-subst = Nicht geschlossene Befehlsersetzung $(...)
+msg-bashism = Bash-spezifische Syntax '{ $construct }' in einem POSIX-sh-Skript
+
+## Best practice
+msg-use-set-e = Verwenden Sie 'set -e', um bei Fehlern abzubrechen
+msg-use-set-u = Verwenden Sie 'set -u', um nicht gesetzte Variablen als Fehler zu behandeln
+msg-use-set-pipefail = Verwenden Sie 'set -o pipefail', um Fehler in Pipelines zu erkennen
+msg-unquoted-variable = Variable ohne Anführungszeichen - verwenden Sie "$variable", um die Worttrennung zu verhindern
+msg-cd-without-check = cd-Befehl ohne Fehlerprüfung - verwenden Sie 'cd dir || exit 1'
+msg-use-dollar-paren = Verwenden Sie $(...) statt Backticks für die Befehlsersetzung
+
+## Security
+msg-eval-dangerous = Die Verwendung von 'eval' ist gefährlich - vermeiden Sie dynamische Codeausführung
+msg-curl-pipe-sh = curl/wget direkt an eine Shell weiterzuleiten ist gefährlich - erst herunterladen und prüfen
+msg-dangerous-rm = Gefährliches rm -rf mit einer Variablen oder dem Wurzelpfad - fügen Sie eine Prüfung hinzu
+msg-user-input-in-cmd = Benutzereingaben werden zur Befehlsausführung verwendet - Eingaben prüfen und bereinigen
+msg-expression-injection = Der Ausdruck '{ $expression }' wird vor der Ausführung in das Skript eingesetzt und kann Code-Injektion ermöglichen - übergeben Sie ihn über eine Umgebungsvariable
+
+## Style
+msg-use-spaces = Verwenden Sie Leerzeichen statt Tabulatoren zur Einrückung
+msg-inconsistent-indent = Uneinheitliche Einrückung - verwenden Sie ein Vielfaches von { $width } Leerzeichen
+msg-line-too-long = Zeile zu lang ({ $length } > { $max } Zeichen)
+msg-function-naming = Der Funktionsname '{ $name }' sollte snake_case verwenden (Kleinbuchstaben mit Unterstrichen)
+msg-variable-naming = Die lokale Variable '{ $name }' sollte Kleinbuchstaben mit Unterstrichen verwenden
+
+## Baseline
+baseline-fixed = Inzwischen behobene Einträge der Baseline:
+baseline-written = Baseline nach { $path } geschrieben ({ $count ->
+        [one] { $count } Problem
+       *[other] { $count } Probleme
+    })
+baseline-suppressed = { $count ->
+        [one] { $count } bestehendes Problem
+       *[other] { $count } bestehende Probleme
+    } durch die Baseline unterdrückt
+
+## Script detection
+reason-shebang = Shebang
+reason-modeline = Modeline
+reason-file-name = Dateiname
+reason-extension = Dateiendung
+reason-default = kein Hinweis auf die Shell, als bash geprüft
+reason-embedded = eingebettet in { $format }
+reason-config = in der Konfigurationsdatei festgelegt
+skipped = übersprungen
+
+## Git hook
+hook-installed = Pre-commit-Hook installiert: { $path }
+
+## Watch mode
+watch-last-checked = Zuletzt geprüft
+watch-waiting = Warte auf Änderungen... (Strg+C zum Beenden)
+
+## Quick fixes and editor actions
+fix-dollar-paren = Backticks durch $(...) ersetzen
+fix-cd-exit = '|| exit 1' nach cd einfügen
+fix-quote-variable = Variable in Anführungszeichen setzen
+fix-tabs = Tabulatoren durch Leerzeichen ersetzen
+fix-insert = '{ $text }' einfügen
+fix-replace = Durch '{ $text }' ersetzen
+fix-disable-line = { $rule } für diese Zeile deaktivieren
+fix-disable-file = { $rule } für diese Datei deaktivieren
+
+## Command line
+checking = Prüfe: { $path }
+error-invalid-config = Fehler: Ungültige Konfiguration { $error }
+error-unknown-rule = Fehler: Unbekannte Regel '{ $rule }' (siehe --list-rules)
+error-invalid-glob = Fehler: Ungültiges Glob-Muster: { $error }
+error-git-changes = Fehler: Änderungen seit '{ $rev }' konnten nicht ermittelt werden: { $error }
+error-path-not-found = Fehler: Pfad existiert nicht: { $path }
+error-reading-file = Fehler beim Lesen der Datei { $path }: { $error }
+error-reading-stdin = Fehler beim Lesen der Standardeingabe: { $error }
+error-walking-directory = Fehler beim Durchsuchen des Verzeichnisses: { $error }
+error-reading-baseline = Fehler beim Lesen der Baseline { $path }: { $error }
+error-writing-baseline = Fehler beim Schreiben der Baseline { $path }: { $error }
+error-staged-files = Fehler: Vorgemerkte Dateien konnten nicht ermittelt werden: { $error }
+error-reading-staged-file = Fehler beim Lesen der vorgemerkten Datei { $path }: { $error }
+error-hook-exists = Fehler: { $path } existiert bereits. Verwenden Sie --force zum Überschreiben.
+error-not-git-repository = Fehler: Kein Git-Repository: { $error }
+error-writing-hook = Fehler beim Schreiben des Hooks { $path }: { $error }
+error-watch = Fehler: Dateien können nicht überwacht werden: { $error }
+error-catalog = Warnung: Ungültiger Nachrichtenkatalog { $path }: { $error }
+error-unsupported-language = Fehler: Nicht unterstützte Sprache '{ $language }'. Verfügbare Sprachen: { $available }.
+error-language-server = Fehler: Der Sprachserver ist fehlgeschlagen: { $error }
+
+## Configuration file
+config-unsupported-language = { $path }: nicht unterstützte Sprache '{ $language }' (verfügbar: { $available })
+config-unknown-rule = { $path }: unbekannte Regel '{ $rule }'
+config-invalid-custom-rule = { $path }: ungültige benutzerdefinierte Regel '{ $rule }' - die ID muss eindeutig sein, darf nur Buchstaben, Ziffern, '-' und '_' enthalten und keine eingebaute Regel benennen, und genau eines von 'pattern' oder 'regex' muss angegeben sein
+config-invalid-regex = { $path }: ungültiger regulärer Ausdruck in der benutzerdefinierten Regel '{ $rule }': { $error }
+
+## Command-line arguments
+error-unexpected-argument = Fehler: Unerwartetes Argument '{ $arg }'
+error-missing-value = Fehler: '{ $arg }' erfordert einen Wert, aber es wurde keiner angegeben
+error-invalid-value = Fehler: Ungültiger Wert '{ $value }' für '{ $arg }'
+error-unexpected-value = Fehler: Unerwarteter Wert '{ $value }' für '{ $arg }'
+error-missing-arguments = Fehler: Die folgenden erforderlichen Argumente wurden nicht angegeben:
+error-argument-conflict = Fehler: Das Argument '{ $arg }' kann nicht zusammen mit '{ $other }' verwendet werden
+error-argument-repeated = Fehler: Das Argument '{ $arg }' kann nicht mehrfach verwendet werden
+error-unknown-subcommand = Fehler: Unbekannter Unterbefehl '{ $subcommand }'
+possible-values = Mögliche Werte: { $values }
+did-you-mean = Tipp: Meinten Sie '{ $suggestion }'?
+try-help = Weitere Informationen erhalten Sie mit '--help'.
+
+## Help
+help-about = Prüfprogramm für Bash-Skripte
+help-version-info = shellchecker Version { $version }
+help-usage = Verwendung
+help-commands = Befehle
+help-arguments = Argumente
+help-options = Optionen
+help-help = Hilfe anzeigen
+help-version = Version anzeigen
+help-help-command = Diese Hilfe oder die Hilfe der angegebenen Unterbefehle anzeigen
+help-hook = Den Git-pre-commit-Hook verwalten
+help-hook-install = Einen Git-pre-commit-Hook installieren, der vorgemerkte Shell-Dateien prüft
+help-hook-install-force = Einen vorhandenen pre-commit-Hook überschreiben
+help-hook-install-args = Zusätzliche Optionen, die der Hook an shellchecker übergibt
+help-lsp = Einen Sprachserver (LSP) über stdio ausführen
+help-explain = Eine Regel erklären: Begründung, Beispiele und wie sie unterdrückt wird
+help-explain-rule = Regel-ID (siehe --list-rules)
+help-explain-markdown = Eine Markdown-Referenz aller Regeln ausgeben
+help-paths = Pfade zu Bash-Skripten oder Verzeichnissen ('-' liest ein Skript von der Standardeingabe)
+help-stdin-filename = Dateiname für Berichte und die Konfigurationssuche beim Lesen von der Standardeingabe
+help-recursive = Verzeichnisse rekursiv durchsuchen
+help-no-ignore = .gitignore- und .ignore-Dateien beim Durchsuchen von Verzeichnissen nicht beachten
+help-hidden = Versteckte Dateien und Verzeichnisse durchsuchen
+help-include = Nur gefundene Dateien prüfen, die diesem Glob entsprechen (wiederholbar)
+help-exclude = Dateien und Verzeichnisse überspringen, die diesem Glob entsprechen (wiederholbar)
+help-list-files = Die zu prüfenden Dateien mit der erkannten Shell und dem Grund auflisten
+help-list-rules = Alle Regeln mit Kategorie und Standard-Schweregrad auflisten
+help-changed-since = Nur Shell-Dateien prüfen, die seit der angegebenen Git-Revision geändert wurden
+help-diff-lines-only = Nur Probleme in Zeilen melden, die seit --changed-since geändert wurden
+help-staged = Die vorgemerkte Version (Index) vorgemerkter Shell-Dateien prüfen
+help-watch = Weiterlaufen und Skripte bei Änderungen erneut prüfen
+help-jobs = Anzahl der parallel geprüften Dateien [Standard: Anzahl der CPUs]
+help-severity = Minimaler gemeldeter Schweregrad (error, warning, info) [Standard: info]
+help-errors-only = Nur Fehler anzeigen (wie --severity error)
+help-category = Nur diese Kategorien melden (syntax, best-practice, security, style)
+help-exclude-category = Diese Kategorien nicht melden
+help-rule = Nur diese Regeln melden
+help-exclude-rule = Diese Regeln nicht melden
+help-profile = Von einem eingebauten Regelprofil ausgehen (strict, recommended, minimal, security) [Standard: extends aus .shellchecker.toml, sonst recommended]
+help-language = Ausgabesprache (en, ja, zh-Hans, ko, de, es oder ein Katalog in $SHELLCHECKER_LOCALE_DIR) [Standard: aus .shellchecker.toml, dann LC_ALL / LC_MESSAGES / LANG, sonst en]
+help-baseline = In der Baseline-Datei erfasste Probleme unterdrücken und nur neue melden
+help-write-baseline = Alle aktuellen Probleme in einer Baseline-Datei erfassen
+help-fail-on = Minimaler Schweregrad, bei dem der Lauf fehlschlägt (error, warning, info, none) [Standard: error]
+help-max-warnings = Fehlschlagen, wenn mehr als N Warnungen gefunden werden
+
+## Rule documentation
+doc-rules-title = Regeln
+doc-rule = Regel
+doc-category = Kategorie
+doc-severity = Standard-Schweregrad
+doc-rationale = Warum
+doc-bad = Problematisch
+doc-good = Korrigiert
+doc-suppress = Unterdrücken
+doc-suppress-line = die nächste Zeile
+doc-suppress-file = die gesamte Datei
+doc-suppress-config = alle Dateien im Verzeichnis
+doc-missing-shebang-summary = Das Skript hat keine Shebang-Zeile
+doc-missing-shebang-rationale = Ohne Shebang läuft das Skript mit der Shell, die es aufruft, sodass bash-spezifischer Code fehlschlagen kann, wenn es aus sh oder einer anderen Shell gestartet wird.
+doc-invalid-shebang-summary = Die Shebang-Zeile nennt weder bash noch sh
+doc-invalid-shebang-rationale = Eine Shebang-Zeile mit einem unbekannten Interpreter ist meist ein Tippfehler im Pfad, wodurch das Skript nicht starten kann.
+doc-unmatched-bracket-summary = Zu einer schließenden ']' gibt es keine '['
+doc-unmatched-bracket-rationale = Eine Klammer ohne Gegenstück ist meist ein falsch geschriebener test-Ausdruck, der beim Ausführen der Zeile mit einem Syntaxfehler scheitert.
+doc-unclosed-bracket-summary = Eine '[' wird nicht geschlossen
+doc-unclosed-bracket-rationale = Der test-Befehl verlangt eine schließende ']', daher schlägt die Bedingung mit einem Fehler fehl, statt ausgewertet zu werden.
+doc-unclosed-brace-summary = Eine {"'{'"} wird nicht geschlossen
+doc-unclosed-brace-rationale = Ein Funktionsrumpf oder eine Befehlsgruppe ohne schließende Klammer verschluckt den Rest der Datei, und die Shell meldet ein unerwartetes Dateiende.
+doc-unclosed-paren-summary = Eine '(' wird nicht geschlossen
+doc-unclosed-paren-rationale = Eine nicht geschlossene Subshell oder Array-Zuweisung lässt die Shell die folgenden Zeilen als Teil davon lesen, bis sie mit einem Syntaxfehler scheitert.
+doc-unclosed-single-quote-summary = Eine Zeichenkette in einfachen Anführungszeichen wird nicht geschlossen
+doc-unclosed-single-quote-rationale = Eine Zeichenkette in einfachen Anführungszeichen reicht auch über Zeilen hinweg bis zum nächsten einfachen Anführungszeichen, sodass der folgende Code Teil der Zeichenkette wird.
+doc-unclosed-double-quote-summary = Eine Zeichenkette in doppelten Anführungszeichen wird nicht geschlossen
+doc-unclosed-double-quote-rationale = Eine Zeichenkette in doppelten Anführungszeichen reicht auch über Zeilen hinweg bis zum nächsten doppelten Anführungszeichen, sodass der folgende Code Teil der Zeichenkette wird.
+doc-unclosed-var-expansion-summary = Eine {"'${'"}-Expansion wird nicht geschlossen
+doc-unclosed-var-expansion-rationale = Die Shell weist eine Parameterexpansion ohne schließende Klammer mit dem Fehler bad substitution zurück.
+doc-unclosed-cmd-subst-summary = Eine '$('-Befehlsersetzung wird nicht geschlossen
+doc-unclosed-cmd-subst-rationale = Eine nicht geschlossene Befehlsersetzung lässt die Shell die folgenden Zeilen als Teil des Befehls lesen, bis sie mit einem Syntaxfehler scheitert.
+doc-bashism-summary = In einem POSIX-sh-Skript wird bash-spezifische Syntax verwendet
+doc-bashism-rationale = /bin/sh ist auf vielen Systemen dash oder busybox sh, die bash-Erweiterungen wie '[[ ]]', Arrays oder 'function' nicht unterstützen. Verwenden Sie POSIX-Syntax oder ändern Sie die Shebang-Zeile auf bash.
+doc-missing-set-e-summary = Das Skript bricht nicht ab, wenn ein Befehl fehlschlägt
+doc-missing-set-e-rationale = Standardmäßig läuft ein Skript nach einem fehlgeschlagenen Befehl weiter, sodass spätere Befehle mit fehlenden Dateien oder unvollständigen Ergebnissen arbeiten. 'set -e' beendet das Skript beim ersten Fehler.
+doc-missing-set-u-summary = Nicht gesetzte Variablen werden nicht als Fehler behandelt
+doc-missing-set-u-rationale = Eine falsch geschriebene oder nicht gesetzte Variable wird stillschweigend zu einer leeren Zeichenkette, wodurch aus 'rm -rf "$build_dir/"' ein 'rm -rf /' werden kann. Mit 'set -u' ist eine solche Expansion ein Fehler.
+doc-missing-pipefail-summary = Fehler innerhalb von Pipelines werden ignoriert
+doc-missing-pipefail-rationale = Der Exit-Status einer Pipeline ist der ihres letzten Befehls, daher ist 'curl ... | tar xz' auch dann erfolgreich, wenn der Download fehlschlägt. 'set -o pipefail' lässt die Pipeline fehlschlagen, wenn ein beliebiger Befehl fehlschlägt. Für POSIX sh, das diese Option nicht kennt, wird dies nicht gemeldet.
+doc-unquoted-variable-summary = Eine Variable wird ohne Anführungszeichen expandiert
+doc-unquoted-variable-rationale = Eine Expansion ohne Anführungszeichen wird an Leerraum getrennt und als Glob-Muster expandiert, sodass ein Wert mit einem Leerzeichen oder '*' den Befehl zerstört oder andere Dateien betrifft.
+doc-cd-without-check-summary = Das Ergebnis von cd wird nicht geprüft
+doc-cd-without-check-rationale = Wenn cd fehlschlägt, läuft das Skript im aktuellen Verzeichnis weiter, und Befehle für das Zielverzeichnis wie 'rm -rf ./*' laufen am falschen Ort.
+doc-backtick-substitution-summary = Die Befehlsersetzung verwendet Backticks
+doc-backtick-substitution-rationale = Backticks sind schwer zu lesen, müssen zum Verschachteln maskiert werden und behandeln Backslashes besonders. '$(...)' lässt sich sauber verschachteln und verhält sich einheitlich.
+doc-eval-usage-summary = eval wird verwendet
+doc-eval-usage-rationale = eval führt seine Argumente als Code aus, sodass alle Daten, die es erreichen, etwa Dateinamen, Eingaben oder Befehlsausgaben, beliebige Befehle ausführen können. Arrays, 'case' oder indirekte Expansion erledigen die Aufgabe meist sicher.
+doc-curl-pipe-shell-summary = Ein heruntergeladenes Skript wird an eine Shell weitergeleitet
+doc-curl-pipe-shell-rationale = Das Skript läuft, bevor jemand es prüfen kann, ein abgebrochener Download kann ein halbes Skript ausführen, und ein kompromittierter Server führt Code auf jeder Maschine aus. Laden Sie es in eine Datei, prüfen Sie es und führen Sie es dann aus.
+doc-dangerous-rm-summary = rm -rf wird auf eine Variable oder den Wurzelpfad angewendet
+doc-dangerous-rm-rationale = Ist die Variable leer oder nicht gesetzt, löscht 'rm -rf "$dir"/*' alles unter /. Verwenden Sie {"'${dir:?}'"}, um bei leerer Variable abzubrechen.
+doc-user-input-in-command-summary = Skriptargumente oder Eingaben werden in eval oder einer Befehlsersetzung verwendet
+doc-user-input-in-command-rationale = Argumente und Eingaben stammen vom Aufrufer, und wenn sie in Code eingefügt werden, den eval oder eine Befehlsersetzung ausführt, können sie beliebige Befehle ausführen. Prüfen Sie den Wert und übergeben Sie ihn als separates Argument in Anführungszeichen.
+doc-expression-injection-summary = Ein nicht vertrauenswürdiger Ausdruck wird in ein eingebettetes Skript eingesetzt
+doc-expression-injection-rationale = CI-Ausdrücke wie {"'${{"} github.event.issue.title {"}}'"} und Groovy-Interpolation werden vor der Ausführung der Shell in den Skripttext eingesetzt, sodass jeder, der den Wert kontrolliert, Befehle einschleusen kann. Übergeben Sie den Wert über eine Umgebungsvariable und setzen Sie ihn in Anführungszeichen.
+doc-tab-indentation-summary = Die Einrückung verwendet Tabulatoren
+doc-tab-indentation-rationale = Tabulatoren werden in jedem Editor unterschiedlich breit dargestellt, daher wirkt gemischte Einrückung aus Tabulatoren und Leerzeichen uneinheitlich. Rücken Sie mit Leerzeichen ein; Tabulatoren werden nur für '<<-'-Heredocs benötigt.
+doc-inconsistent-indentation-summary = Die Einrückung ist kein Vielfaches von 2 Leerzeichen
+doc-inconsistent-indentation-rationale = Eine ungerade Einrückungsbreite bedeutet meist eine falsch platzierte Zeile, wodurch die Blockstruktur schwer nachzuvollziehen ist.
+doc-line-too-long-summary = Die Zeile ist länger als 120 Zeichen
+doc-line-too-long-rationale = Lange Zeilen sind schwer zu lesen und zu prüfen. Teilen Sie lange Befehle mit einem Backslash am Zeilenende auf, eine Option pro Zeile.
+doc-function-naming-summary = Ein Funktionsname enthält Großbuchstaben
+doc-function-naming-rationale = Kleingeschriebene Namen mit Unterstrichen sind die übliche Konvention für Shell-Funktionen und unterscheiden sie von Umgebungsvariablen.
+doc-variable-naming-summary = Ein lokaler Variablenname mischt Groß- und Kleinschreibung
+doc-variable-naming-rationale = Großgeschriebene Namen sind üblicherweise Umgebungsvariablen und Konstanten vorbehalten. Verwenden Sie für skriptlokale Variablen Kleinbuchstaben mit Unterstrichen.
@@ -0,0 +1,252 @@
+# shellchecker の英語メッセージ（他の言語で見つからないメッセージにも使用する）
+
+## Severity levels
+severity-error = ERROR
+severity-warning = WARNING
+severity-info = INFO
+
+## Summary
+no-issues = ✓ No issues found
+summary = Summary: { $errors ->
+        [one] { $errors } error
+       *[other] { $errors } errors
+    }, { $warnings ->
+        [one] { $warnings } warning
+       *[other] { $warnings } warnings
+    }, { $infos ->
+        [one] { $infos } info
+       *[other] { $infos } infos
+    }
+
+## Categories
+cat-syntax = Syntax
+cat-best-practice = Best Practice
+cat-security = Security
+cat-style = Style
+
+## Syntax
+msg-missing-shebang = Missing shebang line (#!/bin/bash or #!/bin/sh)
+msg-invalid-shebang = Shebang does not specify bash or sh
+msg-unmatched-bracket = Unmatched closing bracket ']'
+msg-unclosed-bracket = Unclosed bracket '['
+msg-unclosed-brace = Unclosed brace {"'{'"}
+msg-unclosed-paren = Unclosed parenthesis '('
+msg-unclosed-single-quote = Unclosed single quote
+msg-unclosed-double-quote = Unclosed double quote
+msg-unclosed-var-expansion = Unclosed variable expansion {"${...}"}
+msg-unclosed-cmd-subst = Unclosed command substitution $(...)
+msg-bashism = Bash-specific syntax '{ $construct }' in a POSIX sh script
+
+## Best practice
+msg-use-set-e = Consider using 'set -e' to exit on errors
+msg-use-set-u = Consider using 'set -u' to treat unset variables as errors
+msg-use-set-pipefail = Consider using 'set -o pipefail' to catch errors in pipelines
+msg-unquoted-variable = Unquoted variable usage - consider using "$variable" to prevent word splitting
+msg-cd-without-check = cd command without error checking - consider using 'cd dir || exit 1'
+msg-use-dollar-paren = Use $(...) instead of backticks for command substitution
+
+## Security
+msg-eval-dangerous = Usage of 'eval' is dangerous - avoid dynamic code execution
+msg-curl-pipe-sh = Piping curl/wget directly to shell is dangerous - download and inspect first
+msg-dangerous-rm = Dangerous rm -rf usage with variable or root path - add proper validation
+msg-user-input-in-cmd = User input used in command execution - validate and sanitize input
+msg-expression-injection = Expression '{ $expression }' is expanded into the script before it runs and may allow code injection - pass it through an environment variable
+
+## Style
+msg-use-spaces = Use spaces instead of tabs for indentation
+msg-inconsistent-indent = Inconsistent indentation - use a multiple of { $width } spaces
+msg-line-too-long = Line too long ({ $length } > { $max } characters)
+msg-function-naming = Function name '{ $name }' should use snake_case (lowercase with underscores)
+msg-variable-naming = Local variable '{ $name }' should use lowercase with underscores
+
+## Baseline
+baseline-fixed = Baseline entries that are now fixed:
+baseline-written = Baseline written to { $path } ({ $count ->
+        [one] { $count } issue
+       *[other] { $count } issues
+    })
+baseline-suppressed = { $count ->
+        [one] { $count } existing issue
+       *[other] { $count } existing issues
+    } suppressed by baseline
+
+## Script detection
+reason-shebang = shebang
+reason-modeline = modeline
+reason-file-name = file name
+reason-extension = extension
+reason-default = no shell indicator, checked as bash
+reason-embedded = embedded in { $format }
+reason-config = dialect set in the configuration file
+skipped = skipped
+
+## Git hook
+hook-installed = Installed pre-commit hook: { $path }
+
+## Watch mode
+watch-last-checked = Last checked
+watch-waiting = Watching for changes... (press Ctrl+C to stop)
+
+## Quick fixes and editor actions
+fix-dollar-paren = Replace backticks with $(...)
+fix-cd-exit = Add '|| exit 1' after cd
+fix-quote-variable = Quote the variable
+fix-tabs = Replace tabs with spaces
+fix-insert = Insert '{ $text }'
+fix-replace = Replace with '{ $text }'
+fix-disable-line = Disable { $rule } for this line
+fix-disable-file = Disable { $rule } for this file
+
+## Command line
+checking = Checking: { $path }
+error-invalid-config = Error: Invalid configuration { $error }
+error-unknown-rule = Error: Unknown rule '{ $rule }' (see --list-rules)
+error-invalid-glob = Error: Invalid glob pattern: { $error }
+error-git-changes = Error: Failed to get changes since '{ $rev }': { $error }
+error-path-not-found = Error: Path does not exist: { $path }
+error-reading-file = Error reading file { $path }: { $error }
+error-reading-stdin = Error reading stdin: { $error }
+error-walking-directory = Error walking directory: { $error }
+error-reading-baseline = Error reading baseline { $path }: { $error }
+error-writing-baseline = Error writing baseline { $path }: { $error }
+error-staged-files = Error: Failed to get staged files: { $error }
+error-reading-staged-file = Error reading staged file { $path }: { $error }
+error-hook-exists = Error: { $path } already exists. Use --force to overwrite it.
+error-not-git-repository = Error: Not in a git repository: { $error }
+error-writing-hook = Error writing hook { $path }: { $error }
+error-watch = Error: Failed to watch files: { $error }
+error-catalog = Warning: Invalid message catalog { $path }: { $error }
+error-unsupported-language = Error: Unsupported language '{ $language }'. Available languages: { $available }.
+error-language-server = Error: Language server failed: { $error }
+
+## Configuration file
+config-unsupported-language = { $path }: unsupported language '{ $language }' (available: { $available })
+config-unknown-rule = { $path }: unknown rule '{ $rule }'
+config-invalid-custom-rule = { $path }: invalid custom rule '{ $rule }' - the ID must be unique, use only letters, digits, '-' and '_' and not name a built-in rule, and exactly one of 'pattern' or 'regex' must be given
+config-invalid-regex = { $path }: invalid regex in custom rule '{ $rule }': { $error }
+
+## Command-line arguments
+error-unexpected-argument = Error: Unexpected argument '{ $arg }'
+error-missing-value = Error: A value is required for '{ $arg }' but none was supplied
+error-invalid-value = Error: Invalid value '{ $value }' for '{ $arg }'
+error-unexpected-value = Error: Unexpected value '{ $value }' for '{ $arg }'
+error-missing-arguments = Error: The following required arguments were not provided:
+error-argument-conflict = Error: The argument '{ $arg }' cannot be used with '{ $other }'
+error-argument-repeated = Error: The argument '{ $arg }' cannot be used multiple times
+error-unknown-subcommand = Error: Unrecognized subcommand '{ $subcommand }'
+possible-values = Possible values: { $values }
+did-you-mean = Tip: Did you mean '{ $suggestion }'?
+try-help = For more information, try '--help'.
+
+## Help
+help-about = Bash script checker
+help-version-info = shellchecker { $version }
+help-usage = Usage
+help-commands = Commands
+help-arguments = Arguments
+help-options = Options
+help-help = Print help
+help-version = Print version
+help-help-command = Print this message or the help of the given subcommand(s)
+help-hook = Manage the git pre-commit hook
+help-hook-install = Install a git pre-commit hook that checks staged shell files
+help-hook-install-force = Overwrite an existing pre-commit hook
+help-hook-install-args = Extra options passed to shellchecker by the hook
+help-lsp = Run a language server (LSP) over stdio
+help-explain = Explain a rule: why it matters, examples and how to suppress it
+help-explain-rule = Rule ID (see --list-rules)
+help-explain-markdown = Print a Markdown reference of all rules
+help-paths = Paths to bash scripts or directories ('-' reads a script from stdin)
+help-stdin-filename = File name used for reporting and config resolution when reading from stdin
+help-recursive = Recursive directory scan
+help-no-ignore = Do not respect .gitignore and .ignore files during directory scans
+help-hidden = Scan hidden files and directories
+help-include = Only check scanned files matching this glob (repeatable)
+help-exclude = Skip scanned files and directories matching this glob (repeatable)
+help-list-files = List the files that would be checked, with the detected shell and the reason
+help-list-rules = List all rules with their category and default severity
+help-changed-since = Only check shell files changed since the given git revision
+help-diff-lines-only = Only report issues on lines changed since --changed-since
+help-staged = Check the staged (index) version of staged shell files
+help-watch = Keep running and re-check scripts when they change
+help-jobs = Number of files to check in parallel [default: number of CPUs]
+help-severity = Minimum severity to report (error, warning, info) [default: info]
+help-errors-only = Show only errors (same as --severity error)
+help-category = Report only these categories (syntax, best-practice, security, style)
+help-exclude-category = Do not report these categories
+help-rule = Report only these rules
+help-exclude-rule = Do not report these rules
+help-profile = Start from a built-in rule profile (strict, recommended, minimal, security) [default: from .shellchecker.toml, or recommended]
+help-language = Language for output (en, ja, zh-Hans, ko, de, es, or a catalog in $SHELLCHECKER_LOCALE_DIR) [default: from .shellchecker.toml, then LC_ALL / LC_MESSAGES / LANG, or en]
+help-baseline = Suppress issues recorded in the baseline file and report only new ones
+help-write-baseline = Record all current issues to a baseline file
+help-fail-on = Minimum severity that makes the run fail (error, warning, info, none) [default: error]
+help-max-warnings = Fail when more than N warnings are found
+
+## Rule documentation
+doc-rules-title = Rules
+doc-rule = Rule
+doc-category = Category
+doc-severity = Default severity
+doc-rationale = Why
+doc-bad = Bad
+doc-good = Good
+doc-suppress = Suppress
+doc-suppress-line = the next line
+doc-suppress-file = the whole file
+doc-suppress-config = every file under the directory
+
+doc-missing-shebang-summary = The script has no shebang line
+doc-missing-shebang-rationale = Without a shebang the script runs with whichever shell invokes it, so bash-specific code can fail when it is started from sh or another shell.
+doc-invalid-shebang-summary = The shebang does not name bash or sh
+doc-invalid-shebang-rationale = A shebang naming an unknown interpreter is usually a typo in the interpreter path, which makes the script fail to start.
+doc-unmatched-bracket-summary = A closing ']' has no matching '['
+doc-unmatched-bracket-rationale = An unmatched bracket is usually a mistyped test expression, which fails with a syntax error when the line runs.
+doc-unclosed-bracket-summary = A '[' is never closed
+doc-unclosed-bracket-rationale = The test command requires a closing ']', so the condition fails with an error instead of being evaluated.
+doc-unclosed-brace-summary = A {"'{'"} is never closed
+doc-unclosed-brace-rationale = A function body or command group without its closing brace swallows the rest of the file, and the shell reports an unexpected end of file.
+doc-unclosed-paren-summary = A '(' is never closed
+doc-unclosed-paren-rationale = An unclosed subshell or array assignment makes the shell read the following lines as part of it until it fails with a syntax error.
+doc-unclosed-single-quote-summary = A single-quoted string is never closed
+doc-unclosed-single-quote-rationale = A single-quoted string runs until the next single quote, even across lines, so the following code becomes part of the string.
+doc-unclosed-double-quote-summary = A double-quoted string is never closed
+doc-unclosed-double-quote-rationale = A double-quoted string runs until the next double quote, even across lines, so the following code becomes part of the string.
+doc-unclosed-var-expansion-summary = A {"'${'"} expansion is never closed
+doc-unclosed-var-expansion-rationale = The shell rejects a parameter expansion without its closing brace with a bad substitution error.
+doc-unclosed-cmd-subst-summary = A '$(' command substitution is never closed
+doc-unclosed-cmd-subst-rationale = An unclosed command substitution makes the shell read the following lines as part of the command until it fails with a syntax error.
+doc-bashism-summary = Bash-specific syntax is used in a POSIX sh script
+doc-bashism-rationale = /bin/sh is dash or busybox sh on many systems, which do not support bash extensions such as '[[ ]]', arrays or 'function'. Use POSIX syntax or change the shebang to bash.
+doc-missing-set-e-summary = The script does not exit when a command fails
+doc-missing-set-e-rationale = By default a script keeps running after a command fails, so later commands work on missing files or partial results. 'set -e' stops the script at the first failure.
+doc-missing-set-u-summary = Unset variables are not treated as errors
+doc-missing-set-u-rationale = A misspelt or unset variable silently expands to an empty string, which can turn 'rm -rf "$build_dir/"' into 'rm -rf /'. 'set -u' makes such an expansion an error.
+doc-missing-pipefail-summary = Failures inside pipelines are ignored
+doc-missing-pipefail-rationale = The exit status of a pipeline is that of its last command, so 'curl ... | tar xz' succeeds even when the download fails. 'set -o pipefail' makes the pipeline fail when any command fails. Not reported for POSIX sh, which lacks the option.
+doc-unquoted-variable-summary = A variable is expanded without quotes
+doc-unquoted-variable-rationale = An unquoted expansion is split on whitespace and expanded as a glob pattern, so a value containing a space or '*' breaks the command or affects other files.
+doc-cd-without-check-summary = The result of cd is not checked
+doc-cd-without-check-rationale = If cd fails the script keeps running in the current directory, and commands meant for the target directory, such as 'rm -rf ./*', run in the wrong place.
+doc-backtick-substitution-summary = Command substitution uses backticks
+doc-backtick-substitution-rationale = Backticks are hard to read, need escaping to nest and treat backslashes specially. '$(...)' nests cleanly and behaves consistently.
+doc-eval-usage-summary = eval is used
+doc-eval-usage-rationale = eval runs its arguments as code, so any data that reaches it, such as file names, input or command output, can execute arbitrary commands. Arrays, 'case' or indirect expansion usually do the job safely.
+doc-curl-pipe-shell-summary = A downloaded script is piped into a shell
+doc-curl-pipe-shell-rationale = The script runs before anyone can inspect it, an interrupted download can run half a script, and a compromised server runs code on every machine. Download to a file, verify it, then run it.
+doc-dangerous-rm-summary = rm -rf is run on a variable or the root path
+doc-dangerous-rm-rationale = If the variable is empty or unset, 'rm -rf "$dir"/*' removes everything under /. Use {"'${dir:?}'"} to abort when the variable is empty.
+doc-user-input-in-command-summary = Script arguments or input are used in eval or command substitution
+doc-user-input-in-command-rationale = Arguments and input come from the caller, and when they are spliced into code run by eval or a command substitution they can run arbitrary commands. Validate the value and pass it as a separate, quoted argument.
+doc-expression-injection-summary = An untrusted expression is expanded into an embedded script
+doc-expression-injection-rationale = CI expressions such as {"'${{"} github.event.issue.title {"}}'"} and Groovy interpolation are substituted into the script text before the shell runs, so whoever controls the value can inject commands. Pass the value through an environment variable and quote it.
+doc-tab-indentation-summary = Indentation uses tabs
+doc-tab-indentation-rationale = Tabs are displayed with different widths in each editor, so mixed tab and space indentation looks inconsistent. Indent with spaces; tabs are only needed for '<<-' heredocs.
+doc-inconsistent-indentation-summary = Indentation is not a multiple of 2 spaces
+doc-inconsistent-indentation-rationale = An odd indentation width usually means a misplaced line, which makes the block structure hard to follow.
+doc-line-too-long-summary = The line is longer than 120 characters
+doc-line-too-long-rationale = Long lines are hard to read and review. Split long commands with a trailing backslash, one option per line.
+doc-function-naming-summary = A function name contains uppercase letters
+doc-function-naming-rationale = Lowercase names with underscores are the usual convention for shell functions and keep them distinct from environment variables.
+doc-variable-naming-summary = A local variable name uses mixed case
+doc-variable-naming-rationale = Uppercase names are conventionally reserved for environment variables and constants. Use lowercase with underscores for variables local to the script.
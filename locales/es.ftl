@@ -0,0 +1,251 @@
+# shellchecker のスペイン語メッセージ
+
+## Severity levels
+severity-error = ERROR
+severity-warning = ADVERTENCIA
+severity-info = INFO
+
+## Summary
+no-issues = ✓ No se encontraron problemas
+summary = Resumen: { $errors ->
+        [one] { $errors } error
+       *[other] { $errors } errores
+    }, { $warnings ->
+        [one] { $warnings } advertencia
+       *[other] { $warnings } advertencias
+    }, { $infos ->
+        [one] { $infos } aviso informativo
+       *[other] { $infos } avisos informativos
+    }
+
+## Categories
+cat-syntax = Sintaxis
+cat-best-practice = Buenas prácticas
+cat-security = Seguridad
+cat-style = Estilo
+
+## Syntax
+msg-missing-shebang = Falta la línea shebang (#!/bin/bash o #!/bin/sh)
+msg-invalid-shebang = La línea shebang no indica bash ni sh
+msg-unmatched-bracket = Corchete de cierre ']' sin pareja
+msg-unclosed-bracket = Corchete '[' sin cerrar
+msg-unclosed-brace = Llave {"'{'"} sin cerrar
+msg-unclosed-paren = Paréntesis '(' sin cerrar
+msg-unclosed-single-quote = Comilla simple sin cerrar
+msg-unclosed-double-quote = Comilla doble sin cerrar
+msg-unclosed-var-expansion = Expansión de variable {"${...}"} sin cerrar
+msg-unclosed-cmd-subst = Sustitución de comandos $(...) sin cerrar
+msg-bashism = Sintaxis específica de bash '{ $construct }' en un script POSIX sh
+
+## Best practice
+msg-use-set-e = Considere usar 'set -e' para salir cuando haya errores
+msg-use-set-u = Considere usar 'set -u' para tratar las variables no definidas como errores
+msg-use-set-pipefail = Considere usar 'set -o pipefail' para detectar errores en las tuberías
+msg-unquoted-variable = Variable sin comillas - considere usar "$variable" para evitar la división en palabras
+msg-cd-without-check = Comando cd sin comprobación de errores - considere usar 'cd dir || exit 1'
+msg-use-dollar-paren = Use $(...) en lugar de comillas invertidas para la sustitución de comandos
+
+## Security
+msg-eval-dangerous = El uso de 'eval' es peligroso - evite la ejecución dinámica de código
+msg-curl-pipe-sh = Redirigir curl/wget directamente a una shell es peligroso - descárguelo e inspecciónelo primero
+msg-dangerous-rm = Uso peligroso de rm -rf con una variable o la ruta raíz - añada una validación adecuada
+msg-user-input-in-cmd = Se usa entrada del usuario en la ejecución de comandos - valide y sanee la entrada
+msg-expression-injection = La expresión '{ $expression }' se expande en el script antes de ejecutarse y puede permitir la inyección de código - pásela mediante una variable de entorno
+
+## Style
+msg-use-spaces = Use espacios en lugar de tabulaciones para la sangría
+msg-inconsistent-indent = Sangría inconsistente - use un múltiplo de { $width } espacios
+msg-line-too-long = Línea demasiado larga ({ $length } > { $max } caracteres)
+msg-function-naming = El nombre de función '{ $name }' debería usar snake_case (minúsculas con guiones bajos)
+msg-variable-naming = La variable local '{ $name }' debería usar minúsculas con guiones bajos
+
+## Baseline
+baseline-fixed = Entradas de la línea base que ya están corregidas:
+baseline-written = Línea base escrita en { $path } ({ $count ->
+        [one] { $count } problema
+       *[other] { $count } problemas
+    })
+baseline-suppressed = { $count ->
+        [one] { $count } problema existente suprimido
+       *[other] { $count } problemas existentes suprimidos
+    } por la línea base
+
+## Script detection
+reason-shebang = shebang
+reason-modeline = modeline
+reason-file-name = nombre de archivo
+reason-extension = extensión
+reason-default = sin indicación de la shell, comprobado como bash
+reason-embedded = incrustado en { $format }
+reason-config = indicado en el archivo de configuración
+skipped = omitido
+
+## Git hook
+hook-installed = Hook pre-commit instalado: { $path }
+
+## Watch mode
+watch-last-checked = Última comprobación
+watch-waiting = Esperando cambios... (pulse Ctrl+C para detener)
+
+## Quick fixes and editor actions
+fix-dollar-paren = Sustituir las comillas invertidas por $(...)
+fix-cd-exit = Añadir '|| exit 1' después de cd
+fix-quote-variable = Poner la variable entre comillas
+fix-tabs = Sustituir las tabulaciones por espacios
+fix-insert = Insertar '{ $text }'
+fix-replace = Reemplazar por '{ $text }'
+fix-disable-line = Desactivar { $rule } en esta línea
+fix-disable-file = Desactivar { $rule } en este archivo
+
+## Command line
+checking = Comprobando: { $path }
+error-invalid-config = Error: Configuración no válida { $error }
+error-unknown-rule = Error: Regla desconocida '{ $rule }' (consulte --list-rules)
+error-invalid-glob = Error: Patrón glob no válido: { $error }
+error-git-changes = Error: No se pudieron obtener los cambios desde '{ $rev }': { $error }
+error-path-not-found = Error: La ruta no existe: { $path }
+error-reading-file = Error al leer el archivo { $path }: { $error }
+error-reading-stdin = Error al leer la entrada estándar: { $error }
+error-walking-directory = Error al recorrer el directorio: { $error }
+error-reading-baseline = Error al leer la línea base { $path }: { $error }
+error-writing-baseline = Error al escribir la línea base { $path }: { $error }
+error-staged-files = Error: No se pudieron obtener los archivos preparados: { $error }
+error-reading-staged-file = Error al leer el archivo preparado { $path }: { $error }
+error-hook-exists = Error: { $path } ya existe. Use --force para sobrescribirlo.
+error-not-git-repository = Error: No es un repositorio git: { $error }
+error-writing-hook = Error al escribir el hook { $path }: { $error }
+error-watch = Error: No se pueden vigilar los archivos: { $error }
+error-catalog = Advertencia: Catálogo de mensajes no válido { $path }: { $error }
+error-unsupported-language = Error: Idioma no compatible '{ $language }'. Idiomas disponibles: { $available }.
+error-language-server = Error: El servidor de lenguaje falló: { $error }
+
+## Configuration file
+config-unsupported-language = { $path }: idioma no compatible '{ $language }' (disponibles: { $available })
+config-unknown-rule = { $path }: regla desconocida '{ $rule }'
+config-invalid-custom-rule = { $path }: regla personalizada '{ $rule }' no válida - el ID debe ser único, usar solo letras, dígitos, '-' y '_' y no nombrar una regla integrada, y se debe indicar exactamente uno de 'pattern' o 'regex'
+config-invalid-regex = { $path }: expresión regular no válida en la regla personalizada '{ $rule }': { $error }
+
+## Command-line arguments
+error-unexpected-argument = Error: Argumento inesperado '{ $arg }'
+error-missing-value = Error: '{ $arg }' requiere un valor, pero no se proporcionó ninguno
+error-invalid-value = Error: Valor no válido '{ $value }' para '{ $arg }'
+error-unexpected-value = Error: Valor inesperado '{ $value }' para '{ $arg }'
+error-missing-arguments = Error: No se proporcionaron los siguientes argumentos obligatorios:
+error-argument-conflict = Error: El argumento '{ $arg }' no se puede usar junto con '{ $other }'
+error-argument-repeated = Error: El argumento '{ $arg }' no se puede usar varias veces
+error-unknown-subcommand = Error: Subcomando no reconocido '{ $subcommand }'
+possible-values = Valores posibles: { $values }
+did-you-mean = Sugerencia: ¿Quiso decir '{ $suggestion }'?
+try-help = Para más información, pruebe '--help'.
+
+## Help
+help-about = Comprobador de scripts de Bash
+help-version-info = shellchecker versión { $version }
+help-usage = Uso
+help-commands = Comandos
+help-arguments = Argumentos
+help-options = Opciones
+help-help = Mostrar la ayuda
+help-version = Mostrar la versión
+help-help-command = Mostrar este mensaje o la ayuda de los subcomandos indicados
+help-hook = Administrar el hook pre-commit de git
+help-hook-install = Instalar un hook pre-commit de git que comprueba los archivos de shell preparados
+help-hook-install-force = Sobrescribir un hook pre-commit existente
+help-hook-install-args = Opciones adicionales que el hook pasa a shellchecker
+help-lsp = Ejecutar un servidor de lenguaje (LSP) sobre stdio
+help-explain = Explicar una regla: por qué importa, ejemplos y cómo suprimirla
+help-explain-rule = ID de la regla (consulte --list-rules)
+help-explain-markdown = Imprimir una referencia en Markdown de todas las reglas
+help-paths = Rutas a scripts de bash o directorios ('-' lee un script de la entrada estándar)
+help-stdin-filename = Nombre de archivo usado en los informes y para buscar la configuración al leer de la entrada estándar
+help-recursive = Recorrer los directorios de forma recursiva
+help-no-ignore = No respetar los archivos .gitignore e .ignore al recorrer directorios
+help-hidden = Recorrer también los archivos y directorios ocultos
+help-include = Comprobar solo los archivos encontrados que coincidan con este glob (repetible)
+help-exclude = Omitir los archivos y directorios que coincidan con este glob (repetible)
+help-list-files = Listar los archivos que se comprobarían, con el shell detectado y el motivo
+help-list-rules = Listar todas las reglas con su categoría y gravedad predeterminada
+help-changed-since = Comprobar solo los archivos de shell modificados desde la revisión de git indicada
+help-diff-lines-only = Informar solo de los problemas en líneas modificadas desde --changed-since
+help-staged = Comprobar la versión preparada (índice) de los archivos de shell preparados
+help-watch = Seguir en ejecución y volver a comprobar los scripts cuando cambien
+help-jobs = Número de archivos a comprobar en paralelo [predeterminado: número de CPU]
+help-severity = Gravedad mínima a informar (error, warning, info) [predeterminado: info]
+help-errors-only = Mostrar solo errores (igual que --severity error)
+help-category = Informar solo de estas categorías (syntax, best-practice, security, style)
+help-exclude-category = No informar de estas categorías
+help-rule = Informar solo de estas reglas
+help-exclude-rule = No informar de estas reglas
+help-profile = Partir de un perfil de reglas integrado (strict, recommended, minimal, security) [predeterminado: extends de .shellchecker.toml, o recommended]
+help-language = Idioma de salida (en, ja, zh-Hans, ko, de, es o un catálogo en $SHELLCHECKER_LOCALE_DIR) [predeterminado: de .shellchecker.toml, luego LC_ALL / LC_MESSAGES / LANG, o en]
+help-baseline = Suprimir los problemas registrados en el archivo de línea base e informar solo de los nuevos
+help-write-baseline = Registrar todos los problemas actuales en un archivo de línea base
+help-fail-on = Gravedad mínima que hace fallar la ejecución (error, warning, info, none) [predeterminado: error]
+help-max-warnings = Fallar cuando se encuentren más de N advertencias
+
+## Rule documentation
+doc-rules-title = Reglas
+doc-rule = Regla
+doc-category = Categoría
+doc-severity = Gravedad predeterminada
+doc-rationale = Por qué
+doc-bad = Incorrecto
+doc-good = Correcto
+doc-suppress = Cómo suprimirla
+doc-suppress-line = la línea siguiente
+doc-suppress-file = todo el archivo
+doc-suppress-config = todos los archivos del directorio
+doc-missing-shebang-summary = El script no tiene línea shebang
+doc-missing-shebang-rationale = Sin shebang el script se ejecuta con la shell que lo invoque, por lo que el código específico de bash puede fallar si se inicia desde sh u otra shell.
+doc-invalid-shebang-summary = La línea shebang no indica bash ni sh
+doc-invalid-shebang-rationale = Una línea shebang con un intérprete desconocido suele ser un error tipográfico en la ruta, lo que impide que el script se inicie.
+doc-unmatched-bracket-summary = Un ']' de cierre no tiene su '[' correspondiente
+doc-unmatched-bracket-rationale = Un corchete sin pareja suele ser una expresión test mal escrita, que falla con un error de sintaxis al ejecutarse la línea.
+doc-unclosed-bracket-summary = Un '[' nunca se cierra
+doc-unclosed-bracket-rationale = El comando test requiere un ']' de cierre, por lo que la condición falla con un error en lugar de evaluarse.
+doc-unclosed-brace-summary = Una {"'{'"} nunca se cierra
+doc-unclosed-brace-rationale = El cuerpo de una función o un grupo de comandos sin su llave de cierre absorbe el resto del archivo, y la shell informa de un final de archivo inesperado.
+doc-unclosed-paren-summary = Un '(' nunca se cierra
+doc-unclosed-paren-rationale = Una subshell o una asignación de array sin cerrar hace que la shell lea las líneas siguientes como parte de ella hasta fallar con un error de sintaxis.
+doc-unclosed-single-quote-summary = Una cadena entre comillas simples nunca se cierra
+doc-unclosed-single-quote-rationale = Una cadena entre comillas simples continúa hasta la siguiente comilla simple, incluso entre líneas, por lo que el código siguiente pasa a formar parte de la cadena.
+doc-unclosed-double-quote-summary = Una cadena entre comillas dobles nunca se cierra
+doc-unclosed-double-quote-rationale = Una cadena entre comillas dobles continúa hasta la siguiente comilla doble, incluso entre líneas, por lo que el código siguiente pasa a formar parte de la cadena.
+doc-unclosed-var-expansion-summary = Una expansión {"'${'"} nunca se cierra
+doc-unclosed-var-expansion-rationale = La shell rechaza una expansión de parámetros sin su llave de cierre con un error bad substitution.
+doc-unclosed-cmd-subst-summary = Una sustitución de comandos '$(' nunca se cierra
+doc-unclosed-cmd-subst-rationale = Una sustitución de comandos sin cerrar hace que la shell lea las líneas siguientes como parte del comando hasta fallar con un error de sintaxis.
+doc-bashism-summary = Se usa sintaxis específica de bash en un script POSIX sh
+doc-bashism-rationale = En muchos sistemas /bin/sh es dash o busybox sh, que no admiten extensiones de bash como '[[ ]]', los arrays o 'function'. Use sintaxis POSIX o cambie la línea shebang a bash.
+doc-missing-set-e-summary = El script no termina cuando falla un comando
+doc-missing-set-e-rationale = De forma predeterminada un script sigue ejecutándose después de que falle un comando, por lo que los comandos posteriores trabajan con archivos inexistentes o resultados parciales. 'set -e' detiene el script en el primer fallo.
+doc-missing-set-u-summary = Las variables no definidas no se tratan como errores
+doc-missing-set-u-rationale = Una variable mal escrita o no definida se expande en silencio a una cadena vacía, lo que puede convertir 'rm -rf "$build_dir/"' en 'rm -rf /'. 'set -u' hace que esa expansión sea un error.
+doc-missing-pipefail-summary = Se ignoran los fallos dentro de las tuberías
+doc-missing-pipefail-rationale = El estado de salida de una tubería es el de su último comando, así que 'curl ... | tar xz' tiene éxito aunque falle la descarga. 'set -o pipefail' hace que la tubería falle cuando falla cualquier comando. No se informa en POSIX sh, que carece de esta opción.
+doc-unquoted-variable-summary = Una variable se expande sin comillas
+doc-unquoted-variable-rationale = Una expansión sin comillas se divide por los espacios en blanco y se expande como patrón glob, por lo que un valor con un espacio o '*' rompe el comando o afecta a otros archivos.
+doc-cd-without-check-summary = No se comprueba el resultado de cd
+doc-cd-without-check-rationale = Si cd falla, el script sigue ejecutándose en el directorio actual, y los comandos pensados para el directorio de destino, como 'rm -rf ./*', se ejecutan en el lugar equivocado.
+doc-backtick-substitution-summary = La sustitución de comandos usa comillas invertidas
+doc-backtick-substitution-rationale = Las comillas invertidas son difíciles de leer, hay que escaparlas para anidarlas y tratan las barras invertidas de forma especial. '$(...)' se anida sin problemas y se comporta de forma coherente.
+doc-eval-usage-summary = Se usa eval
+doc-eval-usage-rationale = eval ejecuta sus argumentos como código, por lo que cualquier dato que le llegue, como nombres de archivo, entrada o salida de comandos, puede ejecutar comandos arbitrarios. Los arrays, 'case' o la expansión indirecta suelen resolverlo de forma segura.
+doc-curl-pipe-shell-summary = Se redirige un script descargado a una shell
+doc-curl-pipe-shell-rationale = El script se ejecuta antes de que nadie pueda inspeccionarlo, una descarga interrumpida puede ejecutar medio script y un servidor comprometido ejecuta código en todas las máquinas. Descárguelo a un archivo, verifíquelo y después ejecútelo.
+doc-dangerous-rm-summary = Se ejecuta rm -rf sobre una variable o la ruta raíz
+doc-dangerous-rm-rationale = Si la variable está vacía o no definida, 'rm -rf "$dir"/*' elimina todo lo que hay bajo /. Use {"'${dir:?}'"} para abortar cuando la variable esté vacía.
+doc-user-input-in-command-summary = Se usan argumentos o entrada del script en eval o en una sustitución de comandos
+doc-user-input-in-command-rationale = Los argumentos y la entrada proceden de quien llama al script, y si se insertan en código que ejecuta eval o una sustitución de comandos pueden ejecutar comandos arbitrarios. Valide el valor y páselo como un argumento independiente entre comillas.
+doc-expression-injection-summary = Se expande una expresión no fiable en un script incrustado
+doc-expression-injection-rationale = Las expresiones de CI como {"'${{"} github.event.issue.title {"}}'"} y la interpolación de Groovy se sustituyen en el texto del script antes de que se ejecute la shell, por lo que quien controle el valor puede inyectar comandos. Pase el valor mediante una variable de entorno y póngalo entre comillas.
+doc-tab-indentation-summary = La sangría usa tabulaciones
+doc-tab-indentation-rationale = Cada editor muestra las tabulaciones con un ancho distinto, por lo que la sangría que mezcla tabulaciones y espacios parece inconsistente. Use espacios para la sangría; las tabulaciones solo son necesarias en los heredocs '<<-'.
+doc-inconsistent-indentation-summary = La sangría no es múltiplo de 2 espacios
+doc-inconsistent-indentation-rationale = Un ancho de sangría impar suele indicar una línea mal colocada, lo que dificulta seguir la estructura de los bloques.
+doc-line-too-long-summary = La línea tiene más de 120 caracteres
+doc-line-too-long-rationale = Las líneas largas son difíciles de leer y revisar. Divida los comandos largos con una barra invertida al final de la línea, una opción por línea.
+doc-function-naming-summary = Un nombre de función contiene mayúsculas
+doc-function-naming-rationale = Los nombres en minúsculas con guiones bajos son la convención habitual para las funciones de shell y las distinguen de las variables de entorno.
+doc-variable-naming-summary = Un nombre de variable local mezcla mayúsculas y minúsculas
+doc-variable-naming-rationale = Por convención, los nombres en mayúsculas se reservan para las variables de entorno y las constantes. Use minúsculas con guiones bajos para las variables locales del script.
@@ -0,0 +1,237 @@
+# shellchecker の日本語メッセージ
+
+## Severity levels
+severity-error = エラー
+severity-warning = 警告
+severity-info = 情報
+
+## Summary
+no-issues = ✓ 問題は見つかりませんでした
+summary = サマリ: { $errors } 個のエラー, { $warnings } 個の警告, { $infos } 個の情報
+
+## Categories
+cat-syntax = 構文
+cat-best-practice = ベストプラクティス
+cat-security = セキュリティ
+cat-style = スタイル
+
+## Syntax
+msg-missing-shebang = シバン行がありません (#!/bin/bash または #!/bin/sh)
+msg-invalid-shebang = シバン行でbashまたはshが指定されていません
+msg-unmatched-bracket = 対応しない閉じ括弧 ']'
+msg-unclosed-bracket = 閉じられていない括弧 '['
+msg-unclosed-brace = 閉じられていない波括弧 {"'{'"}
+msg-unclosed-paren = 閉じられていない丸括弧 '('
+msg-unclosed-single-quote = 閉じられていないシングルクォート
+msg-unclosed-double-quote = 閉じられていないダブルクォート
+msg-unclosed-var-expansion = 閉じられていない変数展開 {"${...}"}
+msg-unclosed-cmd-subst = 閉じられていないコマンド置換 $(...)
+msg-bashism = POSIX sh スクリプトで bash 固有の構文 '{ $construct }' が使用されています
+
+## Best practice
+msg-use-set-e = 'set -e' の使用を検討してください（エラー時に終了）
+msg-use-set-u = 'set -u' の使用を検討してください（未定義変数をエラーとして扱う）
+msg-use-set-pipefail = 'set -o pipefail' の使用を検討してください（パイプライン内のエラーを検出）
+msg-unquoted-variable = クォートされていない変数 - 単語分割を防ぐため "$variable" の使用を検討してください
+msg-cd-without-check = cdコマンドにエラーチェックがありません - 'cd dir || exit 1' の使用を検討してください
+msg-use-dollar-paren = コマンド置換にはバッククォートではなく $(...) を使用してください
+
+## Security
+msg-eval-dangerous = 'eval' の使用は危険です - 動的なコード実行を避けてください
+msg-curl-pipe-sh = curl/wgetを直接シェルにパイプするのは危険です - まずダウンロードして検査してください
+msg-dangerous-rm = 変数またはルートパスでの危険な rm -rf の使用 - 適切な検証を追加してください
+msg-user-input-in-cmd = ユーザー入力がコマンド実行で使用されています - 入力の検証とサニタイズを行ってください
+msg-expression-injection = 式 '{ $expression }' は実行前にスクリプトへ展開されるため、コードインジェクションの危険があります - 環境変数を経由して渡してください
+
+## Style
+msg-use-spaces = インデントにはタブではなくスペースを使用してください
+msg-inconsistent-indent = インデントが不統一です - { $width } の倍数のスペースを使用してください
+msg-line-too-long = 行が長すぎます ({ $length } > { $max } 文字)
+msg-function-naming = 関数名 '{ $name }' はスネークケース（小文字とアンダースコア）を使用してください
+msg-variable-naming = ローカル変数 '{ $name }' は小文字とアンダースコアを使用してください
+
+## Baseline
+baseline-fixed = 修正済みのベースラインエントリ:
+baseline-written = ベースラインを { $path } に書き込みました ({ $count } 件の問題)
+baseline-suppressed = ベースラインにより { $count } 件の既存の問題を抑制しました
+
+## Script detection
+reason-shebang = シバン行
+reason-modeline = モードライン
+reason-file-name = ファイル名
+reason-extension = 拡張子
+reason-default = シェルの手がかりなし、bash としてチェック
+reason-embedded = { $format } に埋め込まれたスクリプト
+reason-config = 設定ファイルで指定
+skipped = スキップ
+
+## Git hook
+hook-installed = pre-commit フックをインストールしました: { $path }
+
+## Watch mode
+watch-last-checked = 最終チェック
+watch-waiting = 変更を監視しています... (Ctrl+C で終了)
+
+## Quick fixes and editor actions
+fix-dollar-paren = バッククォートを $(...) に置き換える
+fix-cd-exit = cd の後に '|| exit 1' を追加する
+fix-quote-variable = 変数をクォートで囲む
+fix-tabs = タブをスペースに置き換える
+fix-insert = '{ $text }' を挿入する
+fix-replace = '{ $text }' に置き換える
+fix-disable-line = この行で { $rule } を無効にする
+fix-disable-file = このファイルで { $rule } を無効にする
+
+## Command line
+checking = チェック中: { $path }
+error-invalid-config = エラー: 不正な設定ファイル { $error }
+error-unknown-rule = エラー: 不明なルール '{ $rule }'（--list-rules を参照してください）
+error-invalid-glob = エラー: 不正なグロブパターン: { $error }
+error-git-changes = エラー: '{ $rev }' からの変更を取得できません: { $error }
+error-path-not-found = エラー: パスが存在しません: { $path }
+error-reading-file = ファイル読み込みエラー { $path }: { $error }
+error-reading-stdin = 標準入力の読み込みエラー: { $error }
+error-walking-directory = ディレクトリ走査エラー: { $error }
+error-reading-baseline = ベースライン読み込みエラー { $path }: { $error }
+error-writing-baseline = ベースライン書き込みエラー { $path }: { $error }
+error-staged-files = エラー: ステージされたファイルを取得できません: { $error }
+error-reading-staged-file = ステージされたファイルの読み込みエラー { $path }: { $error }
+error-hook-exists = エラー: { $path } は既に存在します。上書きするには --force を指定してください。
+error-not-git-repository = エラー: git リポジトリではありません: { $error }
+error-writing-hook = フック書き込みエラー { $path }: { $error }
+error-watch = エラー: ファイルを監視できません: { $error }
+error-catalog = 警告: 不正なメッセージカタログ { $path }: { $error }
+error-unsupported-language = エラー: 未対応の言語 '{ $language }'。使用できる言語: { $available }
+error-language-server = エラー: 言語サーバーが異常終了しました: { $error }
+
+## Configuration file
+config-unsupported-language = { $path }: 未対応の言語 '{ $language }'（使用できる言語: { $available }）
+config-unknown-rule = { $path }: 不明なルール '{ $rule }'
+config-invalid-custom-rule = { $path }: ユーザー定義ルール '{ $rule }' が不正です - ID は英数字、'-'、'_' のみを使用した重複しない組み込みルール以外の名前にし、'pattern' と 'regex' のどちらか一方を指定してください
+config-invalid-regex = { $path }: ユーザー定義ルール '{ $rule }' の正規表現が不正です: { $error }
+
+## Command-line arguments
+error-unexpected-argument = エラー: 不明な引数 '{ $arg }'
+error-missing-value = エラー: '{ $arg }' に値が指定されていません
+error-invalid-value = エラー: '{ $arg }' の値 '{ $value }' は不正です
+error-unexpected-value = エラー: '{ $arg }' に余分な値 '{ $value }' が指定されています
+error-missing-arguments = エラー: 次の必須の引数が指定されていません:
+error-argument-conflict = エラー: 引数 '{ $arg }' は '{ $other }' と同時に指定できません
+error-argument-repeated = エラー: 引数 '{ $arg }' は複数回指定できません
+error-unknown-subcommand = エラー: 不明なサブコマンド '{ $subcommand }'
+possible-values = 指定できる値: { $values }
+did-you-mean = ヒント: '{ $suggestion }' ではありませんか？
+try-help = 詳しくは '--help' を参照してください。
+
+## Help
+help-about = Bash スクリプトのチェッカー
+help-version-info = shellchecker バージョン { $version }
+help-usage = 使い方
+help-commands = コマンド
+help-arguments = 引数
+help-options = オプション
+help-help = ヘルプを表示する
+help-version = バージョンを表示する
+help-help-command = このヘルプ、または指定したサブコマンドのヘルプを表示する
+help-hook = git の pre-commit フックを管理する
+help-hook-install = ステージされたシェルファイルをチェックする git の pre-commit フックをインストールする
+help-hook-install-force = 既存の pre-commit フックを上書きする
+help-hook-install-args = フックから shellchecker に渡す追加のオプション
+help-lsp = 標準入出力で言語サーバー (LSP) を起動する
+help-explain = ルールの理由、例、抑制方法を説明する
+help-explain-rule = ルール ID（--list-rules を参照）
+help-explain-markdown = 全ルールのリファレンスを Markdown で出力する
+help-paths = bash スクリプトまたはディレクトリのパス（'-' は標準入力からスクリプトを読み込む）
+help-stdin-filename = 標準入力から読み込むときに、レポートと設定ファイルの解決に使用するファイル名
+help-recursive = ディレクトリを再帰的にスキャンする
+help-no-ignore = ディレクトリのスキャンで .gitignore と .ignore ファイルに従わない
+help-hidden = 隠しファイルと隠しディレクトリもスキャンする
+help-include = スキャンしたファイルのうち、このグロブに一致するものだけをチェックする（複数指定可）
+help-exclude = このグロブに一致するファイルとディレクトリをスキャンしない（複数指定可）
+help-list-files = チェック対象のファイルを、判定したシェルとその理由とともに一覧表示する
+help-list-rules = 全ルールを分類と既定の重大度とともに一覧表示する
+help-changed-since = 指定した git リビジョン以降に変更されたシェルファイルだけをチェックする
+help-diff-lines-only = --changed-since 以降に変更された行の問題だけを報告する
+help-staged = ステージされたシェルファイルを、ステージ（インデックス）上の内容でチェックする
+help-watch = 実行を続け、スクリプトが変更されたら再チェックする
+help-jobs = 並列にチェックするファイル数 [既定値: CPU 数]
+help-severity = 報告する最小の重大度 (error, warning, info) [既定値: info]
+help-errors-only = エラーのみ表示する（--severity error と同じ）
+help-category = 指定した分類だけを報告する (syntax, best-practice, security, style)
+help-exclude-category = 指定した分類を報告しない
+help-rule = 指定したルールだけを報告する
+help-exclude-rule = 指定したルールを報告しない
+help-profile = 組み込みのルールプロファイルを土台にする (strict, recommended, minimal, security) [既定値: .shellchecker.toml の extends、なければ recommended]
+help-language = 出力言語 (en, ja, zh-Hans, ko, de, es、または $SHELLCHECKER_LOCALE_DIR のカタログ) [既定値: .shellchecker.toml、次に LC_ALL / LC_MESSAGES / LANG、なければ en]
+help-baseline = ベースラインファイルに記録された問題を抑制し、新しい問題だけを報告する
+help-write-baseline = 現在のすべての問題をベースラインファイルに記録する
+help-fail-on = 実行を失敗させる最小の重大度 (error, warning, info, none) [既定値: error]
+help-max-warnings = 警告が N 件を超えたら失敗させる
+
+## Rule documentation
+doc-rules-title = ルール一覧
+doc-rule = ルール
+doc-category = 分類
+doc-severity = 既定の重大度
+doc-rationale = 理由
+doc-bad = 問題のある例
+doc-good = 修正例
+doc-suppress = 抑制方法
+doc-suppress-line = 次の行
+doc-suppress-file = ファイル全体
+doc-suppress-config = ディレクトリ以下のすべてのファイル
+
+doc-missing-shebang-summary = スクリプトにシバン行がありません
+doc-missing-shebang-rationale = シバン行がないとスクリプトは呼び出し元のシェルで実行されるため、sh などから起動すると bash 固有のコードが失敗することがあります。
+doc-invalid-shebang-summary = シバン行で bash または sh が指定されていません
+doc-invalid-shebang-rationale = 不明なインタプリタを指定したシバン行の多くはパスの誤りで、スクリプトを起動できなくなります。
+doc-unmatched-bracket-summary = 閉じ括弧 ']' に対応する '[' がありません
+doc-unmatched-bracket-rationale = 対応しない括弧の多くは test 式の書き誤りで、その行の実行時に構文エラーになります。
+doc-unclosed-bracket-summary = '[' が閉じられていません
+doc-unclosed-bracket-rationale = test コマンドには閉じ括弧 ']' が必要なため、条件は評価されずにエラーになります。
+doc-unclosed-brace-summary = {"'{'"} が閉じられていません
+doc-unclosed-brace-rationale = 閉じ波括弧のない関数本体やコマンドグループはファイルの残りを取り込んでしまい、シェルは予期しないファイルの終わりを報告します。
+doc-unclosed-paren-summary = '(' が閉じられていません
+doc-unclosed-paren-rationale = 閉じられていないサブシェルや配列の代入は、構文エラーになるまで後続の行を取り込んでしまいます。
+doc-unclosed-single-quote-summary = シングルクォートの文字列が閉じられていません
+doc-unclosed-single-quote-rationale = シングルクォートの文字列は行をまたいで次のシングルクォートまで続くため、後続のコードが文字列の一部になります。
+doc-unclosed-double-quote-summary = ダブルクォートの文字列が閉じられていません
+doc-unclosed-double-quote-rationale = ダブルクォートの文字列は行をまたいで次のダブルクォートまで続くため、後続のコードが文字列の一部になります。
+doc-unclosed-var-expansion-summary = {"'${'"} の展開が閉じられていません
+doc-unclosed-var-expansion-rationale = 閉じ波括弧のないパラメータ展開は、シェルが bad substitution エラーとして拒否します。
+doc-unclosed-cmd-subst-summary = '$(' のコマンド置換が閉じられていません
+doc-unclosed-cmd-subst-rationale = 閉じられていないコマンド置換は、構文エラーになるまで後続の行をコマンドの一部として読み込みます。
+doc-bashism-summary = POSIX sh スクリプトで bash 固有の構文が使用されています
+doc-bashism-rationale = 多くのシステムで /bin/sh は dash や busybox sh であり、'[[ ]]'、配列、'function' などの bash の拡張に対応していません。POSIX の構文を使用するか、シバン行を bash に変更してください。
+doc-missing-set-e-summary = コマンドが失敗してもスクリプトが終了しません
+doc-missing-set-e-rationale = 既定ではコマンドが失敗してもスクリプトは実行を続けるため、後続のコマンドが存在しないファイルや不完全な結果を処理します。'set -e' を指定すると最初の失敗でスクリプトが終了します。
+doc-missing-set-u-summary = 未定義の変数がエラーとして扱われません
+doc-missing-set-u-rationale = 綴りを誤った変数や未定義の変数は暗黙のうちに空文字列に展開され、'rm -rf "$build_dir/"' が 'rm -rf /' になることがあります。'set -u' を指定するとこのような展開はエラーになります。
+doc-missing-pipefail-summary = パイプライン内の失敗が無視されます
+doc-missing-pipefail-rationale = パイプラインの終了ステータスは最後のコマンドのものになるため、'curl ... | tar xz' はダウンロードに失敗しても成功します。'set -o pipefail' を指定するといずれかのコマンドが失敗したときにパイプラインが失敗します。このオプションのない POSIX sh では報告しません。
+doc-unquoted-variable-summary = 変数がクォートなしで展開されています
+doc-unquoted-variable-rationale = クォートなしの展開は空白で分割され、グロブパターンとして展開されるため、空白や '*' を含む値でコマンドが壊れたり、他のファイルに影響したりします。
+doc-cd-without-check-summary = cd の結果が確認されていません
+doc-cd-without-check-rationale = cd が失敗するとスクリプトは現在のディレクトリで実行を続け、'rm -rf ./*' のように移動先のディレクトリ向けのコマンドが誤った場所で実行されます。
+doc-backtick-substitution-summary = コマンド置換にバッククォートが使用されています
+doc-backtick-substitution-rationale = バッククォートは読みにくく、入れ子にするにはエスケープが必要で、バックスラッシュを特別に扱います。'$(...)' は素直に入れ子にでき、動作も一貫しています。
+doc-eval-usage-summary = eval が使用されています
+doc-eval-usage-rationale = eval は引数をコードとして実行するため、ファイル名、入力、コマンドの出力などのデータが渡ると任意のコマンドを実行できてしまいます。多くの場合、配列、'case'、間接展開で安全に代替できます。
+doc-curl-pipe-shell-summary = ダウンロードしたスクリプトがシェルにパイプされています
+doc-curl-pipe-shell-rationale = スクリプトは内容を確認する前に実行され、ダウンロードが中断すると途中までのスクリプトが実行され、サーバーが侵害されるとすべてのマシンでコードが実行されます。ファイルにダウンロードして検証してから実行してください。
+doc-dangerous-rm-summary = 変数またはルートパスに対して rm -rf が実行されています
+doc-dangerous-rm-rationale = 変数が空または未定義の場合、'rm -rf "$dir"/*' は / {"以下をすべて削除します。'${dir:?}'"} を使用して変数が空の場合は中止してください。
+doc-user-input-in-command-summary = スクリプトの引数や入力が eval またはコマンド置換で使用されています
+doc-user-input-in-command-rationale = 引数や入力は呼び出し元から渡される値であり、eval やコマンド置換で実行されるコードに埋め込むと任意のコマンドを実行できてしまいます。値を検証し、クォートした独立した引数として渡してください。
+doc-expression-injection-summary = 信頼できない式が埋め込みスクリプトに展開されています
+doc-expression-injection-rationale = {"'${{"} github.event.issue.title {"}}'"} などの CI の式や Groovy の補間はシェルの実行前にスクリプトのテキストへ展開されるため、値を制御できる人がコマンドを注入できます。環境変数を経由して渡し、クォートで囲んでください。
+doc-tab-indentation-summary = インデントにタブが使用されています
+doc-tab-indentation-rationale = タブの幅はエディタごとに異なるため、タブとスペースが混在したインデントは不揃いに見えます。スペースでインデントしてください。タブが必要なのは '<<-' のヒアドキュメントのみです。
+doc-inconsistent-indentation-summary = インデントが 2 スペースの倍数ではありません
+doc-inconsistent-indentation-rationale = 奇数幅のインデントの多くは行の位置の誤りで、ブロックの構造が読み取りにくくなります。
+doc-line-too-long-summary = 行が 120 文字を超えています
+doc-line-too-long-rationale = 長い行は読みにくく、レビューしにくくなります。長いコマンドは行末のバックスラッシュで分割し、オプションを 1 行に 1 つずつ記述してください。
+doc-function-naming-summary = 関数名に大文字が含まれています
+doc-function-naming-rationale = 小文字とアンダースコアの名前はシェル関数の一般的な慣習であり、環境変数と区別しやすくなります。
+doc-variable-naming-summary = ローカル変数の名前に大文字と小文字が混在しています
+doc-variable-naming-rationale = 大文字の名前は慣習的に環境変数と定数に使用されます。スクリプト内の変数には小文字とアンダースコアを使用してください。
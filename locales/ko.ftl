@@ -0,0 +1,236 @@
+# shellchecker の韓国語メッセージ
+
+## Severity levels
+severity-error = 오류
+severity-warning = 경고
+severity-info = 정보
+
+## Summary
+no-issues = ✓ 문제가 발견되지 않았습니다
+summary = 요약: 오류 { $errors }개, 경고 { $warnings }개, 정보 { $infos }개
+
+## Categories
+cat-syntax = 구문
+cat-best-practice = 모범 사례
+cat-security = 보안
+cat-style = 스타일
+
+## Syntax
+msg-missing-shebang = 셔뱅 행이 없습니다 (#!/bin/bash 또는 #!/bin/sh)
+msg-invalid-shebang = 셔뱅 행에 bash 또는 sh가 지정되지 않았습니다
+msg-unmatched-bracket = 짝이 없는 닫는 대괄호 ']'
+msg-unclosed-bracket = 닫히지 않은 대괄호 '['
+msg-unclosed-brace = 닫히지 않은 중괄호 {"'{'"}
+msg-unclosed-paren = 닫히지 않은 괄호 '('
+msg-unclosed-single-quote = 닫히지 않은 작은따옴표
+msg-unclosed-double-quote = 닫히지 않은 큰따옴표
+msg-unclosed-var-expansion = 닫히지 않은 변수 확장 {"${...}"}
+msg-unclosed-cmd-subst = 닫히지 않은 명령 치환 $(...)
+msg-bashism = POSIX sh 스크립트에서 bash 전용 구문 '{ $construct }'이(가) 사용되었습니다
+
+## Best practice
+msg-use-set-e = 'set -e' 사용을 고려하세요 (오류 시 종료)
+msg-use-set-u = 'set -u' 사용을 고려하세요 (정의되지 않은 변수를 오류로 처리)
+msg-use-set-pipefail = 'set -o pipefail' 사용을 고려하세요 (파이프라인의 오류 감지)
+msg-unquoted-variable = 따옴표로 묶지 않은 변수 - 단어 분리를 막으려면 "$variable" 사용을 고려하세요
+msg-cd-without-check = cd 명령에 오류 검사가 없습니다 - 'cd dir || exit 1' 사용을 고려하세요
+msg-use-dollar-paren = 명령 치환에는 백틱 대신 $(...)를 사용하세요
+
+## Security
+msg-eval-dangerous = 'eval' 사용은 위험합니다 - 동적 코드 실행을 피하세요
+msg-curl-pipe-sh = curl/wget을 셸로 직접 파이프하는 것은 위험합니다 - 먼저 내려받아 검사하세요
+msg-dangerous-rm = 변수 또는 루트 경로에 대한 위험한 rm -rf 사용 - 적절한 검증을 추가하세요
+msg-user-input-in-cmd = 사용자 입력이 명령 실행에 사용되었습니다 - 입력을 검증하고 정제하세요
+msg-expression-injection = 식 '{ $expression }'은(는) 실행 전에 스크립트에 전개되므로 코드 주입이 가능합니다 - 환경 변수를 통해 전달하세요
+
+## Style
+msg-use-spaces = 들여쓰기에는 탭 대신 공백을 사용하세요
+msg-inconsistent-indent = 들여쓰기가 일관되지 않습니다 - 공백을 { $width }칸의 배수로 사용하세요
+msg-line-too-long = 행이 너무 깁니다 ({ $length } > { $max }자)
+msg-function-naming = 함수 이름 '{ $name }'에는 snake_case(소문자와 밑줄)를 사용하세요
+msg-variable-naming = 지역 변수 '{ $name }'에는 소문자와 밑줄을 사용하세요
+
+## Baseline
+baseline-fixed = 이제 수정된 기준선 항목:
+baseline-written = 기준선을 { $path }에 기록했습니다 (문제 { $count }개)
+baseline-suppressed = 기준선에 의해 기존 문제 { $count }개를 억제했습니다
+
+## Script detection
+reason-shebang = 셔뱅
+reason-modeline = 모드라인
+reason-file-name = 파일 이름
+reason-extension = 확장자
+reason-default = 셸 단서 없음, bash로 검사
+reason-embedded = { $format }에 포함된 스크립트
+reason-config = 설정 파일에서 지정
+skipped = 건너뜀
+
+## Git hook
+hook-installed = pre-commit 훅을 설치했습니다: { $path }
+
+## Watch mode
+watch-last-checked = 마지막 검사
+watch-waiting = 변경 사항을 감시하는 중... (Ctrl+C로 중지)
+
+## Quick fixes and editor actions
+fix-dollar-paren = 백틱을 $(...)로 바꾸기
+fix-cd-exit = cd 뒤에 '|| exit 1' 추가
+fix-quote-variable = 변수를 따옴표로 묶기
+fix-tabs = 탭을 공백으로 바꾸기
+fix-insert = '{ $text }' 삽입
+fix-replace = '{ $text }'(으)로 바꾸기
+fix-disable-line = 이 행에서 { $rule } 비활성화
+fix-disable-file = 이 파일에서 { $rule } 비활성화
+
+## Command line
+checking = 검사 중: { $path }
+error-invalid-config = 오류: 잘못된 설정 파일 { $error }
+error-unknown-rule = 오류: 알 수 없는 규칙 '{ $rule }' (--list-rules 참조)
+error-invalid-glob = 오류: 잘못된 glob 패턴: { $error }
+error-git-changes = 오류: '{ $rev }' 이후의 변경 사항을 가져올 수 없습니다: { $error }
+error-path-not-found = 오류: 경로가 존재하지 않습니다: { $path }
+error-reading-file = 파일 읽기 오류 { $path }: { $error }
+error-reading-stdin = 표준 입력 읽기 오류: { $error }
+error-walking-directory = 디렉터리 탐색 오류: { $error }
+error-reading-baseline = 기준선 읽기 오류 { $path }: { $error }
+error-writing-baseline = 기준선 쓰기 오류 { $path }: { $error }
+error-staged-files = 오류: 스테이징된 파일을 가져올 수 없습니다: { $error }
+error-reading-staged-file = 스테이징된 파일 읽기 오류 { $path }: { $error }
+error-hook-exists = 오류: { $path }이(가) 이미 존재합니다. 덮어쓰려면 --force를 지정하세요.
+error-not-git-repository = 오류: git 저장소가 아닙니다: { $error }
+error-writing-hook = 훅 쓰기 오류 { $path }: { $error }
+error-watch = 오류: 파일을 감시할 수 없습니다: { $error }
+error-catalog = 경고: 잘못된 메시지 카탈로그 { $path }: { $error }
+error-unsupported-language = 오류: 지원하지 않는 언어 '{ $language }'. 사용 가능한 언어: { $available }
+error-language-server = 오류: 언어 서버가 실패했습니다: { $error }
+
+## Configuration file
+config-unsupported-language = { $path }: 지원하지 않는 언어 '{ $language }' (사용 가능한 언어: { $available })
+config-unknown-rule = { $path }: 알 수 없는 규칙 '{ $rule }'
+config-invalid-custom-rule = { $path }: 사용자 정의 규칙 '{ $rule }'이(가) 잘못되었습니다 - ID는 고유해야 하고 문자, 숫자, '-', '_'만 사용하며 내장 규칙 이름이 아니어야 하고, 'pattern'과 'regex' 중 하나만 지정해야 합니다
+config-invalid-regex = { $path }: 사용자 정의 규칙 '{ $rule }'의 정규식이 잘못되었습니다: { $error }
+
+## Command-line arguments
+error-unexpected-argument = 오류: 예상하지 못한 인수 '{ $arg }'
+error-missing-value = 오류: '{ $arg }'에 값이 필요하지만 지정되지 않았습니다
+error-invalid-value = 오류: '{ $arg }'의 값 '{ $value }'이(가) 잘못되었습니다
+error-unexpected-value = 오류: '{ $arg }'에 예상하지 못한 값 '{ $value }'이(가) 지정되었습니다
+error-missing-arguments = 오류: 다음 필수 인수가 지정되지 않았습니다:
+error-argument-conflict = 오류: 인수 '{ $arg }'은(는) '{ $other }'와(과) 함께 사용할 수 없습니다
+error-argument-repeated = 오류: 인수 '{ $arg }'은(는) 여러 번 사용할 수 없습니다
+error-unknown-subcommand = 오류: 알 수 없는 하위 명령 '{ $subcommand }'
+possible-values = 가능한 값: { $values }
+did-you-mean = 팁: '{ $suggestion }'을(를) 의도하셨나요?
+try-help = 자세한 내용은 '--help'를 참조하세요.
+
+## Help
+help-about = Bash 스크립트 검사기
+help-version-info = shellchecker 버전 { $version }
+help-usage = 사용법
+help-commands = 명령
+help-arguments = 인수
+help-options = 옵션
+help-help = 도움말 출력
+help-version = 버전 출력
+help-help-command = 이 도움말 또는 지정한 하위 명령의 도움말 출력
+help-hook = git pre-commit 훅 관리
+help-hook-install = 스테이징된 셸 파일을 검사하는 git pre-commit 훅 설치
+help-hook-install-force = 기존 pre-commit 훅 덮어쓰기
+help-hook-install-args = 훅이 shellchecker에 전달하는 추가 옵션
+help-lsp = 표준 입출력으로 언어 서버(LSP) 실행
+help-explain = 규칙 설명: 중요한 이유, 예제, 억제 방법
+help-explain-rule = 규칙 ID (--list-rules 참조)
+help-explain-markdown = 모든 규칙의 참조를 Markdown으로 출력
+help-paths = bash 스크립트 또는 디렉터리 경로 ('-'는 표준 입력에서 스크립트를 읽음)
+help-stdin-filename = 표준 입력에서 읽을 때 보고와 설정 파일 탐색에 사용할 파일 이름
+help-recursive = 디렉터리를 재귀적으로 검색
+help-no-ignore = 디렉터리 검색 시 .gitignore 및 .ignore 파일을 따르지 않음
+help-hidden = 숨김 파일과 디렉터리도 검색
+help-include = 검색된 파일 중 이 glob과 일치하는 파일만 검사 (여러 번 지정 가능)
+help-exclude = 이 glob과 일치하는 파일과 디렉터리를 건너뜀 (여러 번 지정 가능)
+help-list-files = 검사할 파일을 감지된 셸과 그 이유와 함께 나열
+help-list-rules = 모든 규칙을 분류 및 기본 심각도와 함께 나열
+help-changed-since = 지정한 git 리비전 이후 변경된 셸 파일만 검사
+help-diff-lines-only = --changed-since 이후 변경된 줄의 문제만 보고
+help-staged = 스테이징된 셸 파일의 스테이징(인덱스) 버전을 검사
+help-watch = 계속 실행하면서 스크립트가 변경되면 다시 검사
+help-jobs = 병렬로 검사할 파일 수 [기본값: CPU 수]
+help-severity = 보고할 최소 심각도 (error, warning, info) [기본값: info]
+help-errors-only = 오류만 표시 (--severity error와 동일)
+help-category = 이 분류만 보고 (syntax, best-practice, security, style)
+help-exclude-category = 이 분류는 보고하지 않음
+help-rule = 이 규칙만 보고
+help-exclude-rule = 이 규칙은 보고하지 않음
+help-profile = 내장 규칙 프로필을 기반으로 사용 (strict, recommended, minimal, security) [기본값: .shellchecker.toml의 extends, 없으면 recommended]
+help-language = 출력 언어 (en, ja, zh-Hans, ko, de, es 또는 $SHELLCHECKER_LOCALE_DIR의 카탈로그) [기본값: .shellchecker.toml, 그다음 LC_ALL / LC_MESSAGES / LANG, 없으면 en]
+help-baseline = 기준선 파일에 기록된 문제를 억제하고 새 문제만 보고
+help-write-baseline = 현재의 모든 문제를 기준선 파일에 기록
+help-fail-on = 실행을 실패로 만드는 최소 심각도 (error, warning, info, none) [기본값: error]
+help-max-warnings = 경고가 N개를 넘으면 실패
+
+## Rule documentation
+doc-rules-title = 규칙 목록
+doc-rule = 규칙
+doc-category = 분류
+doc-severity = 기본 심각도
+doc-rationale = 이유
+doc-bad = 문제가 있는 예
+doc-good = 수정한 예
+doc-suppress = 억제 방법
+doc-suppress-line = 다음 행
+doc-suppress-file = 파일 전체
+doc-suppress-config = 디렉터리 아래의 모든 파일
+doc-missing-shebang-summary = 스크립트에 셔뱅 행이 없습니다
+doc-missing-shebang-rationale = 셔뱅이 없으면 스크립트는 호출한 셸로 실행되므로 sh나 다른 셸에서 시작하면 bash 전용 코드가 실패할 수 있습니다.
+doc-invalid-shebang-summary = 셔뱅 행에 bash 또는 sh가 지정되지 않았습니다
+doc-invalid-shebang-rationale = 알 수 없는 인터프리터를 지정한 셔뱅 행은 대개 경로의 오타이며, 스크립트를 시작할 수 없게 됩니다.
+doc-unmatched-bracket-summary = 닫는 ']'에 대응하는 '['가 없습니다
+doc-unmatched-bracket-rationale = 짝이 없는 대괄호는 대개 잘못 쓴 test 식이며, 해당 행을 실행할 때 구문 오류로 실패합니다.
+doc-unclosed-bracket-summary = '['가 닫히지 않았습니다
+doc-unclosed-bracket-rationale = test 명령에는 닫는 ']'가 필요하므로 조건이 평가되지 않고 오류로 실패합니다.
+doc-unclosed-brace-summary = {"'{'"}가 닫히지 않았습니다
+doc-unclosed-brace-rationale = 닫는 중괄호가 없는 함수 본문이나 명령 그룹은 파일의 나머지를 삼켜 버리고, 셸은 예기치 않은 파일 끝을 보고합니다.
+doc-unclosed-paren-summary = '('가 닫히지 않았습니다
+doc-unclosed-paren-rationale = 닫히지 않은 서브셸이나 배열 대입은 구문 오류로 실패할 때까지 이후의 행을 그 일부로 읽게 합니다.
+doc-unclosed-single-quote-summary = 작은따옴표 문자열이 닫히지 않았습니다
+doc-unclosed-single-quote-rationale = 작은따옴표 문자열은 행을 넘어 다음 작은따옴표까지 이어지므로 이후의 코드가 문자열의 일부가 됩니다.
+doc-unclosed-double-quote-summary = 큰따옴표 문자열이 닫히지 않았습니다
+doc-unclosed-double-quote-rationale = 큰따옴표 문자열은 행을 넘어 다음 큰따옴표까지 이어지므로 이후의 코드가 문자열의 일부가 됩니다.
+doc-unclosed-var-expansion-summary = {"'${'"} 확장이 닫히지 않았습니다
+doc-unclosed-var-expansion-rationale = 닫는 중괄호가 없는 매개변수 확장은 셸이 bad substitution 오류로 거부합니다.
+doc-unclosed-cmd-subst-summary = '$(' 명령 치환이 닫히지 않았습니다
+doc-unclosed-cmd-subst-rationale = 닫히지 않은 명령 치환은 구문 오류로 실패할 때까지 이후의 행을 명령의 일부로 읽게 합니다.
+doc-bashism-summary = POSIX sh 스크립트에서 bash 전용 구문이 사용되었습니다
+doc-bashism-rationale = 많은 시스템에서 /bin/sh는 dash나 busybox sh이며, '[[ ]]', 배열, 'function' 같은 bash 확장을 지원하지 않습니다. POSIX 구문을 사용하거나 셔뱅 행을 bash로 바꾸세요.
+doc-missing-set-e-summary = 명령이 실패해도 스크립트가 종료되지 않습니다
+doc-missing-set-e-rationale = 기본적으로 스크립트는 명령이 실패한 뒤에도 계속 실행되므로 이후의 명령이 존재하지 않는 파일이나 불완전한 결과를 처리합니다. 'set -e'를 지정하면 첫 번째 실패에서 스크립트가 중지됩니다.
+doc-missing-set-u-summary = 정의되지 않은 변수가 오류로 처리되지 않습니다
+doc-missing-set-u-rationale = 철자가 틀리거나 정의되지 않은 변수는 조용히 빈 문자열로 확장되어 'rm -rf "$build_dir/"'가 'rm -rf /'가 될 수 있습니다. 'set -u'를 지정하면 이런 확장이 오류가 됩니다.
+doc-missing-pipefail-summary = 파이프라인 안의 실패가 무시됩니다
+doc-missing-pipefail-rationale = 파이프라인의 종료 상태는 마지막 명령의 것이므로 다운로드가 실패해도 'curl ... | tar xz'는 성공합니다. 'set -o pipefail'을 지정하면 어느 명령이든 실패할 때 파이프라인이 실패합니다. 이 옵션이 없는 POSIX sh에서는 보고하지 않습니다.
+doc-unquoted-variable-summary = 변수가 따옴표 없이 확장됩니다
+doc-unquoted-variable-rationale = 따옴표 없는 확장은 공백으로 분리되고 glob 패턴으로 확장되므로, 공백이나 '*'를 포함한 값이 명령을 망가뜨리거나 다른 파일에 영향을 줍니다.
+doc-cd-without-check-summary = cd의 결과를 확인하지 않습니다
+doc-cd-without-check-rationale = cd가 실패하면 스크립트는 현재 디렉터리에서 계속 실행되고, 'rm -rf ./*'처럼 대상 디렉터리를 위한 명령이 잘못된 위치에서 실행됩니다.
+doc-backtick-substitution-summary = 명령 치환에 백틱을 사용합니다
+doc-backtick-substitution-rationale = 백틱은 읽기 어렵고, 중첩하려면 이스케이프가 필요하며, 백슬래시를 특별하게 처리합니다. '$(...)'는 깔끔하게 중첩되고 동작도 일관됩니다.
+doc-eval-usage-summary = eval이 사용되었습니다
+doc-eval-usage-rationale = eval은 인수를 코드로 실행하므로 파일 이름, 입력, 명령 출력 등 전달되는 모든 데이터가 임의의 명령을 실행할 수 있습니다. 대개 배열, 'case', 간접 확장으로 안전하게 대체할 수 있습니다.
+doc-curl-pipe-shell-summary = 내려받은 스크립트를 셸로 파이프합니다
+doc-curl-pipe-shell-rationale = 스크립트는 누구도 검사하기 전에 실행되고, 다운로드가 중단되면 스크립트의 절반만 실행될 수 있으며, 서버가 침해되면 모든 머신에서 코드가 실행됩니다. 파일로 내려받아 검증한 뒤 실행하세요.
+doc-dangerous-rm-summary = 변수 또는 루트 경로에 대해 rm -rf를 실행합니다
+doc-dangerous-rm-rationale = 변수가 비어 있거나 정의되지 않았으면 'rm -rf "$dir"/*'는 / 아래의 모든 것을 삭제합니다. 변수가 비어 있을 때 중단하려면 {"'${dir:?}'"}를 사용하세요.
+doc-user-input-in-command-summary = 스크립트 인수나 입력이 eval 또는 명령 치환에 사용됩니다
+doc-user-input-in-command-rationale = 인수와 입력은 호출자가 전달하는 값이며, eval이나 명령 치환으로 실행되는 코드에 끼워 넣으면 임의의 명령을 실행할 수 있습니다. 값을 검증하고 따옴표로 묶은 별도의 인수로 전달하세요.
+doc-expression-injection-summary = 신뢰할 수 없는 식이 포함된 스크립트에 전개됩니다
+doc-expression-injection-rationale = {"'${{"} github.event.issue.title {"}}'"} 같은 CI 식과 Groovy 보간은 셸이 실행되기 전에 스크립트 텍스트에 치환되므로 값을 제어하는 사람이 명령을 주입할 수 있습니다. 환경 변수를 통해 값을 전달하고 따옴표로 묶으세요.
+doc-tab-indentation-summary = 들여쓰기에 탭을 사용합니다
+doc-tab-indentation-rationale = 탭은 편집기마다 다른 너비로 표시되므로 탭과 공백이 섞인 들여쓰기는 일관되지 않아 보입니다. 공백으로 들여쓰세요. 탭은 '<<-' 히어독에서만 필요합니다.
+doc-inconsistent-indentation-summary = 들여쓰기가 공백 2칸의 배수가 아닙니다
+doc-inconsistent-indentation-rationale = 홀수 너비의 들여쓰기는 대개 행의 위치가 잘못되었음을 뜻하며, 블록 구조를 파악하기 어렵게 만듭니다.
+doc-line-too-long-summary = 행이 120자를 넘습니다
+doc-line-too-long-rationale = 긴 행은 읽고 리뷰하기 어렵습니다. 긴 명령은 행 끝의 백슬래시로 나누고, 한 행에 옵션을 하나씩 쓰세요.
+doc-function-naming-summary = 함수 이름에 대문자가 포함되어 있습니다
+doc-function-naming-rationale = 소문자와 밑줄로 된 이름은 셸 함수의 일반적인 관례이며 환경 변수와 구별하기 쉽습니다.
+doc-variable-naming-summary = 지역 변수 이름에 대문자와 소문자가 섞여 있습니다
+doc-variable-naming-rationale = 대문자 이름은 관례적으로 환경 변수와 상수에 사용됩니다. 스크립트 안의 변수에는 소문자와 밑줄을 사용하세요.
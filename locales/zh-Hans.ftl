@@ -0,0 +1,236 @@
+# shellchecker の中国語（簡体字）メッセージ
+
+## Severity levels
+severity-error = 错误
+severity-warning = 警告
+severity-info = 信息
+
+## Summary
+no-issues = ✓ 未发现问题
+summary = 摘要: { $errors } 个错误, { $warnings } 个警告, { $infos } 条信息
+
+## Categories
+cat-syntax = 语法
+cat-best-practice = 最佳实践
+cat-security = 安全
+cat-style = 风格
+
+## Syntax
+msg-missing-shebang = 缺少 shebang 行 (#!/bin/bash 或 #!/bin/sh)
+msg-invalid-shebang = shebang 行未指定 bash 或 sh
+msg-unmatched-bracket = 没有匹配的右方括号 ']'
+msg-unclosed-bracket = 未闭合的方括号 '['
+msg-unclosed-brace = 未闭合的花括号 {"'{'"}
+msg-unclosed-paren = 未闭合的圆括号 '('
+msg-unclosed-single-quote = 未闭合的单引号
+msg-unclosed-double-quote = 未闭合的双引号
+msg-unclosed-var-expansion = 未闭合的变量展开 {"${...}"}
+msg-unclosed-cmd-subst = 未闭合的命令替换 $(...)
+msg-bashism = 在 POSIX sh 脚本中使用了 bash 特有的语法 '{ $construct }'
+
+## Best practice
+msg-use-set-e = 建议使用 'set -e'（出错时退出）
+msg-use-set-u = 建议使用 'set -u'（将未定义的变量视为错误）
+msg-use-set-pipefail = 建议使用 'set -o pipefail'（捕获管道中的错误）
+msg-unquoted-variable = 变量未加引号 - 建议使用 "$variable" 以防止单词拆分
+msg-cd-without-check = cd 命令没有错误检查 - 建议使用 'cd dir || exit 1'
+msg-use-dollar-paren = 命令替换请使用 $(...) 而不是反引号
+
+## Security
+msg-eval-dangerous = 使用 'eval' 很危险 - 请避免动态执行代码
+msg-curl-pipe-sh = 将 curl/wget 直接通过管道传给 shell 很危险 - 请先下载并检查
+msg-dangerous-rm = 对变量或根路径使用 rm -rf 很危险 - 请添加适当的验证
+msg-user-input-in-cmd = 用户输入被用于命令执行 - 请验证并清理输入
+msg-expression-injection = 表达式 '{ $expression }' 会在运行前展开到脚本中，可能导致代码注入 - 请通过环境变量传递
+
+## Style
+msg-use-spaces = 缩进请使用空格而不是制表符
+msg-inconsistent-indent = 缩进不一致 - 请使用 { $width } 的倍数个空格
+msg-line-too-long = 行太长 ({ $length } > { $max } 个字符)
+msg-function-naming = 函数名 '{ $name }' 应使用 snake_case（小写字母和下划线）
+msg-variable-naming = 局部变量 '{ $name }' 应使用小写字母和下划线
+
+## Baseline
+baseline-fixed = 已修复的基线条目:
+baseline-written = 基线已写入 { $path } ({ $count } 个问题)
+baseline-suppressed = 基线抑制了 { $count } 个已有问题
+
+## Script detection
+reason-shebang = shebang 行
+reason-modeline = 模式行
+reason-file-name = 文件名
+reason-extension = 扩展名
+reason-default = 没有 shell 的线索，按 bash 检查
+reason-embedded = 嵌入在 { $format } 中的脚本
+reason-config = 在配置文件中指定
+skipped = 已跳过
+
+## Git hook
+hook-installed = 已安装 pre-commit 钩子: { $path }
+
+## Watch mode
+watch-last-checked = 上次检查
+watch-waiting = 正在监视更改... (按 Ctrl+C 停止)
+
+## Quick fixes and editor actions
+fix-dollar-paren = 将反引号替换为 $(...)
+fix-cd-exit = 在 cd 后添加 '|| exit 1'
+fix-quote-variable = 为变量加上引号
+fix-tabs = 将制表符替换为空格
+fix-insert = 插入 '{ $text }'
+fix-replace = 替换为 '{ $text }'
+fix-disable-line = 在此行禁用 { $rule }
+fix-disable-file = 在此文件中禁用 { $rule }
+
+## Command line
+checking = 正在检查: { $path }
+error-invalid-config = 错误: 无效的配置文件 { $error }
+error-unknown-rule = 错误: 未知规则 '{ $rule }'（请参阅 --list-rules）
+error-invalid-glob = 错误: 无效的 glob 模式: { $error }
+error-git-changes = 错误: 无法获取自 '{ $rev }' 以来的更改: { $error }
+error-path-not-found = 错误: 路径不存在: { $path }
+error-reading-file = 读取文件出错 { $path }: { $error }
+error-reading-stdin = 读取标准输入出错: { $error }
+error-walking-directory = 遍历目录出错: { $error }
+error-reading-baseline = 读取基线出错 { $path }: { $error }
+error-writing-baseline = 写入基线出错 { $path }: { $error }
+error-staged-files = 错误: 无法获取暂存的文件: { $error }
+error-reading-staged-file = 读取暂存的文件出错 { $path }: { $error }
+error-hook-exists = 错误: { $path } 已存在。使用 --force 覆盖。
+error-not-git-repository = 错误: 不是 git 仓库: { $error }
+error-writing-hook = 写入钩子出错 { $path }: { $error }
+error-watch = 错误: 无法监视文件: { $error }
+error-catalog = 警告: 无效的消息目录 { $path }: { $error }
+error-unsupported-language = 错误: 不支持的语言 '{ $language }'。可用的语言: { $available }
+error-language-server = 错误: 语言服务器运行失败: { $error }
+
+## Configuration file
+config-unsupported-language = { $path }: 不支持的语言 '{ $language }'（可用的语言: { $available }）
+config-unknown-rule = { $path }: 未知规则 '{ $rule }'
+config-invalid-custom-rule = { $path }: 自定义规则 '{ $rule }' 无效 - ID 必须唯一，只能使用字母、数字、'-' 和 '_'，且不能与内置规则同名；必须且只能指定 'pattern' 或 'regex' 之一
+config-invalid-regex = { $path }: 自定义规则 '{ $rule }' 中的正则表达式无效: { $error }
+
+## Command-line arguments
+error-unexpected-argument = 错误: 意外的参数 '{ $arg }'
+error-missing-value = 错误: '{ $arg }' 需要一个值，但未提供
+error-invalid-value = 错误: '{ $arg }' 的值 '{ $value }' 无效
+error-unexpected-value = 错误: '{ $arg }' 有多余的值 '{ $value }'
+error-missing-arguments = 错误: 未提供以下必需的参数:
+error-argument-conflict = 错误: 参数 '{ $arg }' 不能与 '{ $other }' 一起使用
+error-argument-repeated = 错误: 参数 '{ $arg }' 不能多次使用
+error-unknown-subcommand = 错误: 无法识别的子命令 '{ $subcommand }'
+possible-values = 可选的值: { $values }
+did-you-mean = 提示: 您是不是指 '{ $suggestion }'？
+try-help = 如需更多信息，请尝试 '--help'。
+
+## Help
+help-about = Bash 脚本检查工具
+help-version-info = shellchecker 版本 { $version }
+help-usage = 用法
+help-commands = 命令
+help-arguments = 参数
+help-options = 选项
+help-help = 显示帮助
+help-version = 显示版本
+help-help-command = 显示此帮助或指定子命令的帮助
+help-hook = 管理 git pre-commit 钩子
+help-hook-install = 安装检查暂存的 shell 文件的 git pre-commit 钩子
+help-hook-install-force = 覆盖已有的 pre-commit 钩子
+help-hook-install-args = 钩子传给 shellchecker 的额外选项
+help-lsp = 通过标准输入输出运行语言服务器 (LSP)
+help-explain = 解释规则：原因、示例以及抑制方法
+help-explain-rule = 规则 ID（请参阅 --list-rules）
+help-explain-markdown = 以 Markdown 格式输出所有规则的参考
+help-paths = bash 脚本或目录的路径（'-' 表示从标准输入读取脚本）
+help-stdin-filename = 从标准输入读取时，用于报告和查找配置文件的文件名
+help-recursive = 递归扫描目录
+help-no-ignore = 扫描目录时不遵循 .gitignore 和 .ignore 文件
+help-hidden = 扫描隐藏的文件和目录
+help-include = 只检查扫描到的、与此 glob 匹配的文件（可多次指定）
+help-exclude = 跳过与此 glob 匹配的文件和目录（可多次指定）
+help-list-files = 列出将要检查的文件，以及检测到的 shell 和原因
+help-list-rules = 列出所有规则及其分类和默认严重级别
+help-changed-since = 只检查自指定 git 修订版本以来更改过的 shell 文件
+help-diff-lines-only = 只报告自 --changed-since 以来更改过的行上的问题
+help-staged = 检查暂存的 shell 文件在暂存区（索引）中的版本
+help-watch = 保持运行，并在脚本更改时重新检查
+help-jobs = 并行检查的文件数 [默认: CPU 数量]
+help-severity = 报告的最低严重级别 (error, warning, info) [默认: info]
+help-errors-only = 只显示错误（同 --severity error）
+help-category = 只报告这些分类 (syntax, best-practice, security, style)
+help-exclude-category = 不报告这些分类
+help-rule = 只报告这些规则
+help-exclude-rule = 不报告这些规则
+help-profile = 以内置规则配置文件为基础 (strict, recommended, minimal, security) [默认: .shellchecker.toml 中的 extends，否则为 recommended]
+help-language = 输出语言 (en, ja, zh-Hans, ko, de, es，或 $SHELLCHECKER_LOCALE_DIR 中的消息目录) [默认: 依次取 .shellchecker.toml、LC_ALL / LC_MESSAGES / LANG，否则为 en]
+help-baseline = 抑制基线文件中记录的问题，只报告新问题
+help-write-baseline = 将当前所有问题记录到基线文件
+help-fail-on = 使运行失败的最低严重级别 (error, warning, info, none) [默认: error]
+help-max-warnings = 警告超过 N 个时失败
+
+## Rule documentation
+doc-rules-title = 规则列表
+doc-rule = 规则
+doc-category = 分类
+doc-severity = 默认严重级别
+doc-rationale = 原因
+doc-bad = 有问题的示例
+doc-good = 修正示例
+doc-suppress = 抑制方法
+doc-suppress-line = 下一行
+doc-suppress-file = 整个文件
+doc-suppress-config = 目录下的所有文件
+doc-missing-shebang-summary = 脚本没有 shebang 行
+doc-missing-shebang-rationale = 没有 shebang 时，脚本由调用它的 shell 执行，因此从 sh 或其他 shell 启动时，bash 特有的代码可能会失败。
+doc-invalid-shebang-summary = shebang 行未指定 bash 或 sh
+doc-invalid-shebang-rationale = 指定了未知解释器的 shebang 行通常是解释器路径的拼写错误，会导致脚本无法启动。
+doc-unmatched-bracket-summary = 右方括号 ']' 没有对应的 '['
+doc-unmatched-bracket-rationale = 不匹配的方括号通常是写错的 test 表达式，该行执行时会因语法错误而失败。
+doc-unclosed-bracket-summary = '[' 没有闭合
+doc-unclosed-bracket-rationale = test 命令需要右方括号 ']'，因此条件不会被求值，而是以错误告终。
+doc-unclosed-brace-summary = {"'{'"} 没有闭合
+doc-unclosed-brace-rationale = 缺少右花括号的函数体或命令组会吞掉文件的其余部分，shell 会报告意外的文件结尾。
+doc-unclosed-paren-summary = '(' 没有闭合
+doc-unclosed-paren-rationale = 未闭合的子 shell 或数组赋值会使 shell 将后续行当作其中的一部分读取，直到出现语法错误。
+doc-unclosed-single-quote-summary = 单引号字符串没有闭合
+doc-unclosed-single-quote-rationale = 单引号字符串会跨行一直延续到下一个单引号，因此后面的代码会成为字符串的一部分。
+doc-unclosed-double-quote-summary = 双引号字符串没有闭合
+doc-unclosed-double-quote-rationale = 双引号字符串会跨行一直延续到下一个双引号，因此后面的代码会成为字符串的一部分。
+doc-unclosed-var-expansion-summary = {"'${'"} 展开没有闭合
+doc-unclosed-var-expansion-rationale = 缺少右花括号的参数展开会被 shell 以 bad substitution 错误拒绝。
+doc-unclosed-cmd-subst-summary = '$(' 命令替换没有闭合
+doc-unclosed-cmd-subst-rationale = 未闭合的命令替换会使 shell 将后续行当作命令的一部分读取，直到出现语法错误。
+doc-bashism-summary = 在 POSIX sh 脚本中使用了 bash 特有的语法
+doc-bashism-rationale = 在许多系统上 /bin/sh 是 dash 或 busybox sh，它们不支持 '[[ ]]'、数组或 'function' 等 bash 扩展。请使用 POSIX 语法，或将 shebang 行改为 bash。
+doc-missing-set-e-summary = 命令失败时脚本不会退出
+doc-missing-set-e-rationale = 默认情况下，命令失败后脚本会继续运行，后续命令会处理不存在的文件或不完整的结果。'set -e' 会在第一次失败时终止脚本。
+doc-missing-set-u-summary = 未定义的变量不会被视为错误
+doc-missing-set-u-rationale = 拼写错误或未定义的变量会悄悄展开为空字符串，可能使 'rm -rf "$build_dir/"' 变成 'rm -rf /'。'set -u' 会使这样的展开成为错误。
+doc-missing-pipefail-summary = 管道中的失败被忽略
+doc-missing-pipefail-rationale = 管道的退出状态是其最后一个命令的状态，因此即使下载失败，'curl ... | tar xz' 也会成功。'set -o pipefail' 会使任一命令失败时管道失败。POSIX sh 没有此选项，因此不会报告。
+doc-unquoted-variable-summary = 变量在没有引号的情况下展开
+doc-unquoted-variable-rationale = 没有引号的展开会按空白拆分并作为 glob 模式展开，因此包含空格或 '*' 的值会破坏命令或影响其他文件。
+doc-cd-without-check-summary = 没有检查 cd 的结果
+doc-cd-without-check-rationale = 如果 cd 失败，脚本会在当前目录继续运行，原本针对目标目录的命令（如 'rm -rf ./*'）会在错误的位置执行。
+doc-backtick-substitution-summary = 命令替换使用了反引号
+doc-backtick-substitution-rationale = 反引号难以阅读，嵌套时需要转义，并且会特殊处理反斜杠。'$(...)' 可以干净地嵌套，行为也一致。
+doc-eval-usage-summary = 使用了 eval
+doc-eval-usage-rationale = eval 会将参数作为代码执行，因此传给它的任何数据（如文件名、输入或命令输出）都可能执行任意命令。通常可以用数组、'case' 或间接展开安全地替代。
+doc-curl-pipe-shell-summary = 下载的脚本通过管道传给了 shell
+doc-curl-pipe-shell-rationale = 脚本在任何人检查之前就已运行，下载中断可能执行半个脚本，服务器被攻破时会在每台机器上执行代码。请先下载到文件并验证，然后再运行。
+doc-dangerous-rm-summary = 对变量或根路径执行了 rm -rf
+doc-dangerous-rm-rationale = 如果变量为空或未定义，'rm -rf "$dir"/*' 会删除 / 下的所有内容。请使用 {"'${dir:?}'"} 在变量为空时中止。
+doc-user-input-in-command-summary = 脚本参数或输入被用于 eval 或命令替换
+doc-user-input-in-command-rationale = 参数和输入来自调用方，将它们拼接到由 eval 或命令替换执行的代码中时，可能执行任意命令。请验证该值，并将其作为单独的、加引号的参数传递。
+doc-expression-injection-summary = 不可信的表达式被展开到嵌入的脚本中
+doc-expression-injection-rationale = {"'${{"} github.event.issue.title {"}}'"} 等 CI 表达式和 Groovy 插值会在 shell 运行前替换到脚本文本中，因此能控制该值的人可以注入命令。请通过环境变量传递该值，并为其加上引号。
+doc-tab-indentation-summary = 缩进使用了制表符
+doc-tab-indentation-rationale = 制表符在不同编辑器中显示的宽度不同，因此混用制表符和空格的缩进看起来不一致。请使用空格缩进；只有 '<<-' 的 heredoc 才需要制表符。
+doc-inconsistent-indentation-summary = 缩进不是 2 个空格的倍数
+doc-inconsistent-indentation-rationale = 奇数宽度的缩进通常意味着某行位置放错，使块结构难以理解。
+doc-line-too-long-summary = 行超过了 120 个字符
+doc-line-too-long-rationale = 长行难以阅读和审查。请用行尾的反斜杠拆分长命令，每行一个选项。
+doc-function-naming-summary = 函数名包含大写字母
+doc-function-naming-rationale = 小写字母加下划线的名称是 shell 函数的惯例，也便于与环境变量区分。
+doc-variable-naming-summary = 局部变量名混用了大小写
+doc-variable-naming-rationale = 按惯例，大写名称用于环境变量和常量。脚本内的变量请使用小写字母和下划线。
@@ -0,0 +1,257 @@
+// src/baseline.rs
+use crate::report::Report;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub file: String,
+    pub rule: String,
+    pub fingerprint: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    entries: Vec<BaselineEntry>,
+}
+
+/// 既存の問題を記録し、新しい問題のみを報告するためのベースライン
+///
+/// エントリはファイル・ルールID・行内容のフィンガープリントで識別されるため、
+/// 行番号がずれても同じ問題として扱われる。ファイルはベースラインファイルのディレクトリからの
+/// 相対パスで記録するため、どのディレクトリから実行しても同じエントリに一致する。
+#[derive(Clone)]
+pub struct Baseline {
+    // 同一キーの問題が複数ある場合に備えて件数で管理する
+    entries: BTreeMap<BaselineEntry, usize>,
+    checked_files: BTreeSet<String>,
+    // ベースラインファイルのディレクトリ（絶対パス）
+    root: PathBuf,
+}
+
+impl Baseline {
+    /// `path` に保存するベースラインを作成する
+    pub fn new(path: &Path) -> Self {
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        Baseline {
+            entries: BTreeMap::new(),
+            checked_files: BTreeSet::new(),
+            root: normalize(dir),
+        }
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        let file: BaselineFile = serde_json::from_str(&content)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let mut baseline = Baseline::new(path);
+        for entry in file.entries {
+            *baseline.entries.entry(entry).or_insert(0) += 1;
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let entries = self
+            .entries
+            .iter()
+            .flat_map(|(entry, count)| std::iter::repeat_n(entry.clone(), *count))
+            .collect();
+        let file = BaselineFile {
+            version: BASELINE_VERSION,
+            entries,
+        };
+        let json = serde_json::to_string_pretty(&file)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, json + "\n")
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.values().sum()
+    }
+
+    /// レポートの全ての問題をベースラインに記録する
+    pub fn record(&mut self, file: &Path, content: &str, report: &Report) {
+        let file = self.file_key(file);
+        for issue in report.issues() {
+            let entry = BaselineEntry {
+                file: file.clone(),
+                rule: issue.rule.clone(),
+                fingerprint: fingerprint(content, issue.line),
+            };
+            *self.entries.entry(entry).or_insert(0) += 1;
+        }
+    }
+
+    /// ベースラインに記録済みの問題をレポートから取り除き、取り除いた件数を返す
+    pub fn suppress(&mut self, file: &Path, content: &str, report: &mut Report) -> usize {
+        let file = self.file_key(file);
+        let mut suppressed = 0;
+
+        report.retain(|issue| {
+            let entry = BaselineEntry {
+                file: file.clone(),
+                rule: issue.rule.clone(),
+                fingerprint: fingerprint(content, issue.line),
+            };
+            match self.entries.get_mut(&entry) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    suppressed += 1;
+                    false
+                }
+                _ => true,
+            }
+        });
+
+        self.checked_files.insert(file);
+        suppressed
+    }
+
+    /// 修正済みになったエントリ（チェック済みまたは削除済みファイルで一致しなかったもの）
+    pub fn fixed_entries(&self) -> Vec<&BaselineEntry> {
+        self.entries
+            .iter()
+            .filter(|(entry, count)| {
+                **count > 0
+                    && (self.checked_files.contains(&entry.file) || !self.root.join(&entry.file).exists())
+            })
+            .flat_map(|(entry, count)| std::iter::repeat_n(entry, *count))
+            .collect()
+    }
+
+    /// ベースラインファイルのディレクトリからの相対パス（区切りは `/`）
+    fn file_key(&self, path: &Path) -> String {
+        let path = normalize(path);
+        let common = self.root.components().zip(path.components()).take_while(|(a, b)| a == b).count();
+        let mut key = PathBuf::new();
+        for _ in self.root.components().skip(common) {
+            key.push("..");
+        }
+        key.extend(path.components().skip(common));
+        key.to_string_lossy().replace('\\', "/")
+    }
+}
+
+/// カレントディレクトリからの絶対パスにし、`.` と `..` を取り除く
+fn normalize(path: &Path) -> PathBuf {
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut normalized = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// 行内容から空白の差異を無視したフィンガープリントを計算する (FNV-1a)
+fn fingerprint(content: &str, line: usize) -> String {
+    let text = content.lines().nth(line.saturating_sub(1)).unwrap_or("");
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in normalized.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{Category, Severity};
+
+    fn report(issues: &[(usize, &str)]) -> Report {
+        let mut report = Report::new();
+        for (line, rule) in issues {
+            report.add_issue(*line, rule, Severity::Warning, Category::BestPractice, "message");
+        }
+        report
+    }
+
+    fn rules(report: &Report) -> Vec<(usize, &str)> {
+        report.iter().map(|issue| (issue.line, issue.rule.as_str())).collect()
+    }
+
+    #[test]
+    fn record_keys_files_relative_to_the_baseline_directory() {
+        let mut baseline = Baseline::new(Path::new("/repo/bl.json"));
+        baseline.record(Path::new("/repo/scripts/./prod/a.sh"), "echo $A\n", &report(&[(1, "unquoted-variable")]));
+        baseline.record(Path::new("/repo/scripts/../other/b.sh"), "eval x\n", &report(&[(1, "eval-usage")]));
+        baseline.record(Path::new("/elsewhere/c.sh"), "eval x\n", &report(&[(1, "eval-usage")]));
+
+        let files: Vec<&str> = baseline.entries.keys().map(|entry| entry.file.as_str()).collect();
+        assert_eq!(files, ["../elsewhere/c.sh", "other/b.sh", "scripts/prod/a.sh"]);
+        assert_eq!(baseline.len(), 3);
+    }
+
+    #[test]
+    fn suppress_matches_shifted_lines_and_other_spellings_of_the_path() {
+        let mut baseline = Baseline::new(Path::new("/repo/bl.json"));
+        let content = "#!/bin/bash\necho $A\n";
+        baseline.record(Path::new("/repo/a.sh"), content, &report(&[(2, "unquoted-variable")]));
+
+        // 行が追加されて行番号がずれ、インデントが変わっても同じ問題として扱う
+        let shifted = "#!/bin/bash\n\n# comment\n    echo   $A\necho $B\n";
+        let mut new_report = report(&[(4, "unquoted-variable"), (5, "unquoted-variable")]);
+        assert_eq!(baseline.suppress(Path::new("/repo/sub/../a.sh"), shifted, &mut new_report), 1);
+        assert_eq!(rules(&new_report), [(5, "unquoted-variable")]);
+        assert!(baseline.fixed_entries().is_empty());
+    }
+
+    #[test]
+    fn duplicate_issues_are_counted() {
+        let mut baseline = Baseline::new(Path::new("/repo/bl.json"));
+        let content = "echo $A\necho $A\n";
+        baseline.record(Path::new("/repo/a.sh"), content, &report(&[(1, "unquoted-variable"), (2, "unquoted-variable")]));
+        assert_eq!(baseline.len(), 2);
+
+        // 3 つ目の同じ行は新しい問題
+        let content = "echo $A\necho $A\necho $A\n";
+        let mut new_report = report(&[(1, "unquoted-variable"), (2, "unquoted-variable"), (3, "unquoted-variable")]);
+        assert_eq!(baseline.suppress(Path::new("/repo/a.sh"), content, &mut new_report), 2);
+        assert_eq!(rules(&new_report), [(3, "unquoted-variable")]);
+    }
+
+    #[test]
+    fn fixed_entries_are_limited_to_checked_and_deleted_files() {
+        let dir = std::env::temp_dir().join(format!("shellchecker-baseline-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["checked.sh", "unchecked.sh"] {
+            fs::write(dir.join(name), "eval x\n").unwrap();
+        }
+
+        let mut baseline = Baseline::new(&dir.join("bl.json"));
+        for name in ["checked.sh", "unchecked.sh", "deleted.sh"] {
+            baseline.record(&dir.join(name), "eval x\n", &report(&[(1, "eval-usage"), (1, "eval-usage")]));
+        }
+        baseline.save(&dir.join("bl.json")).unwrap();
+
+        // 保存したベースラインを別のディレクトリからの相対パスで照合する
+        let mut baseline = Baseline::load(&dir.join("bl.json")).unwrap();
+        assert_eq!(baseline.len(), 6);
+        let mut new_report = report(&[(1, "eval-usage")]);
+        baseline.suppress(&dir.join("sub/../checked.sh"), "eval x\n", &mut new_report);
+        let fixed: Vec<&str> = baseline.fixed_entries().iter().map(|entry| entry.file.as_str()).collect();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(new_report.issues().is_empty());
+        assert_eq!(fixed, ["checked.sh", "deleted.sh", "deleted.sh"]);
+    }
+}
@@ -1,10 +1,12 @@
 // src/checker/best_practice.rs
+use crate::detect::Dialect;
 use crate::parser::ScriptParser;
-use crate::report::{Report, Severity};
+use crate::report::{Category, Fix, Report, Severity};
+use crate::rules;
 use crate::i18n::{
-    Language, CAT_BEST_PRACTICE, MSG_USE_SET_E, MSG_USE_SET_U,
+    Language, MSG_USE_SET_E, MSG_USE_SET_U,
     MSG_USE_SET_PIPEFAIL, MSG_UNQUOTED_VARIABLE, MSG_CD_WITHOUT_CHECK,
-    MSG_USE_DOLLAR_PAREN,
+    MSG_USE_DOLLAR_PAREN, FIX_CD_EXIT, FIX_DOLLAR_PAREN, FIX_QUOTE_VARIABLE, FIX_INSERT,
 };
 use regex::Regex;
 use lazy_static::lazy_static;
@@ -14,14 +16,14 @@ lazy_static! {
     static ref VARIABLE_USAGE: Regex = Regex::new(r"\$[A-Za-z_][A-Za-z0-9_]*").unwrap();
 }
 
-pub fn check(parser: &ScriptParser, report: &mut Report, language: &Language) {
-    check_set_options(parser, report, language);
+pub fn check(parser: &ScriptParser, report: &mut Report, language: &Language, dialect: &Dialect) {
+    check_set_options(parser, report, language, dialect);
     check_variable_quoting(parser, report, language);
     check_cd_without_check(parser, report, language);
     check_command_substitution_style(parser, report, language);
 }
 
-fn check_set_options(parser: &ScriptParser, report: &mut Report, language: &Language) {
+fn check_set_options(parser: &ScriptParser, report: &mut Report, language: &Language, dialect: &Dialect) {
     let mut has_set_e = false;
     let mut has_set_u = false;
     let mut has_set_pipefail = false;
@@ -42,29 +44,33 @@ fn check_set_options(parser: &ScriptParser, report: &mut Report, language: &Lang
         }
     }
 
-    if !has_set_e {
-        report.add_issue(
-            1,
-            Severity::Warning,
-            CAT_BEST_PRACTICE.get(language),
-            MSG_USE_SET_E.get(language)
-        );
-    }
-    if !has_set_u {
-        report.add_issue(
-            1,
-            Severity::Warning,
-            CAT_BEST_PRACTICE.get(language),
-            MSG_USE_SET_U.get(language)
-        );
-    }
-    if !has_set_pipefail {
-        report.add_issue(
-            1,
-            Severity::Warning,
-            CAT_BEST_PRACTICE.get(language),
-            MSG_USE_SET_PIPEFAIL.get(language)
-        );
+    let missing = [
+        (!has_set_e, rules::MISSING_SET_E, "set -e", &MSG_USE_SET_E.get(language)),
+        (!has_set_u, rules::MISSING_SET_U, "set -u", &MSG_USE_SET_U.get(language)),
+        // pipefail は dash などの POSIX sh では使用できない
+        (!has_set_pipefail && !dialect.is_posix(), rules::MISSING_PIPEFAIL, "set -o pipefail", &MSG_USE_SET_PIPEFAIL.get(language)),
+    ];
+
+    // シバン行があればその直後に set を挿入する修正案を付ける
+    let shebang = parser.lines().first().filter(|line| line.content.starts_with("#!"));
+    for (is_missing, rule, option, message) in missing {
+        if !is_missing {
+            continue;
+        }
+        match shebang {
+            Some(shebang) => report.add_issue_with_fix(
+                1,
+                rule,
+                Severity::Warning,
+                Category::BestPractice,
+                message,
+                Fix {
+                    title: FIX_INSERT.format(language, &[("text", (*option).into())]),
+                    replacement: format!("{}\n{}", shebang.content, option),
+                },
+            ),
+            None => report.add_issue(1, rule, Severity::Warning, Category::BestPractice, message),
+        }
     }
 }
 
@@ -99,11 +105,22 @@ fn check_variable_quoting(parser: &ScriptParser, report: &mut Report, language:
             let is_braced = var_usage.starts_with("${") || after_char == Some('}');
 
             if !is_quoted && !is_braced {
-                report.add_issue(
+                let replacement = format!(
+                    "{}\"{}\"{}",
+                    &line.content[..pos],
+                    var_usage,
+                    &line.content[after_pos..]
+                );
+                report.add_issue_with_fix(
                     line.number,
+                    rules::UNQUOTED_VARIABLE,
                     Severity::Warning,
-                    CAT_BEST_PRACTICE.get(language),
-                    MSG_UNQUOTED_VARIABLE.get(language)
+                    Category::BestPractice,
+                    &MSG_UNQUOTED_VARIABLE.get(language),
+                    Fix {
+                        title: FIX_QUOTE_VARIABLE.get(language),
+                        replacement,
+                    },
                 );
                 break; // 1行につき1回だけ警告
             }
@@ -116,7 +133,7 @@ fn check_cd_without_check(parser: &ScriptParser, report: &mut Report, language:
         if line.trimmed.starts_with("cd ") {
             // 次の行が || や if [ $? で始まっているかチェック
             let next_line = parser.lines().get(idx + 1);
-            let has_check = next_line.map_or(false, |nl| {
+            let has_check = next_line.is_some_and(|nl| {
                 nl.trimmed.starts_with("||") ||
                     nl.trimmed.starts_with("if") ||
                     line.content.contains("||") ||
@@ -124,12 +141,29 @@ fn check_cd_without_check(parser: &ScriptParser, report: &mut Report, language:
             });
 
             if !has_check {
-                report.add_issue(
-                    line.number,
-                    Severity::Warning,
-                    CAT_BEST_PRACTICE.get(language),
-                    MSG_CD_WITHOUT_CHECK.get(language)
-                );
+                let fix = Fix {
+                    title: FIX_CD_EXIT.get(language),
+                    replacement: format!("{} || exit 1", line.content.trim_end()),
+                };
+                // 行末コメントがある場合は修正案を付けない
+                if line.content.contains('#') {
+                    report.add_issue(
+                        line.number,
+                        rules::CD_WITHOUT_CHECK,
+                        Severity::Warning,
+                        Category::BestPractice,
+                        &MSG_CD_WITHOUT_CHECK.get(language)
+                    );
+                } else {
+                    report.add_issue_with_fix(
+                        line.number,
+                        rules::CD_WITHOUT_CHECK,
+                        Severity::Warning,
+                        Category::BestPractice,
+                        &MSG_CD_WITHOUT_CHECK.get(language),
+                        fix,
+                    );
+                }
             }
         }
     }
@@ -142,12 +176,45 @@ fn check_command_substitution_style(parser: &ScriptParser, report: &mut Report,
         }
 
         if line.content.contains("`") && !line.content.contains("\\`") {
-            report.add_issue(
-                line.number,
-                Severity::Info,
-                CAT_BEST_PRACTICE.get(language),
-                MSG_USE_DOLLAR_PAREN.get(language)
-            );
+            match replace_backticks(&line.content) {
+                Some(replacement) => report.add_issue_with_fix(
+                    line.number,
+                    rules::BACKTICK_SUBSTITUTION,
+                    Severity::Info,
+                    Category::BestPractice,
+                    &MSG_USE_DOLLAR_PAREN.get(language),
+                    Fix {
+                        title: FIX_DOLLAR_PAREN.get(language),
+                        replacement,
+                    },
+                ),
+                None => report.add_issue(
+                    line.number,
+                    rules::BACKTICK_SUBSTITUTION,
+                    Severity::Info,
+                    Category::BestPractice,
+                    &MSG_USE_DOLLAR_PAREN.get(language)
+                ),
+            }
         }
     }
-}
\ No newline at end of file
+}
+
+/// 対になったバッククォートを `$(` と `)` に置き換える（対にならない場合は None）
+fn replace_backticks(content: &str) -> Option<String> {
+    if !content.matches('`').count().is_multiple_of(2) {
+        return None;
+    }
+
+    let mut opened = false;
+    let mut result = String::with_capacity(content.len() + 2);
+    for c in content.chars() {
+        if c == '`' {
+            result.push_str(if opened { ")" } else { "$(" });
+            opened = !opened;
+        } else {
+            result.push(c);
+        }
+    }
+    Some(result)
+}
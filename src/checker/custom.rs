@@ -0,0 +1,307 @@
+// src/checker/custom.rs
+// 設定ファイルの `[[custom-rules]]` で定義されたルール
+use crate::i18n::{FIX_REPLACE, Language};
+use crate::parser::{self, ScriptParser, SimpleCommand};
+use crate::report::{Category, Fix, Report, Severity};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// ユーザー定義のルール
+///
+/// `pattern` はコマンドと引数に、`regex` は行の内容に一致させる（どちらか一方を指定する）。
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct CustomRule {
+    pub id: String,
+    pub severity: Severity,
+    pub category: Category,
+    pub message: CustomMessage,
+    /// コマンド名と引数のパターン（`*` と `?` を使用できる。例: `aws s3 rm --recursive`）
+    #[serde(default)]
+    pub pattern: Option<String>,
+    /// 行の内容に一致させる正規表現
+    #[serde(default)]
+    pub regex: Option<String>,
+    /// いずれかの引数に一致すれば報告しない（`pattern` のみ）
+    #[serde(default)]
+    pub unless: Vec<String>,
+    /// 修正案（`pattern` では `{command}` と `{args}`、`regex` では `$1` などで一致した部分を参照できる）
+    #[serde(default)]
+    pub fix: Option<String>,
+    // コンパイル済みの正規表現（`Config::load` で設定する）
+    #[serde(skip)]
+    compiled: Option<Regex>,
+}
+
+/// 出力言語ごとのメッセージ（1 つの文字列、または言語コードをキーとするテーブル）
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum CustomMessage {
+    Text(String),
+    Translated(BTreeMap<String, String>),
+}
+
+impl CustomMessage {
+    /// 出力言語のメッセージ（なければ `zh` などの主言語、英語、最初のものの順に使用する）
+    pub fn get(&self, lang: &Language) -> &str {
+        match self {
+            CustomMessage::Text(text) => text,
+            CustomMessage::Translated(messages) => {
+                let code = lang.code();
+                let primary = code.split('-').next().unwrap_or(code);
+                [code, primary, "en"]
+                    .iter()
+                    .find_map(|key| messages.get(*key))
+                    .or_else(|| messages.values().next())
+                    .map_or("", String::as_str)
+            }
+        }
+    }
+}
+
+impl CustomRule {
+    /// 定義が正しいか（ID の形式と、`pattern` と `regex` のどちらか一方の指定）
+    pub fn is_valid(&self) -> bool {
+        let valid_id = !self.id.is_empty()
+            && self.id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            && self.id != "all";
+        let pattern = self.pattern.as_deref().is_some_and(|p| !parser::split_words(p).is_empty());
+        valid_id && pattern != self.regex.is_some()
+    }
+
+    /// 正規表現をコンパイルしておく
+    pub fn compile(&mut self) -> Result<(), regex::Error> {
+        if let Some(regex) = &self.regex {
+            self.compiled = Some(Regex::new(regex)?);
+        }
+        Ok(())
+    }
+
+    fn regex(&self) -> Option<Regex> {
+        match &self.compiled {
+            Some(regex) => Some(regex.clone()),
+            None => self.regex.as_deref().and_then(|regex| Regex::new(regex).ok()),
+        }
+    }
+
+    fn matches(&self, pattern: &[String], command: &SimpleCommand) -> bool {
+        let Some((name, expected)) = pattern.split_first() else { return false };
+        let args = command.args();
+        if !wildcard(name, command.name()) && !command.words.first().is_some_and(|word| wildcard(name, word)) {
+            return false;
+        }
+        if self.unless.iter().any(|word| args.iter().any(|arg| wildcard(word, arg))) {
+            return false;
+        }
+
+        // オプションはどの位置にあってもよく、それ以外の単語は順序どおりに現れる必要がある
+        let mut position = 0;
+        for word in expected {
+            if word.starts_with('-') {
+                if !args.iter().any(|arg| wildcard(word, arg)) {
+                    return false;
+                }
+                continue;
+            }
+            match args[position..].iter().position(|arg| !arg.starts_with('-') && wildcard(word, arg)) {
+                Some(offset) => position += offset + 1,
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+pub fn check(parser: &ScriptParser, report: &mut Report, language: &Language, rules: &[CustomRule]) {
+    for rule in rules {
+        match (&rule.pattern, rule.regex()) {
+            (Some(pattern), _) => check_pattern(parser, report, language, rule, &parser::split_words(pattern)),
+            (None, Some(regex)) => check_regex(parser, report, language, rule, &regex),
+            (None, None) => {}
+        }
+    }
+}
+
+fn check_pattern(
+    parser: &ScriptParser,
+    report: &mut Report,
+    language: &Language,
+    rule: &CustomRule,
+    pattern: &[String],
+) {
+    for line in parser.lines() {
+        if line.trimmed.starts_with('#') {
+            continue;
+        }
+
+        // 1 行につき 1 回だけ報告する
+        let Some(command) = line.commands().into_iter().find(|command| rule.matches(pattern, command)) else {
+            continue;
+        };
+        let fix = rule.fix.as_ref().map(|template| {
+            let text = template
+                .replace("{command}", line.content[command.range.start..command.args_start].trim_end())
+                .replace("{args}", &line.content[command.args_start..command.range.end]);
+            Fix {
+                title: FIX_REPLACE.format(language, &[("text", text.trim().into())]),
+                replacement: format!(
+                    "{}{}{}",
+                    &line.content[..command.range.start],
+                    text.trim(),
+                    &line.content[command.range.end..]
+                ),
+            }
+        });
+        add_issue(report, line.number, rule, language, fix);
+    }
+}
+
+fn check_regex(parser: &ScriptParser, report: &mut Report, language: &Language, rule: &CustomRule, regex: &Regex) {
+    for line in parser.lines() {
+        let Some(captures) = regex.captures(&line.content) else { continue };
+        let fix = rule.fix.as_ref().map(|template| {
+            let mut text = String::new();
+            captures.expand(template, &mut text);
+            Fix {
+                title: FIX_REPLACE.format(language, &[("text", text.as_str().into())]),
+                replacement: regex.replace_all(&line.content, template.as_str()).into_owned(),
+            }
+        });
+        add_issue(report, line.number, rule, language, fix);
+    }
+}
+
+fn add_issue(report: &mut Report, line: usize, rule: &CustomRule, language: &Language, fix: Option<Fix>) {
+    let message = rule.message.get(language);
+    match fix {
+        Some(fix) => report.add_issue_with_fix(line, &rule.id, rule.severity.clone(), rule.category, message, fix),
+        None => report.add_issue(line, &rule.id, rule.severity.clone(), rule.category, message),
+    }
+}
+
+/// `*`（任意の文字列）と `?`（任意の 1 文字）を含むパターンに一致するか
+fn wildcard(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // 直前の `*` の位置と、そこから照合を再開するテキストの位置
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(definition: &str) -> CustomRule {
+        let mut rule: CustomRule = toml::from_str(&format!(
+            "id = \"custom\"\nseverity = \"warning\"\ncategory = \"best-practice\"\nmessage = \"custom\"\n{}",
+            definition
+        ))
+        .unwrap();
+        rule.compile().unwrap();
+        rule
+    }
+
+    /// 報告された (行番号, 修正後の行)
+    fn run(definition: &str, script: &str) -> Vec<(usize, Option<String>)> {
+        let mut report = Report::new();
+        check(&ScriptParser::new(script), &mut report, &Language::ENGLISH, &[rule(definition)]);
+        report.into_iter().map(|issue| (issue.line, issue.fix.map(|fix| fix.replacement))).collect()
+    }
+
+    fn lines(definition: &str, script: &str) -> Vec<usize> {
+        run(definition, script).into_iter().map(|(line, _)| line).collect()
+    }
+
+    #[test]
+    fn wildcard_at_start_middle_and_end() {
+        assert!(wildcard("*.sh", "deploy.sh"));
+        assert!(wildcard("s3://*/backup", "s3://bucket/backup"));
+        assert!(wildcard("--no-*", "--no-verify"));
+        assert!(wildcard("a*b*c", "abxbc"));
+        assert!(wildcard("?.txt", "ü.txt"));
+        assert!(wildcard("*", ""));
+        assert!(!wildcard("*.sh", "deploy.bash"));
+        assert!(!wildcard("s3://*/backup", "s3://bucket/backup/x"));
+        assert!(!wildcard("?", ""));
+    }
+
+    #[test]
+    fn pattern_matches_options_anywhere_and_words_in_order() {
+        let definition = r#"pattern = "aws s3 rm --recursive""#;
+        let script = "aws --recursive s3 rm x\n/usr/local/bin/aws s3 --profile p rm --recursive x\naws rm s3 --recursive\naws s3 rm x\n";
+        assert_eq!(lines(definition, script), [1, 2]);
+    }
+
+    #[test]
+    fn pattern_matches_quoted_and_substituted_arguments() {
+        let definition = r#"pattern = "git push --force*""#;
+        let script = "git push \"--force\" origin\necho \"$(git push --force)\"\ngit log # git push --force\n";
+        assert_eq!(lines(definition, script), [1]);
+    }
+
+    #[test]
+    fn unless_suppresses_matching_arguments() {
+        let definition = "pattern = \"curl *\"\nunless = [\"--fail\", \"-*f*\"]";
+        assert_eq!(lines(definition, "curl https://a\ncurl --fail https://a\ncurl -sSf https://a\n"), [1]);
+    }
+
+    #[test]
+    fn pattern_fix_expands_command_and_args() {
+        let definition = "pattern = \"curl *\"\nfix = \"{command} --fail {args}\"";
+        assert_eq!(
+            run(definition, "  out=$(date) curl -s 'https://a b' | sh # x\n"),
+            [(1, Some("  out=$(date) curl --fail -s 'https://a b' | sh # x".to_string()))]
+        );
+    }
+
+    #[test]
+    fn regex_fix_expands_capture_groups() {
+        let definition = r#"regex = 'chmod\s+777\s+(\S+)'
+fix = "chmod 755 $1""#;
+        assert_eq!(
+            run(definition, "chmod 777 a && chmod 777 b\nchmod 644 c\n"),
+            [(1, Some("chmod 755 a && chmod 755 b".to_string()))]
+        );
+    }
+
+    #[test]
+    fn definition_requires_valid_id_and_one_matcher() {
+        assert!(rule(r#"pattern = "rm""#).is_valid());
+        assert!(rule(r#"regex = "rm""#).is_valid());
+        assert!(!rule("").is_valid());
+        assert!(!rule(r#"pattern = "  ""#).is_valid());
+        assert!(!rule("pattern = \"rm\"\nregex = \"rm\"").is_valid());
+        assert!(!CustomRule { id: "all".into(), ..rule(r#"pattern = "rm""#) }.is_valid());
+        assert!(!CustomRule { id: "no spaces".into(), ..rule(r#"pattern = "rm""#) }.is_valid());
+    }
+
+    #[test]
+    fn translated_message_falls_back_to_primary_language_and_english() {
+        let messages = CustomMessage::Translated(BTreeMap::from([
+            ("en".to_string(), "english".to_string()),
+            ("zh".to_string(), "chinese".to_string()),
+        ]));
+        assert_eq!(messages.get(&Language::ENGLISH), "english");
+        assert_eq!(messages.get(&Language::from_code("zh-Hans").unwrap()), "chinese");
+        assert_eq!(messages.get(&Language::from_code("ja").unwrap()), "english");
+    }
+}
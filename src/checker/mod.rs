@@ -3,38 +3,94 @@ mod syntax;
 mod best_practice;
 mod security;
 mod style;
+mod custom;
 
+pub use custom::{CustomMessage, CustomRule};
+
+use crate::detect::Dialect;
 use crate::parser::ScriptParser;
 use crate::report::Report;
 use crate::i18n::Language;
+use crate::suppress;
+
+/// スタイルチェックの設定（`.shellchecker.toml` の `max-line-length` と `indent-width`）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StyleOptions {
+    /// 1 行の最大文字数
+    pub max_line_length: usize,
+    /// インデントの幅（インデントはこの倍数の空白にする）
+    pub indent_width: usize,
+}
+
+impl Default for StyleOptions {
+    fn default() -> Self {
+        StyleOptions {
+            max_line_length: 120,
+            indent_width: 2,
+        }
+    }
+}
 
+/// スクリプトの内容に全てのチェックを実行する
 pub struct Checker {
     parser: ScriptParser,
     language: Language,
+    dialect: Dialect,
+    style: StyleOptions,
+    custom_rules: Vec<CustomRule>,
 }
 
 impl Checker {
+    /// bash スクリプトとしてチェックするチェッカーを作成する
     pub fn new(content: &str, language: Language) -> Self {
         Checker {
             parser: ScriptParser::new(content),
             language,
+            dialect: Dialect::Bash,
+            style: StyleOptions::default(),
+            custom_rules: Vec::new(),
         }
     }
 
+    /// チェックするシェルの種類を指定する（POSIX sh では bash 固有の構文を報告する）
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// 行の長さとインデントの幅を指定する
+    pub fn with_style(mut self, style: StyleOptions) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// 組み込みのルールと一緒に実行するユーザー定義のルールを指定する
+    pub fn with_custom_rules(mut self, rules: Vec<CustomRule>) -> Self {
+        self.custom_rules = rules;
+        self
+    }
+
+    /// チェックを実行し、抑制コメントを反映したレポートを返す
     pub fn check(&self) -> Report {
         let mut report = Report::new();
 
         // 1. 構文チェック
-        syntax::check(&self.parser, &mut report, &self.language);
+        syntax::check(&self.parser, &mut report, &self.language, &self.dialect);
 
         // 2. ベストプラクティス検証
-        best_practice::check(&self.parser, &mut report, &self.language);
+        best_practice::check(&self.parser, &mut report, &self.language, &self.dialect);
 
         // 3. セキュリティチェック
         security::check(&self.parser, &mut report, &self.language);
 
         // 4. スタイルチェック
-        style::check(&self.parser, &mut report, &self.language);
+        style::check(&self.parser, &mut report, &self.language, &self.style);
+
+        // 5. ユーザー定義ルール
+        custom::check(&self.parser, &mut report, &self.language, &self.custom_rules);
+
+        // 抑制コメントで無効化された問題を取り除く
+        suppress::apply(&self.parser, &mut report);
 
         report
     }
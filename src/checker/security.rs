@@ -1,8 +1,9 @@
 // src/checker/security.rs
 use crate::parser::ScriptParser;
-use crate::report::{Report, Severity};
+use crate::report::{Category, Report, Severity};
+use crate::rules;
 use crate::i18n::{
-    Language, CAT_SECURITY, MSG_EVAL_DANGEROUS, MSG_CURL_PIPE_SH,
+    Language, MSG_EVAL_DANGEROUS, MSG_CURL_PIPE_SH,
     MSG_DANGEROUS_RM, MSG_USER_INPUT_IN_CMD,
 };
 
@@ -22,9 +23,10 @@ fn check_eval_usage(parser: &ScriptParser, report: &mut Report, language: &Langu
         if line.content.contains("eval ") {
             report.add_issue(
                 line.number,
+                rules::EVAL_USAGE,
                 Severity::Error,
-                CAT_SECURITY.get(language),
-                MSG_EVAL_DANGEROUS.get(language)
+                Category::Security,
+                &MSG_EVAL_DANGEROUS.get(language)
             );
         }
     }
@@ -37,9 +39,10 @@ fn check_curl_pipe_sh(parser: &ScriptParser, report: &mut Report, language: &Lan
             && (line.content.contains("sh") || line.content.contains("bash")) {
             report.add_issue(
                 line.number,
+                rules::CURL_PIPE_SHELL,
                 Severity::Error,
-                CAT_SECURITY.get(language),
-                MSG_CURL_PIPE_SH.get(language)
+                Category::Security,
+                &MSG_CURL_PIPE_SH.get(language)
             );
         }
     }
@@ -54,9 +57,10 @@ fn check_rm_rf_usage(parser: &ScriptParser, report: &mut Report, language: &Lang
         if line.content.contains("rm -rf /") || line.content.contains("rm -rf $") {
             report.add_issue(
                 line.number,
+                rules::DANGEROUS_RM,
                 Severity::Error,
-                CAT_SECURITY.get(language),
-                MSG_DANGEROUS_RM.get(language)
+                Category::Security,
+                &MSG_DANGEROUS_RM.get(language)
             );
         }
     }
@@ -73,9 +77,10 @@ fn check_user_input_in_commands(parser: &ScriptParser, report: &mut Report, lang
             && (line.content.contains("eval") || line.content.contains("$(") || line.content.contains("`")) {
             report.add_issue(
                 line.number,
+                rules::USER_INPUT_IN_COMMAND,
                 Severity::Warning,
-                CAT_SECURITY.get(language),
-                MSG_USER_INPUT_IN_CMD.get(language)
+                Category::Security,
+                &MSG_USER_INPUT_IN_CMD.get(language)
             );
         }
     }
@@ -1,9 +1,11 @@
 // src/checker/style.rs
+use super::StyleOptions;
 use crate::parser::ScriptParser;
-use crate::report::{Report, Severity};
+use crate::report::{Category, Fix, Report, Severity};
+use crate::rules;
 use crate::i18n::{
-    Language, CAT_STYLE, MSG_USE_SPACES, MSG_INCONSISTENT_INDENT,
-    msg_line_too_long, msg_function_naming, msg_variable_naming,
+    Language, MSG_USE_SPACES, MSG_INCONSISTENT_INDENT, FIX_TABS,
+    MSG_LINE_TOO_LONG, MSG_FUNCTION_NAMING, MSG_VARIABLE_NAMING,
 };
 use regex::Regex;
 use lazy_static::lazy_static;
@@ -11,16 +13,17 @@ use lazy_static::lazy_static;
 lazy_static! {
     static ref FUNCTION_DEF: Regex = Regex::new(r"^function\s+([a-zA-Z_][a-zA-Z0-9_]*)\s*\(").unwrap();
     static ref SIMPLE_FUNCTION_DEF: Regex = Regex::new(r"^([a-zA-Z_][a-zA-Z0-9_]*)\s*\(\s*\)").unwrap();
+    static ref VAR_ASSIGN: Regex = Regex::new(r"^\s*([A-Z][A-Z0-9_]*)\s*=").unwrap();
 }
 
-pub fn check(parser: &ScriptParser, report: &mut Report, language: &Language) {
-    check_indentation(parser, report, language);
-    check_line_length(parser, report, language);
+pub fn check(parser: &ScriptParser, report: &mut Report, language: &Language, options: &StyleOptions) {
+    check_indentation(parser, report, language, options.indent_width);
+    check_line_length(parser, report, language, options.max_line_length);
     check_function_naming(parser, report, language);
     check_variable_naming(parser, report, language);
 }
 
-fn check_indentation(parser: &ScriptParser, report: &mut Report, language: &Language) {
+fn check_indentation(parser: &ScriptParser, report: &mut Report, language: &Language, width: usize) {
     for line in parser.lines() {
         if line.trimmed.is_empty() || line.trimmed.starts_with('#') {
             continue;
@@ -30,36 +33,46 @@ fn check_indentation(parser: &ScriptParser, report: &mut Report, language: &Lang
 
         // タブ文字チェック
         if line.content.starts_with('\t') {
-            report.add_issue(
+            let body = line.content.trim_start_matches('\t');
+            let tabs = line.content.len() - body.len();
+            report.add_issue_with_fix(
                 line.number,
+                rules::TAB_INDENTATION,
                 Severity::Info,
-                CAT_STYLE.get(language),
-                MSG_USE_SPACES.get(language)
+                Category::Style,
+                &MSG_USE_SPACES.get(language),
+                Fix {
+                    title: FIX_TABS.get(language),
+                    replacement: format!("{}{}", " ".repeat(tabs * width.max(1)), body),
+                },
             );
         }
 
-        // インデントが2または4の倍数かチェック
-        if leading_spaces > 0 && leading_spaces % 2 != 0 {
+        // インデントが指定の幅の倍数かチェック
+        if leading_spaces > 0 && !leading_spaces.is_multiple_of(width.max(1)) {
             report.add_issue(
                 line.number,
+                rules::INCONSISTENT_INDENTATION,
                 Severity::Info,
-                CAT_STYLE.get(language),
-                MSG_INCONSISTENT_INDENT.get(language)
+                Category::Style,
+                &MSG_INCONSISTENT_INDENT.format(language, &[("width", width.into())])
             );
         }
     }
 }
 
-fn check_line_length(parser: &ScriptParser, report: &mut Report, language: &Language) {
-    const MAX_LINE_LENGTH: usize = 120;
-
+fn check_line_length(parser: &ScriptParser, report: &mut Report, language: &Language, max: usize) {
     for line in parser.lines() {
-        if line.content.len() > MAX_LINE_LENGTH {
+        if line.content.len() > max {
             report.add_issue(
                 line.number,
+                rules::LINE_TOO_LONG,
                 Severity::Info,
-                CAT_STYLE.get(language),
-                &msg_line_too_long(line.content.len(), MAX_LINE_LENGTH, language)
+                Category::Style,
+                &MSG_LINE_TOO_LONG.format(
+                    language,
+                    &[("length", line.content.len().into()), ("max", max.into())]
+                )
             );
         }
     }
@@ -72,9 +85,10 @@ fn check_function_naming(parser: &ScriptParser, report: &mut Report, language: &
             if func_name.chars().any(|c| c.is_uppercase()) {
                 report.add_issue(
                     line.number,
+                    rules::FUNCTION_NAMING,
                     Severity::Info,
-                    CAT_STYLE.get(language),
-                    &msg_function_naming(func_name, language)
+                    Category::Style,
+                    &MSG_FUNCTION_NAMING.format(language, &[("name", func_name.into())])
                 );
             }
         } else if let Some(caps) = SIMPLE_FUNCTION_DEF.captures(&line.content) {
@@ -82,9 +96,10 @@ fn check_function_naming(parser: &ScriptParser, report: &mut Report, language: &
             if func_name.chars().any(|c| c.is_uppercase()) {
                 report.add_issue(
                     line.number,
+                    rules::FUNCTION_NAMING,
                     Severity::Info,
-                    CAT_STYLE.get(language),
-                    &msg_function_naming(func_name, language)
+                    Category::Style,
+                    &MSG_FUNCTION_NAMING.format(language, &[("name", func_name.into())])
                 );
             }
         }
@@ -92,22 +107,21 @@ fn check_function_naming(parser: &ScriptParser, report: &mut Report, language: &
 }
 
 fn check_variable_naming(parser: &ScriptParser, report: &mut Report, language: &Language) {
-    let var_assign = Regex::new(r"^\s*([A-Z][A-Z0-9_]*)\s*=").unwrap();
-
     for line in parser.lines() {
         if line.trimmed.starts_with('#') {
             continue;
         }
 
-        if let Some(caps) = var_assign.captures(&line.content) {
+        if let Some(caps) = VAR_ASSIGN.captures(&line.content) {
             let var_name = &caps[1];
             // 環境変数風の大文字変数名は許容
             if var_name.len() < 3 || !var_name.chars().all(|c| c.is_uppercase() || c == '_') {
                 report.add_issue(
                     line.number,
+                    rules::VARIABLE_NAMING,
                     Severity::Info,
-                    CAT_STYLE.get(language),
-                    &msg_variable_naming(var_name, language)
+                    Category::Style,
+                    &MSG_VARIABLE_NAMING.format(language, &[("name", var_name.into())])
                 );
             }
         }
@@ -1,11 +1,13 @@
 // src/checker/syntax.rs
+use crate::detect::Dialect;
 use crate::parser::ScriptParser;
-use crate::report::{Report, Severity};
+use crate::report::{Category, Report, Severity};
+use crate::rules;
 use crate::i18n::{
-    Language, CAT_SYNTAX, MSG_MISSING_SHEBANG, MSG_INVALID_SHEBANG,
+    Language, MSG_MISSING_SHEBANG, MSG_INVALID_SHEBANG,
     MSG_UNMATCHED_BRACKET, MSG_UNCLOSED_BRACKET, MSG_UNCLOSED_BRACE,
     MSG_UNCLOSED_PAREN, MSG_UNCLOSED_SINGLE_QUOTE, MSG_UNCLOSED_DOUBLE_QUOTE,
-    MSG_UNCLOSED_VAR_EXPANSION, MSG_UNCLOSED_CMD_SUBST,
+    MSG_UNCLOSED_VAR_EXPANSION, MSG_UNCLOSED_CMD_SUBST, MSG_BASHISM,
 };
 use regex::Regex;
 use lazy_static::lazy_static;
@@ -13,13 +15,24 @@ use lazy_static::lazy_static;
 lazy_static! {
     static ref UNCLOSED_BRACE: Regex = Regex::new(r"\$\{[^}]*$").unwrap();
     static ref UNCLOSED_PAREN: Regex = Regex::new(r"\$\([^)]*$").unwrap();
+    static ref BASHISMS: Vec<(Regex, &'static str)> = vec![
+        (Regex::new(r"\[\[").unwrap(), "[[ ]]"),
+        (Regex::new(r"^\s*function\s+[A-Za-z_]").unwrap(), "function"),
+        (Regex::new(r"^\s*source\s").unwrap(), "source"),
+        (Regex::new(r"<<<").unwrap(), "<<<"),
+        (Regex::new(r"&>").unwrap(), "&>"),
+        (Regex::new(r"^\s*(local\s+)?[A-Za-z_][A-Za-z0-9_]*=\(").unwrap(), "array"),
+    ];
 }
 
-pub fn check(parser: &ScriptParser, report: &mut Report, language: &Language) {
+pub fn check(parser: &ScriptParser, report: &mut Report, language: &Language, dialect: &Dialect) {
     check_shebang(parser, report, language);
     check_brackets(parser, report, language);
     check_quotes(parser, report, language);
     check_variable_expansion(parser, report, language);
+    if dialect.is_posix() {
+        check_bashisms(parser, report, language);
+    }
 }
 
 fn check_shebang(parser: &ScriptParser, report: &mut Report, language: &Language) {
@@ -28,16 +41,18 @@ fn check_shebang(parser: &ScriptParser, report: &mut Report, language: &Language
         if !content.starts_with("#!") {
             report.add_issue(
                 1,
+                rules::MISSING_SHEBANG,
                 Severity::Error,
-                CAT_SYNTAX.get(language),
-                MSG_MISSING_SHEBANG.get(language)
+                Category::Syntax,
+                &MSG_MISSING_SHEBANG.get(language)
             );
         } else if !content.contains("bash") && !content.contains("sh") {
             report.add_issue(
                 1,
+                rules::INVALID_SHEBANG,
                 Severity::Warning,
-                CAT_SYNTAX.get(language),
-                MSG_INVALID_SHEBANG.get(language)
+                Category::Syntax,
+                &MSG_INVALID_SHEBANG.get(language)
             );
         }
     }
@@ -60,9 +75,10 @@ fn check_brackets(parser: &ScriptParser, report: &mut Report, language: &Languag
                     if bracket_stack.is_empty() {
                         report.add_issue(
                             line.number,
+                            rules::UNMATCHED_BRACKET,
                             Severity::Error,
-                            CAT_SYNTAX.get(language),
-                            MSG_UNMATCHED_BRACKET.get(language)
+                            Category::Syntax,
+                            &MSG_UNMATCHED_BRACKET.get(language)
                         );
                     } else {
                         bracket_stack.pop();
@@ -72,17 +88,13 @@ fn check_brackets(parser: &ScriptParser, report: &mut Report, language: &Languag
                     brace_stack.push((line.number, idx));
                 }
                 '}' => {
-                    if !brace_stack.is_empty() {
-                        brace_stack.pop();
-                    }
+                    brace_stack.pop();
                 }
                 '(' if idx == 0 || line.content.chars().nth(idx - 1) != Some('$') => {
                     paren_stack.push((line.number, idx));
                 }
                 ')' => {
-                    if !paren_stack.is_empty() {
-                        paren_stack.pop();
-                    }
+                    paren_stack.pop();
                 }
                 _ => {}
             }
@@ -90,13 +102,13 @@ fn check_brackets(parser: &ScriptParser, report: &mut Report, language: &Languag
     }
 
     for (line_num, _) in bracket_stack {
-        report.add_issue(line_num, Severity::Error, CAT_SYNTAX.get(language), MSG_UNCLOSED_BRACKET.get(language));
+        report.add_issue(line_num, rules::UNCLOSED_BRACKET, Severity::Error, Category::Syntax, &MSG_UNCLOSED_BRACKET.get(language));
     }
     for (line_num, _) in brace_stack {
-        report.add_issue(line_num, Severity::Error, CAT_SYNTAX.get(language), MSG_UNCLOSED_BRACE.get(language));
+        report.add_issue(line_num, rules::UNCLOSED_BRACE, Severity::Error, Category::Syntax, &MSG_UNCLOSED_BRACE.get(language));
     }
     for (line_num, _) in paren_stack {
-        report.add_issue(line_num, Severity::Error, CAT_SYNTAX.get(language), MSG_UNCLOSED_PAREN.get(language));
+        report.add_issue(line_num, rules::UNCLOSED_PAREN, Severity::Error, Category::Syntax, &MSG_UNCLOSED_PAREN.get(language));
     }
 }
 
@@ -127,17 +139,19 @@ fn check_quotes(parser: &ScriptParser, report: &mut Report, language: &Language)
         if in_single_quote {
             report.add_issue(
                 line.number,
+                rules::UNCLOSED_SINGLE_QUOTE,
                 Severity::Error,
-                CAT_SYNTAX.get(language),
-                MSG_UNCLOSED_SINGLE_QUOTE.get(language)
+                Category::Syntax,
+                &MSG_UNCLOSED_SINGLE_QUOTE.get(language)
             );
         }
         if in_double_quote {
             report.add_issue(
                 line.number,
+                rules::UNCLOSED_DOUBLE_QUOTE,
                 Severity::Error,
-                CAT_SYNTAX.get(language),
-                MSG_UNCLOSED_DOUBLE_QUOTE.get(language)
+                Category::Syntax,
+                &MSG_UNCLOSED_DOUBLE_QUOTE.get(language)
             );
         }
     }
@@ -152,18 +166,39 @@ fn check_variable_expansion(parser: &ScriptParser, report: &mut Report, language
         if UNCLOSED_BRACE.is_match(&line.content) {
             report.add_issue(
                 line.number,
+                rules::UNCLOSED_VAR_EXPANSION,
                 Severity::Error,
-                CAT_SYNTAX.get(language),
-                MSG_UNCLOSED_VAR_EXPANSION.get(language)
+                Category::Syntax,
+                &MSG_UNCLOSED_VAR_EXPANSION.get(language)
             );
         }
 
         if UNCLOSED_PAREN.is_match(&line.content) {
             report.add_issue(
                 line.number,
+                rules::UNCLOSED_CMD_SUBST,
                 Severity::Error,
-                CAT_SYNTAX.get(language),
-                MSG_UNCLOSED_CMD_SUBST.get(language)
+                Category::Syntax,
+                &MSG_UNCLOSED_CMD_SUBST.get(language)
+            );
+        }
+    }
+}
+
+fn check_bashisms(parser: &ScriptParser, report: &mut Report, language: &Language) {
+    for line in parser.lines() {
+        if line.trimmed.starts_with('#') {
+            continue;
+        }
+
+        // 1行につき最初に見つかった構文のみ報告
+        if let Some((_, construct)) = BASHISMS.iter().find(|(re, _)| re.is_match(&line.content)) {
+            report.add_issue(
+                line.number,
+                rules::BASHISM,
+                Severity::Warning,
+                Category::Syntax,
+                &MSG_BASHISM.format(language, &[("construct", (*construct).into())])
             );
         }
     }
@@ -0,0 +1,1038 @@
+// src/cli.rs
+// コマンドラインインターフェース
+use clap::error::{ContextKind, ContextValue, ErrorKind};
+use clap::builder::StyledStr;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use std::ffi::OsString;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+use std::thread;
+
+use crate::baseline::Baseline;
+use crate::config::{self, Config};
+use crate::git::{self, Changes};
+use crate::hook::{self, HookError};
+use crate::i18n::{
+    self, Language, Message, BASELINE_FIXED, BASELINE_SUPPRESSED, BASELINE_WRITTEN, CHECKING, ERROR_CATALOG,
+    ERROR_GIT_CHANGES, ERROR_HOOK_EXISTS, ERROR_INVALID_CONFIG, ERROR_INVALID_GLOB, ERROR_NOT_GIT_REPOSITORY,
+    ERROR_PATH_NOT_FOUND, ERROR_READING_BASELINE, ERROR_READING_FILE, ERROR_READING_STAGED_FILE, ERROR_READING_STDIN,
+    ERROR_STAGED_FILES, ERROR_UNKNOWN_RULE, ERROR_WALKING_DIRECTORY, ERROR_WATCH, ERROR_WRITING_BASELINE,
+    ERROR_WRITING_HOOK, HOOK_INSTALLED, SKIPPED, WATCH_LAST_CHECKED, WATCH_WAITING, ERROR_UNSUPPORTED_LANGUAGE,
+    ERROR_LANGUAGE_SERVER, ERROR_UNEXPECTED_ARGUMENT, ERROR_MISSING_VALUE, ERROR_INVALID_VALUE, ERROR_UNEXPECTED_VALUE,
+    ERROR_MISSING_ARGUMENTS, ERROR_ARGUMENT_CONFLICT, ERROR_ARGUMENT_REPEATED, ERROR_UNKNOWN_SUBCOMMAND,
+    POSSIBLE_VALUES, DID_YOU_MEAN, TRY_HELP, HELP_ABOUT, HELP_VERSION_INFO, HELP_USAGE, HELP_COMMANDS, HELP_ARGUMENTS,
+    HELP_OPTIONS, HELP_HELP, HELP_VERSION, HELP_HELP_COMMAND, HELP_HOOK, HELP_HOOK_INSTALL, HELP_HOOK_INSTALL_FORCE,
+    HELP_HOOK_INSTALL_ARGS, HELP_LSP, HELP_EXPLAIN, HELP_EXPLAIN_RULE, HELP_EXPLAIN_MARKDOWN, HELP_PATHS,
+    HELP_STDIN_FILENAME, HELP_RECURSIVE, HELP_NO_IGNORE, HELP_HIDDEN, HELP_INCLUDE, HELP_EXCLUDE, HELP_LIST_FILES,
+    HELP_LIST_RULES, HELP_CHANGED_SINCE, HELP_DIFF_LINES_ONLY, HELP_STAGED, HELP_WATCH, HELP_JOBS, HELP_SEVERITY,
+    HELP_ERRORS_ONLY, HELP_CATEGORY, HELP_EXCLUDE_CATEGORY, HELP_RULE, HELP_EXCLUDE_RULE, HELP_LANGUAGE, HELP_BASELINE,
+    HELP_WRITE_BASELINE, HELP_FAIL_ON, HELP_MAX_WARNINGS, HELP_PROFILE,
+};
+use crate::profile::Profile;
+use crate::report::{Category, Report, Severity};
+use crate::runner::{self, Job, Outcome};
+use crate::scan::{self, Patterns, ScanOptions};
+use crate::watch::{self, FileWatcher};
+use crate::{detect, explain, lsp};
+
+// 終了コード
+const EXIT_OK: i32 = 0;
+const EXIT_ISSUES: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+const EXIT_IO: i32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum FailOn {
+    Error,
+    Warning,
+    Info,
+    None,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    Hook {
+        #[command(subcommand)]
+        action: HookAction,
+    },
+    Lsp,
+    Explain {
+        #[arg(required_unless_present = "markdown")]
+        rule: Option<String>,
+
+        #[arg(long, conflicts_with = "rule")]
+        markdown: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum HookAction {
+    Install {
+        #[arg(last = true, value_name = "OPTIONS")]
+        args: Vec<String>,
+
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+// ヘルプの文言は実行時に出力言語のカタログから設定する（`localized_command`）
+//
+// 見出しは引数の初出順に表示されるため、位置引数はオプションより前に定義する。
+#[derive(Parser, Debug)]
+#[command(author, version, subcommand_negates_reqs = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[arg(value_name = "PATH", required_unless_present_any = ["changed_since", "staged", "list_rules"])]
+    paths: Vec<PathBuf>,
+
+    #[arg(long, value_name = "NAME")]
+    stdin_filename: Option<PathBuf>,
+
+    #[arg(short, long)]
+    recursive: bool,
+
+    #[arg(long)]
+    no_ignore: bool,
+
+    #[arg(long)]
+    hidden: bool,
+
+    #[arg(long, value_name = "GLOB")]
+    include: Vec<String>,
+
+    #[arg(long, value_name = "GLOB")]
+    exclude: Vec<String>,
+
+    #[arg(long)]
+    list_files: bool,
+
+    #[arg(long)]
+    list_rules: bool,
+
+    #[arg(long, value_name = "REV")]
+    changed_since: Option<String>,
+
+    #[arg(long, requires = "changed_since")]
+    diff_lines_only: bool,
+
+    #[arg(long, conflicts_with = "changed_since")]
+    staged: bool,
+
+    #[arg(short, long, conflicts_with_all = ["write_baseline", "list_files", "changed_since", "staged"])]
+    watch: bool,
+
+    #[arg(short, long, value_name = "N")]
+    jobs: Option<usize>,
+
+    #[arg(long, value_enum, value_name = "LEVEL")]
+    severity: Option<Severity>,
+
+    #[arg(short, long, hide = true, conflicts_with = "severity")]
+    errors_only: bool,
+
+    #[arg(long, value_enum, value_name = "CATEGORY", value_delimiter = ',')]
+    category: Vec<Category>,
+
+    #[arg(long, value_enum, value_name = "CATEGORY", value_delimiter = ',')]
+    exclude_category: Vec<Category>,
+
+    #[arg(long, value_name = "RULE", value_delimiter = ',')]
+    rule: Vec<String>,
+
+    #[arg(long, value_name = "RULE", value_delimiter = ',')]
+    exclude_rule: Vec<String>,
+
+    #[arg(long, value_enum, value_name = "PROFILE")]
+    profile: Option<Profile>,
+
+    #[arg(short, long, global = true)]
+    language: Option<String>,
+
+    #[arg(long, value_name = "FILE")]
+    baseline: Option<PathBuf>,
+
+    #[arg(long, value_name = "FILE", conflicts_with = "baseline")]
+    write_baseline: Option<PathBuf>,
+
+    #[arg(long, value_enum, value_name = "LEVEL", default_value = "error")]
+    fail_on: FailOn,
+
+    #[arg(long, value_name = "N")]
+    max_warnings: Option<usize>,
+}
+
+struct Context {
+    cli_config: Config,
+    configs: HashMap<PathBuf, Option<Config>>,
+    language: Language,
+    baseline: Option<Baseline>,
+    new_baseline: Option<Baseline>,
+    errors: usize,
+    warnings: usize,
+    infos: usize,
+    io_failed: bool,
+    printed_any: bool,
+    changed_lines: Option<Changes>,
+}
+
+impl Context {
+    /// ファイルに適用する設定を解決する（`[[overrides]]` を重ね合わせ、コマンドライン引数を最優先にする）
+    fn config_for(&mut self, path: &Path) -> Config {
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        let dir = std::path::absolute(dir).unwrap_or_else(|_| dir.to_path_buf());
+
+        let language = &self.language;
+        let file_config = self
+            .configs
+            .entry(dir.clone())
+            .or_insert_with(|| {
+                Config::find(&dir).map(|config_path| match Config::load(&config_path) {
+                    Ok(config) => config,
+                    Err(e) => {
+                        let error = e.message(language);
+                        eprintln!("{}", ERROR_INVALID_CONFIG.format(language, &[("error", error.into())]));
+                        std::process::exit(EXIT_USAGE);
+                    }
+                })
+            });
+
+        match file_config {
+            Some(file_config) => file_config.resolve(path).merge(&self.cli_config),
+            None => self.cli_config.clone(),
+        }
+    }
+
+    fn job(&mut self, path: &Path, content: Option<String>, detect: bool) -> Job {
+        Job {
+            path: path.to_path_buf(),
+            content,
+            detect,
+            config: self.config_for(path),
+        }
+    }
+
+    fn add_counts(&mut self, report: &Report) {
+        self.errors += report.count(&Severity::Error);
+        self.warnings += report.count(&Severity::Warning);
+        self.infos += report.count(&Severity::Info);
+    }
+
+    fn exit_code(&self, fail_on: FailOn, max_warnings: Option<usize>) -> i32 {
+        if self.io_failed {
+            return EXIT_IO;
+        }
+
+        let threshold_reached = match fail_on {
+            FailOn::Error => self.errors > 0,
+            FailOn::Warning => self.errors + self.warnings > 0,
+            FailOn::Info => self.errors + self.warnings + self.infos > 0,
+            FailOn::None => false,
+        };
+        let over_budget = max_warnings.is_some_and(|max| self.warnings > max);
+
+        if threshold_reached || over_budget {
+            EXIT_ISSUES
+        } else {
+            EXIT_OK
+        }
+    }
+}
+
+/// コマンドライン引数を解析して実行し、終了コードでプロセスを終了する
+pub fn main() {
+    let raw_args: Vec<OsString> = std::env::args_os().collect();
+
+    // 追加のメッセージカタログは言語の判定より前に読み込む
+    let catalog_errors = match std::env::var_os(i18n::LOCALE_DIR_ENV) {
+        Some(dir) => i18n::load_catalogs(Path::new(&dir)),
+        None => Vec::new(),
+    };
+
+    // ヘルプと引数のエラーも出力言語で表示するため、言語は引数の解析より前に決める
+    let default_language = || config_language().or_else(Language::from_env).unwrap_or_default();
+    let language = match language_arg(&raw_args) {
+        Some(code) => match Language::from_code(&code) {
+            Some(language) => language,
+            None => {
+                eprintln!(
+                    "{}",
+                    ERROR_UNSUPPORTED_LANGUAGE.format(
+                        &default_language(),
+                        &[("language", code.into()), ("available", Language::available().join(", ").into())]
+                    )
+                );
+                std::process::exit(EXIT_USAGE);
+            }
+        },
+        None => default_language(),
+    };
+
+    for (path, error) in catalog_errors {
+        eprintln!(
+            "{}",
+            ERROR_CATALOG.format(&language, &[("path", path.display().to_string().into()), ("error", error.into())])
+        );
+    }
+
+    let args = parse_args(raw_args, &language);
+
+    if let Some(Command::Lsp) = &args.command {
+        // 言語が指定されていなければ文書ごとの設定ファイルに従う
+        if let Err(e) = lsp::run(args.language.as_ref().map(|_| language.clone())) {
+            eprintln!("{}", ERROR_LANGUAGE_SERVER.format(&language, &[("error", e.to_string().into())]));
+            std::process::exit(EXIT_IO);
+        }
+        std::process::exit(EXIT_OK);
+    }
+
+    if let Some(Command::Explain { rule, markdown }) = &args.command {
+        let output = match rule {
+            Some(rule) => explain::explain(rule, &language).unwrap_or_else(|| {
+                eprintln!("{}", ERROR_UNKNOWN_RULE.format(&language, &[("rule", rule.as_str().into())]));
+                std::process::exit(EXIT_USAGE);
+            }),
+            None if *markdown => explain::markdown(&language),
+            None => unreachable!("clap requires a rule or --markdown"),
+        };
+        print!("{}", output);
+        std::process::exit(EXIT_OK);
+    }
+
+    if args.list_rules {
+        print!("{}", explain::list_rules(&language));
+        std::process::exit(EXIT_OK);
+    }
+
+    // ユーザー定義のルールはカレントディレクトリの設定ファイルで定義されたものを受け付ける
+    let current_config = current_config().unwrap_or_default();
+    for rule in args.rule.iter().chain(&args.exclude_rule) {
+        if !current_config.is_known_rule(rule) {
+            eprintln!("{}", ERROR_UNKNOWN_RULE.format(&language, &[("rule", rule.as_str().into())]));
+            std::process::exit(EXIT_USAGE);
+        }
+    }
+
+    let cli_config = Config {
+        extends: args.profile,
+        language: args.language.clone(),
+        severity: if args.errors_only { Some(Severity::Error) } else { args.severity.clone() },
+        categories: args.category.clone(),
+        exclude_categories: args.exclude_category.clone(),
+        rules: args.rule.clone(),
+        exclude_rules: args.exclude_rule.clone(),
+        ..Config::default()
+    };
+
+    if let Some(Command::Hook { action: HookAction::Install { force, args: hook_args } }) = &args.command {
+        install_hook(*force, hook_args, &language);
+    }
+
+    let baseline = match &args.baseline {
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!(
+                    "{}",
+                    ERROR_READING_BASELINE.format(
+                        &language,
+                        &[("path", format!("{:?}", path).into()), ("error", e.to_string().into())]
+                    )
+                );
+                std::process::exit(EXIT_USAGE);
+            }
+        },
+        None => None,
+    };
+
+    let mut ctx = Context {
+        cli_config,
+        configs: HashMap::new(),
+        language,
+        baseline,
+        new_baseline: args.write_baseline.as_deref().map(Baseline::new),
+        errors: 0,
+        warnings: 0,
+        infos: 0,
+        io_failed: false,
+        printed_any: false,
+        changed_lines: None,
+    };
+
+    let patterns = Patterns::new(&args.include).and_then(|include| {
+        Patterns::new(&args.exclude).map(|exclude| (include, exclude))
+    });
+    let (include, exclude) = match patterns {
+        Ok(patterns) => patterns,
+        Err(e) => {
+            eprintln!("{}", ERROR_INVALID_GLOB.format(&ctx.language, &[("error", e.to_string().into())]));
+            std::process::exit(EXIT_USAGE);
+        }
+    };
+    let scan_options = ScanOptions {
+        recursive: args.recursive,
+        no_ignore: args.no_ignore,
+        hidden: args.hidden,
+        include,
+        exclude,
+    };
+
+    let mut jobs = Vec::new();
+    if let Some(rev) = &args.changed_since {
+        let changes = match Changes::since(rev) {
+            Ok(changes) => changes,
+            Err(e) => {
+                eprintln!(
+                    "{}",
+                    ERROR_GIT_CHANGES.format(
+                        &ctx.language,
+                        &[("rev", rev.to_string().into()), ("error", e.to_string().into())]
+                    )
+                );
+                std::process::exit(EXIT_USAGE);
+            }
+        };
+        collect_changed(&changes, &args.paths, &scan_options, &mut ctx, &mut jobs);
+        if args.diff_lines_only {
+            ctx.changed_lines = Some(changes);
+        }
+    }
+
+    if args.staged {
+        collect_staged(&args.paths, &scan_options, &mut ctx, &mut jobs);
+    }
+
+    for path in args.paths.iter().filter(|_| args.changed_since.is_none() && !args.staged) {
+        if path.as_os_str() == "-" {
+            collect_stdin(args.stdin_filename.as_deref(), &mut ctx, &mut jobs);
+        } else if path.is_file() {
+            collect_file(path, false, &mut ctx, &mut jobs);
+        } else if path.is_dir() {
+            collect_directory(path, &scan_options, &mut ctx, &mut jobs);
+        } else {
+            eprintln!("{}", ERROR_PATH_NOT_FOUND.format(&ctx.language, &[("path", format!("{:?}", path).into())]));
+            ctx.io_failed = true;
+        }
+    }
+
+    let threads = args
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+    let paths: Vec<PathBuf> = jobs.iter().map(|job| job.path.clone()).collect();
+    let outcomes = runner::run(jobs, threads, &ctx.language, args.list_files);
+    if args.watch {
+        watch_loop(&args, &scan_options, &mut ctx, paths.into_iter().zip(outcomes).collect(), threads);
+    }
+
+    for (path, outcome) in paths.iter().zip(outcomes) {
+        if args.list_files {
+            list_outcome(path, outcome, &mut ctx);
+        } else {
+            report_outcome(path, outcome, &mut ctx);
+        }
+    }
+
+    if args.list_files {
+        std::process::exit(if ctx.io_failed { EXIT_IO } else { EXIT_OK });
+    }
+
+    if let (Some(path), Some(new_baseline)) = (&args.write_baseline, &ctx.new_baseline) {
+        if let Err(e) = new_baseline.save(path) {
+            eprintln!(
+                "{}",
+                ERROR_WRITING_BASELINE.format(
+                    &ctx.language,
+                    &[("path", format!("{:?}", path).into()), ("error", e.to_string().into())]
+                )
+            );
+            std::process::exit(EXIT_IO);
+        }
+        println!(
+            "{}",
+            BASELINE_WRITTEN.format(
+                &ctx.language,
+                &[("path", path.display().to_string().into()), ("count", new_baseline.len().into())]
+            )
+        );
+        std::process::exit(EXIT_OK);
+    }
+
+    if let Some(baseline) = &ctx.baseline {
+        let fixed = baseline.fixed_entries();
+        if !fixed.is_empty() {
+            println!("{}", BASELINE_FIXED.get(&ctx.language));
+            for entry in fixed {
+                println!("  {}: {}", entry.file, entry.rule);
+            }
+        }
+    }
+
+    std::process::exit(ctx.exit_code(args.fail_on, args.max_warnings));
+}
+
+/// カレントディレクトリから探索した設定ファイル（読み込めない場合は None）
+fn current_config() -> Option<Config> {
+    let config_path = Config::find(&std::path::absolute(".").ok()?)?;
+    Config::load(&config_path).ok()
+}
+
+/// カレントディレクトリの設定ファイルで指定された出力言語
+fn config_language() -> Option<Language> {
+    current_config()?.language()
+}
+
+/// 引数の解析より前に `-l` / `--language` の値を取り出す（clap と同じ書式を受け付ける）
+fn language_arg(raw_args: &[OsString]) -> Option<String> {
+    let mut language = None;
+    let mut args = raw_args.iter().skip(1).filter_map(|arg| arg.to_str());
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        }
+        if let Some(rest) = arg.strip_prefix("--language") {
+            match rest.strip_prefix('=') {
+                Some(value) => language = Some(value.to_string()),
+                None if rest.is_empty() => language = args.next().map(str::to_string),
+                None => {}
+            }
+        } else if let Some(shorts) = arg.strip_prefix('-').filter(|shorts| !shorts.starts_with('-')) {
+            // 短いオプションはまとめて指定できる（値を取る -j より後ろはその値）
+            for (i, c) in shorts.char_indices() {
+                match c {
+                    'l' => {
+                        let value = &shorts[i + 1..];
+                        language = match value {
+                            "" => args.next().map(str::to_string),
+                            _ => Some(value.strip_prefix('=').unwrap_or(value).to_string()),
+                        };
+                        break;
+                    }
+                    'j' => break,
+                    _ => {}
+                }
+            }
+        }
+    }
+    language
+}
+
+/// 引数を解析する（ヘルプ、バージョン、引数のエラーを表示した場合は終了する）
+fn parse_args(raw_args: Vec<OsString>, language: &Language) -> Args {
+    let mut command = localized_command(language);
+    let error = match command.try_get_matches_from_mut(raw_args) {
+        Ok(matches) => match Args::from_arg_matches(&matches) {
+            Ok(args) => return args,
+            Err(e) => e,
+        },
+        Err(e) => e,
+    };
+
+    match error.kind() {
+        ErrorKind::DisplayHelp => {
+            let _ = error.print();
+            std::process::exit(EXIT_OK);
+        }
+        ErrorKind::DisplayVersion => {
+            println!("{}", HELP_VERSION_INFO.format(language, &[("version", env!("CARGO_PKG_VERSION").into())]));
+            std::process::exit(EXIT_OK);
+        }
+        _ => match usage_error(&error, language) {
+            Some(message) => eprint!("{}", message),
+            // 翻訳のない種類のエラーは clap の英語の文言で表示する
+            None => {
+                let _ = error.print();
+            }
+        },
+    }
+    std::process::exit(EXIT_USAGE);
+}
+
+/// ヘルプの文言を出力言語に置き換えたコマンドの定義
+fn localized_command(language: &Language) -> clap::Command {
+    let mut command = Args::command();
+    // 自動で追加される --help、--version と help サブコマンドも置き換えるため、先に構築しておく
+    command.build();
+    localize(command, language)
+}
+
+fn localize(mut command: clap::Command, language: &Language) -> clap::Command {
+    let name = command.get_name().to_string();
+    let usage = usage_line(&command.render_usage(), language);
+    if let Some(about) = command_about(&name) {
+        command = command.about(about.get(language));
+    }
+
+    command
+        .help_template(format!("{{about-with-newline}}\n{}\n\n{{all-args}}", usage))
+        .subcommand_help_heading(HELP_COMMANDS.get(language))
+        // 構築後に引数の順序を変えると解析できなくなるため、その場で書き換える
+        .mut_args(|arg| {
+            let heading = if arg.is_positional() { HELP_ARGUMENTS } else { HELP_OPTIONS };
+            let help = arg_help(&name, arg.get_id().as_str());
+            // 既定値と指定できる値は翻訳した説明に含める
+            let arg = arg
+                .help_heading(heading.get(language))
+                .hide_default_value(true)
+                .hide_possible_values(true);
+            match help {
+                Some(help) => arg.help(help.get(language)),
+                None => arg,
+            }
+        })
+        .mut_subcommands(|subcommand| localize(subcommand, language))
+}
+
+fn command_about(command: &str) -> Option<Message> {
+    let about = match command {
+        "shellchecker" => HELP_ABOUT,
+        "hook" => HELP_HOOK,
+        "install" => HELP_HOOK_INSTALL,
+        "lsp" => HELP_LSP,
+        "explain" => HELP_EXPLAIN,
+        "help" => HELP_HELP_COMMAND,
+        _ => return None,
+    };
+    Some(about)
+}
+
+fn arg_help(command: &str, arg: &str) -> Option<Message> {
+    let help = match (command, arg) {
+        (_, "help") => HELP_HELP,
+        (_, "version") => HELP_VERSION,
+        (_, "language") => HELP_LANGUAGE,
+        ("install", "force") => HELP_HOOK_INSTALL_FORCE,
+        ("install", "args") => HELP_HOOK_INSTALL_ARGS,
+        ("explain", "rule") => HELP_EXPLAIN_RULE,
+        ("explain", "markdown") => HELP_EXPLAIN_MARKDOWN,
+        ("shellchecker", arg) => match arg {
+            "paths" => HELP_PATHS,
+            "stdin_filename" => HELP_STDIN_FILENAME,
+            "recursive" => HELP_RECURSIVE,
+            "no_ignore" => HELP_NO_IGNORE,
+            "hidden" => HELP_HIDDEN,
+            "include" => HELP_INCLUDE,
+            "exclude" => HELP_EXCLUDE,
+            "list_files" => HELP_LIST_FILES,
+            "list_rules" => HELP_LIST_RULES,
+            "changed_since" => HELP_CHANGED_SINCE,
+            "diff_lines_only" => HELP_DIFF_LINES_ONLY,
+            "staged" => HELP_STAGED,
+            "watch" => HELP_WATCH,
+            "jobs" => HELP_JOBS,
+            "severity" => HELP_SEVERITY,
+            "errors_only" => HELP_ERRORS_ONLY,
+            "category" => HELP_CATEGORY,
+            "exclude_category" => HELP_EXCLUDE_CATEGORY,
+            "rule" => HELP_RULE,
+            "exclude_rule" => HELP_EXCLUDE_RULE,
+            "profile" => HELP_PROFILE,
+            "baseline" => HELP_BASELINE,
+            "write_baseline" => HELP_WRITE_BASELINE,
+            "fail_on" => HELP_FAIL_ON,
+            "max_warnings" => HELP_MAX_WARNINGS,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(help)
+}
+
+/// clap の「Usage: 」を翻訳し、2 行目以降の字下げを見出しの幅に合わせる
+fn usage_line(usage: &StyledStr, language: &Language) -> String {
+    let label = HELP_USAGE.get(language);
+    let usage = usage.to_string();
+    let usage = usage.strip_prefix("Usage:").unwrap_or(&usage);
+    let indent = format!("\n{}", " ".repeat(explain::display_width(&label) + 2));
+    let lines: Vec<&str> = usage.lines().map(str::trim).collect();
+    format!("{}: {}", label, lines.join(&indent))
+}
+
+/// 引数のエラーを出力言語で組み立てる（翻訳のない種類のエラーは None）
+fn usage_error(error: &clap::Error, language: &Language) -> Option<String> {
+    let string = |kind| match error.get(kind) {
+        Some(ContextValue::String(value)) => Some(value.as_str()),
+        _ => None,
+    };
+    let strings = |kind| match error.get(kind) {
+        Some(ContextValue::String(value)) => vec![value.clone()],
+        Some(ContextValue::Strings(values)) => values.clone(),
+        _ => Vec::new(),
+    };
+    let arg = || string(ContextKind::InvalidArg);
+    let value = || string(ContextKind::InvalidValue);
+
+    let mut message = match error.kind() {
+        ErrorKind::UnknownArgument => ERROR_UNEXPECTED_ARGUMENT.format(language, &[("arg", arg()?.into())]),
+        ErrorKind::InvalidValue if value()?.is_empty() => {
+            ERROR_MISSING_VALUE.format(language, &[("arg", arg()?.into())])
+        }
+        ErrorKind::InvalidValue | ErrorKind::ValueValidation => {
+            ERROR_INVALID_VALUE.format(language, &[("value", value()?.into()), ("arg", arg()?.into())])
+        }
+        ErrorKind::TooManyValues => {
+            ERROR_UNEXPECTED_VALUE.format(language, &[("value", value()?.into()), ("arg", arg()?.into())])
+        }
+        ErrorKind::MissingRequiredArgument => {
+            let mut message = ERROR_MISSING_ARGUMENTS.get(language);
+            for arg in strings(ContextKind::InvalidArg) {
+                message.push_str(&format!("\n  {}", arg));
+            }
+            message
+        }
+        ErrorKind::ArgumentConflict => {
+            let prior = strings(ContextKind::PriorArg);
+            if prior.is_empty() {
+                return None;
+            } else if prior == [arg()?] {
+                ERROR_ARGUMENT_REPEATED.format(language, &[("arg", arg()?.into())])
+            } else {
+                ERROR_ARGUMENT_CONFLICT
+                    .format(language, &[("arg", arg()?.into()), ("other", prior.join("', '").into())])
+            }
+        }
+        ErrorKind::InvalidSubcommand => ERROR_UNKNOWN_SUBCOMMAND
+            .format(language, &[("subcommand", string(ContextKind::InvalidSubcommand)?.into())]),
+        _ => return None,
+    };
+
+    let values = strings(ContextKind::ValidValue);
+    if !values.is_empty() {
+        message.push_str(&format!("\n  {}", POSSIBLE_VALUES.format(language, &[("values", values.join(", ").into())])));
+    }
+    let suggestion = strings(ContextKind::SuggestedArg)
+        .into_iter()
+        .chain(strings(ContextKind::SuggestedSubcommand))
+        .next();
+    if let Some(suggestion) = suggestion {
+        message.push_str(&format!("\n\n  {}", DID_YOU_MEAN.format(language, &[("suggestion", suggestion.into())])));
+    }
+    if let Some(ContextValue::StyledStr(usage)) = error.get(ContextKind::Usage) {
+        message.push_str(&format!("\n\n{}", usage_line(usage, language)));
+    }
+    Some(format!("{}\n\n{}\n", message, TRY_HELP.get(language)))
+}
+
+fn collect_file(path: &Path, detect: bool, ctx: &mut Context, jobs: &mut Vec<Job>) {
+    jobs.push(ctx.job(path, None, detect));
+}
+
+fn collect_stdin(filename: Option<&Path>, ctx: &mut Context, jobs: &mut Vec<Job>) {
+    let mut content = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut content) {
+        eprintln!("{}", ERROR_READING_STDIN.format(&ctx.language, &[("error", e.to_string().into())]));
+        ctx.io_failed = true;
+        return;
+    }
+
+    // ファイル名が指定されていればレポートと設定の解決に使用する
+    let path = filename.unwrap_or(Path::new("<stdin>"));
+    jobs.push(ctx.job(path, Some(content), false));
+}
+
+fn collect_directory(path: &Path, options: &ScanOptions, ctx: &mut Context, jobs: &mut Vec<Job>) {
+    let (files, errors) = scan::scan(path, options);
+
+    for e in errors {
+        eprintln!("{}", ERROR_WALKING_DIRECTORY.format(&ctx.language, &[("error", e.to_string().into())]));
+        ctx.io_failed = true;
+    }
+
+    for file in files {
+        collect_file(&file, true, ctx, jobs);
+    }
+}
+
+/// 指定パス配下にあり、`--include` / `--exclude` に合致するか（git から得たファイル用）
+fn is_selected(path: &Path, roots: &[PathBuf], options: &ScanOptions) -> bool {
+    let in_roots = roots.is_empty()
+        || roots.iter().any(|root| {
+            let root: PathBuf = root.components().filter(|c| *c != Component::CurDir).collect();
+            path.starts_with(root)
+        });
+    let included = options.include.is_empty() || options.include.matches(path);
+    let excluded = !options.exclude.is_empty()
+        && path.ancestors().any(|p| !p.as_os_str().is_empty() && options.exclude.matches(p));
+
+    in_roots && included && !excluded
+}
+
+fn collect_changed(changes: &Changes, roots: &[PathBuf], options: &ScanOptions, ctx: &mut Context, jobs: &mut Vec<Job>) {
+    for path in changes.files() {
+        if is_selected(path, roots, options) && path.is_file() {
+            collect_file(path, true, ctx, jobs);
+        }
+    }
+}
+
+fn collect_staged(roots: &[PathBuf], options: &ScanOptions, ctx: &mut Context, jobs: &mut Vec<Job>) {
+    let files = match git::staged_files() {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("{}", ERROR_STAGED_FILES.format(&ctx.language, &[("error", e.to_string().into())]));
+            std::process::exit(EXIT_USAGE);
+        }
+    };
+
+    for path in files.iter().filter(|path| is_selected(path, roots, options)) {
+        // 部分的にステージされたファイルもコミットされる内容でチェックする
+        match git::staged_content(path) {
+            Ok(content) => jobs.push(ctx.job(path, Some(content), true)),
+            Err(e) => {
+                eprintln!(
+                    "{}",
+                    ERROR_READING_STAGED_FILE.format(
+                        &ctx.language,
+                        &[("path", format!("{:?}", path).into()), ("error", e.to_string().into())]
+                    )
+                );
+                ctx.io_failed = true;
+            }
+        }
+    }
+}
+
+fn install_hook(force: bool, hook_args: &[String], language: &Language) -> ! {
+    match hook::install(force, hook_args) {
+        Ok(path) => {
+            println!("{}", HOOK_INSTALLED.format(language, &[("path", path.display().to_string().into())]));
+            std::process::exit(EXIT_OK);
+        }
+        Err(HookError::Exists(path)) => {
+            eprintln!("{}", ERROR_HOOK_EXISTS.format(language, &[("path", format!("{:?}", path).into())]));
+            std::process::exit(EXIT_USAGE);
+        }
+        Err(HookError::Git(e)) => {
+            eprintln!("{}", ERROR_NOT_GIT_REPOSITORY.format(language, &[("error", e.to_string().into())]));
+            std::process::exit(EXIT_USAGE);
+        }
+        Err(HookError::Io(path, e)) => {
+            eprintln!(
+                "{}",
+                ERROR_WRITING_HOOK.format(
+                    language,
+                    &[("path", format!("{:?}", path).into()), ("error", e.to_string().into())]
+                )
+            );
+            std::process::exit(EXIT_IO);
+        }
+    }
+}
+
+/// 初回チェック後も監視を続け、変更されたファイルのみを再チェックして結果を再表示する
+fn watch_loop(
+    args: &Args,
+    options: &ScanOptions,
+    ctx: &mut Context,
+    mut results: BTreeMap<PathBuf, Outcome>,
+    threads: usize,
+) -> ! {
+    let roots: Vec<PathBuf> = args.paths.iter().filter(|p| p.exists()).cloned().collect();
+    let watch_paths: Vec<PathBuf> = roots.iter().map(|p| watch::absolute(p)).collect();
+    let watcher = match FileWatcher::new(&watch_paths, args.recursive) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            eprintln!("{}", ERROR_WATCH.format(&ctx.language, &[("error", e.to_string().into())]));
+            std::process::exit(EXIT_IO);
+        }
+    };
+    let baseline = ctx.baseline.clone();
+
+    loop {
+        // 結果を再表示する（集計とベースラインの状態は毎回リセット）
+        watch::clear_screen();
+        println!("{}: {}", WATCH_LAST_CHECKED.get(&ctx.language), watch::timestamp());
+        println!();
+        ctx.baseline = baseline.clone();
+        ctx.printed_any = false;
+        for (path, outcome) in &results {
+            let outcome = match outcome {
+                Outcome::Checked { content, report } => Outcome::Checked {
+                    content: content.clone(),
+                    report: report.clone(),
+                },
+                Outcome::Failed(e) => Outcome::Failed(io::Error::new(e.kind(), e.to_string())),
+                Outcome::Skipped | Outcome::Detected(_) => continue,
+            };
+            report_outcome(path, outcome, ctx);
+        }
+        println!();
+        println!("{}", WATCH_WAITING.get(&ctx.language));
+
+        let changed = watcher.wait();
+
+        // 設定ファイルが変更された場合はすべてのファイルを再チェックする
+        let config_changed = changed
+            .iter()
+            .any(|p| p.file_name().is_some_and(|name| name == config::CONFIG_FILE_NAME));
+        if config_changed {
+            ctx.configs.clear();
+        }
+
+        let mut candidates = Vec::new();
+        for root in &roots {
+            if root.is_dir() {
+                candidates.extend(scan::scan(root, options).0.into_iter().map(|path| (path, true)));
+            } else if root.is_file() {
+                candidates.push((root.clone(), false));
+            }
+        }
+        results.retain(|path, _| candidates.iter().any(|(candidate, _)| candidate == path));
+
+        let mut jobs = Vec::new();
+        for (path, detect) in &candidates {
+            if config_changed || !results.contains_key(path) || changed.contains(&watch::absolute(path)) {
+                collect_file(path, *detect, ctx, &mut jobs);
+            }
+        }
+
+        let paths: Vec<PathBuf> = jobs.iter().map(|job| job.path.clone()).collect();
+        for (path, outcome) in paths.into_iter().zip(runner::run(jobs, threads, &ctx.language, false)) {
+            results.insert(path, outcome);
+        }
+    }
+}
+
+fn list_outcome(path: &Path, outcome: Outcome, ctx: &mut Context) {
+    match outcome {
+        Outcome::Detected(detection) => {
+            let reason = detection.reason.describe(&ctx.language);
+            match detection.dialect {
+                Some(dialect) if !dialect.is_supported() => {
+                    println!("{}: {} - {} ({})", path.display(), dialect.name(), SKIPPED.get(&ctx.language), reason);
+                }
+                dialect => {
+                    let name = dialect.unwrap_or(detect::Dialect::Bash).name();
+                    println!("{}: {} ({})", path.display(), name, reason);
+                }
+            }
+        }
+        Outcome::Failed(e) => {
+            eprintln!(
+                "{}",
+                ERROR_READING_FILE.format(
+                    &ctx.language,
+                    &[("path", format!("{:?}", path).into()), ("error", e.to_string().into())]
+                )
+            );
+            ctx.io_failed = true;
+        }
+        Outcome::Skipped | Outcome::Checked { .. } => {}
+    }
+}
+
+fn report_outcome(path: &Path, outcome: Outcome, ctx: &mut Context) {
+    let (content, mut report) = match outcome {
+        Outcome::Skipped | Outcome::Detected(_) => return,
+        Outcome::Checked { content, report } => (content, report),
+        Outcome::Failed(e) => {
+            eprintln!(
+                "{}",
+                ERROR_READING_FILE.format(
+                    &ctx.language,
+                    &[("path", format!("{:?}", path).into()), ("error", e.to_string().into())]
+                )
+            );
+            ctx.io_failed = true;
+            return;
+        }
+    };
+
+    // 変更された行の問題のみを対象にする
+    if let Some(changes) = &ctx.changed_lines {
+        changes.retain_changed_lines(path, &mut report);
+    }
+
+    // ベースライン作成時は記録のみ行う
+    if let Some(new_baseline) = &mut ctx.new_baseline {
+        new_baseline.record(path, &content, &report);
+        return;
+    }
+
+    let suppressed = match &mut ctx.baseline {
+        Some(baseline) => baseline.suppress(path, &content, &mut report),
+        None => 0,
+    };
+
+    let language = &ctx.language;
+    if ctx.printed_any {
+        println!();
+    }
+    println!("{}", CHECKING.format(language, &[("path", path.display().to_string().into())]));
+    println!("{}", "=".repeat(60));
+
+    report.print(language);
+    if suppressed > 0 {
+        println!("{}", BASELINE_SUPPRESSED.format(language, &[("count", suppressed.into())]));
+    }
+
+    ctx.printed_any = true;
+    ctx.add_counts(&report);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(errors: usize, warnings: usize, infos: usize, io_failed: bool) -> Context {
+        Context {
+            cli_config: Config::default(),
+            configs: HashMap::new(),
+            language: Language::ENGLISH,
+            baseline: None,
+            new_baseline: None,
+            errors,
+            warnings,
+            infos,
+            io_failed,
+            printed_any: false,
+            changed_lines: None,
+        }
+    }
+
+    #[test]
+    fn exit_code_by_fail_on_threshold() {
+        // (エラー, 警告, 情報) ごとの Error / Warning / Info / None の終了コード
+        let cases = [
+            ((0, 0, 0), [EXIT_OK, EXIT_OK, EXIT_OK, EXIT_OK]),
+            ((0, 0, 1), [EXIT_OK, EXIT_OK, EXIT_ISSUES, EXIT_OK]),
+            ((0, 1, 0), [EXIT_OK, EXIT_ISSUES, EXIT_ISSUES, EXIT_OK]),
+            ((1, 0, 0), [EXIT_ISSUES, EXIT_ISSUES, EXIT_ISSUES, EXIT_OK]),
+        ];
+        let thresholds = [FailOn::Error, FailOn::Warning, FailOn::Info, FailOn::None];
+        for ((errors, warnings, infos), expected) in cases {
+            let ctx = context(errors, warnings, infos, false);
+            for (fail_on, code) in thresholds.into_iter().zip(expected) {
+                assert_eq!(ctx.exit_code(fail_on, None), code, "{:?} {:?}", (errors, warnings, infos), fail_on);
+            }
+        }
+    }
+
+    #[test]
+    fn exit_code_with_max_warnings() {
+        let ctx = context(0, 3, 0, false);
+        assert_eq!(ctx.exit_code(FailOn::Error, Some(3)), EXIT_OK);
+        assert_eq!(ctx.exit_code(FailOn::Error, Some(2)), EXIT_ISSUES);
+        assert_eq!(ctx.exit_code(FailOn::None, Some(2)), EXIT_ISSUES);
+        assert_eq!(ctx.exit_code(FailOn::None, Some(0)), EXIT_ISSUES);
+        // 上限内でも閾値に達していれば失敗する
+        assert_eq!(ctx.exit_code(FailOn::Warning, Some(10)), EXIT_ISSUES);
+        assert_eq!(context(1, 0, 0, false).exit_code(FailOn::Error, Some(10)), EXIT_ISSUES);
+        assert_eq!(context(0, 0, 5, false).exit_code(FailOn::Error, Some(0)), EXIT_OK);
+    }
+
+    #[test]
+    fn io_failure_takes_precedence() {
+        for fail_on in [FailOn::Error, FailOn::Warning, FailOn::Info, FailOn::None] {
+            for max_warnings in [None, Some(0), Some(10)] {
+                assert_eq!(context(0, 0, 0, true).exit_code(fail_on, max_warnings), EXIT_IO);
+                assert_eq!(context(2, 5, 1, true).exit_code(fail_on, max_warnings), EXIT_IO);
+            }
+        }
+    }
+}
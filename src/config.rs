@@ -0,0 +1,288 @@
+// src/config.rs
+use crate::checker::{CustomRule, StyleOptions};
+use crate::detect::Dialect;
+use crate::i18n::{
+    Language, CONFIG_INVALID_CUSTOM_RULE, CONFIG_INVALID_REGEX, CONFIG_UNKNOWN_RULE, CONFIG_UNSUPPORTED_LANGUAGE,
+};
+use crate::profile::Profile;
+use crate::report::{Category, Filter, Severity};
+use crate::rules;
+use crate::scan::Patterns;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const CONFIG_FILE_NAME: &str = ".shellchecker.toml";
+
+/// `.shellchecker.toml` の設定内容
+///
+/// コマンドライン引数も同じ構造で表し、`merge` で設定ファイルより優先させる。
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Config {
+    /// 土台にする組み込みプロファイル
+    pub extends: Option<Profile>,
+    pub language: Option<String>,
+    pub severity: Option<Severity>,
+    pub categories: Vec<Category>,
+    pub exclude_categories: Vec<Category>,
+    pub rules: Vec<String>,
+    pub exclude_rules: Vec<String>,
+    /// ルールごとの重大度（`[rule-severity]` テーブル）
+    pub rule_severity: BTreeMap<String, Severity>,
+    /// シバン行などからの判定より優先するシェルの種類
+    pub dialect: Option<Dialect>,
+    pub max_line_length: Option<usize>,
+    pub indent_width: Option<usize>,
+    /// `[[overrides]]` セクション（後に書いたものが優先）
+    pub overrides: Vec<Override>,
+    /// `[[custom-rules]]` セクション
+    pub custom_rules: Vec<CustomRule>,
+}
+
+/// `[[overrides]]` セクション: `files` のグロブに一致するファイルにだけ適用する設定
+///
+/// `/` を含むパターンは設定ファイルのディレクトリからの相対パスに、含まないパターンはファイル名に一致する。
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Override {
+    pub files: Vec<String>,
+    pub severity: Option<Severity>,
+    pub categories: Vec<Category>,
+    pub exclude_categories: Vec<Category>,
+    pub rules: Vec<String>,
+    pub exclude_rules: Vec<String>,
+    pub rule_severity: BTreeMap<String, Severity>,
+    pub dialect: Option<Dialect>,
+    pub max_line_length: Option<usize>,
+    pub indent_width: Option<usize>,
+    // 設定ファイルのディレクトリとコンパイル済みのパターン（`Config::load` で設定する）
+    #[serde(skip)]
+    base: PathBuf,
+    #[serde(skip)]
+    patterns: Option<Patterns>,
+}
+
+impl Override {
+    /// 一致したファイルに重ね合わせる設定
+    pub fn settings(&self) -> Config {
+        Config {
+            severity: self.severity.clone(),
+            categories: self.categories.clone(),
+            exclude_categories: self.exclude_categories.clone(),
+            rules: self.rules.clone(),
+            exclude_rules: self.exclude_rules.clone(),
+            rule_severity: self.rule_severity.clone(),
+            dialect: self.dialect,
+            max_line_length: self.max_line_length,
+            indent_width: self.indent_width,
+            ..Config::default()
+        }
+    }
+
+    pub fn matches(&self, path: &Path) -> bool {
+        let relative = path.strip_prefix(&self.base).unwrap_or(path);
+        match &self.patterns {
+            Some(patterns) => patterns.matches(relative),
+            None => Patterns::new(&self.files).is_ok_and(|patterns| patterns.matches(relative)),
+        }
+    }
+}
+
+/// 設定ファイルを読み込めなかった理由
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    InvalidGlob(PathBuf, globset::Error),
+    UnsupportedLanguage(PathBuf, String),
+    UnknownRule(PathBuf, String),
+    InvalidCustomRule(PathBuf, String),
+    InvalidRegex(PathBuf, String, regex::Error),
+}
+
+impl ConfigError {
+    pub fn message(&self, lang: &Language) -> String {
+        match self {
+            ConfigError::Io(path, e) => format!("{:?}: {}", path, e),
+            ConfigError::Parse(path, e) => format!("{:?}: {}", path, e),
+            ConfigError::InvalidGlob(path, e) => format!("{:?}: {}", path, e),
+            ConfigError::UnsupportedLanguage(path, language) => CONFIG_UNSUPPORTED_LANGUAGE.format(
+                lang,
+                &[
+                    ("path", format!("{:?}", path).into()),
+                    ("language", language.as_str().into()),
+                    ("available", Language::available().join(", ").into()),
+                ],
+            ),
+            ConfigError::UnknownRule(path, rule) => CONFIG_UNKNOWN_RULE.format(
+                lang,
+                &[("path", format!("{:?}", path).into()), ("rule", rule.as_str().into())],
+            ),
+            ConfigError::InvalidCustomRule(path, rule) => CONFIG_INVALID_CUSTOM_RULE.format(
+                lang,
+                &[("path", format!("{:?}", path).into()), ("rule", rule.as_str().into())],
+            ),
+            ConfigError::InvalidRegex(path, rule, e) => CONFIG_INVALID_REGEX.format(
+                lang,
+                &[
+                    ("path", format!("{:?}", path).into()),
+                    ("rule", rule.as_str().into()),
+                    ("error", e.to_string().into()),
+                ],
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message(&Language::ENGLISH))
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let content = fs::read_to_string(path).map_err(|e| ConfigError::Io(path.to_path_buf(), e))?;
+        let mut config: Config = toml::from_str(&content).map_err(|e| ConfigError::Parse(path.to_path_buf(), e))?;
+        config.validate(path)?;
+
+        let base = std::path::absolute(path.parent().unwrap_or(Path::new(".")))
+            .map_err(|e| ConfigError::Io(path.to_path_buf(), e))?;
+        for entry in &mut config.overrides {
+            let patterns = Patterns::new(&entry.files).map_err(|e| ConfigError::InvalidGlob(path.to_path_buf(), e))?;
+            entry.patterns = Some(patterns);
+            entry.base = base.clone();
+        }
+        for rule in &mut config.custom_rules {
+            rule.compile().map_err(|e| ConfigError::InvalidRegex(path.to_path_buf(), rule.id.clone(), e))?;
+        }
+        Ok(config)
+    }
+
+    /// 指定ディレクトリから親方向に設定ファイルを探索する
+    pub fn find(start: &Path) -> Option<PathBuf> {
+        start
+            .ancestors()
+            .map(|dir| dir.join(CONFIG_FILE_NAME))
+            .find(|candidate| candidate.is_file())
+    }
+
+    pub fn validate(&self, path: &Path) -> Result<(), ConfigError> {
+        if let Some(language) = &self.language
+            && Language::from_code(language).is_none()
+        {
+            return Err(ConfigError::UnsupportedLanguage(path.to_path_buf(), language.clone()));
+        }
+        for (idx, rule) in self.custom_rules.iter().enumerate() {
+            let duplicate = self.custom_rules[..idx].iter().any(|other| other.id == rule.id);
+            if !rule.is_valid() || duplicate || rules::ALL.contains(&rule.id.as_str()) {
+                return Err(ConfigError::InvalidCustomRule(path.to_path_buf(), rule.id.clone()));
+            }
+        }
+        self.validate_rules(path, self)?;
+        for entry in &self.overrides {
+            entry.settings().validate_rules(path, self)?;
+        }
+        Ok(())
+    }
+
+    /// 指定されたルールが組み込みのルールか `defined` のユーザー定義ルールであることを確認する
+    fn validate_rules(&self, path: &Path, defined: &Config) -> Result<(), ConfigError> {
+        for rule in self.rules.iter().chain(&self.exclude_rules).chain(self.rule_severity.keys()) {
+            if !defined.is_known_rule(rule) {
+                return Err(ConfigError::UnknownRule(path.to_path_buf(), rule.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// 組み込みのルールか、この設定で定義されたユーザー定義ルールか
+    pub fn is_known_rule(&self, rule: &str) -> bool {
+        rules::ALL.contains(&rule) || self.custom_rules.iter().any(|custom| custom.id == rule)
+    }
+
+    /// ファイルに一致する `[[overrides]]` を順に重ね合わせた設定を返す
+    pub fn resolve(&self, path: &Path) -> Config {
+        let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        self.overrides
+            .iter()
+            .filter(|entry| entry.matches(&path))
+            .fold(self.clone(), |config, entry| config.merge(&entry.settings()))
+    }
+
+    /// `other` の指定を優先して設定を重ね合わせる（除外指定は両方を適用）
+    pub fn merge(&self, other: &Config) -> Config {
+        Config {
+            extends: other.extends.or(self.extends),
+            language: other.language.clone().or_else(|| self.language.clone()),
+            severity: other.severity.clone().or_else(|| self.severity.clone()),
+            categories: if other.categories.is_empty() {
+                self.categories.clone()
+            } else {
+                other.categories.clone()
+            },
+            exclude_categories: union(&self.exclude_categories, &other.exclude_categories),
+            rules: if other.rules.is_empty() {
+                self.rules.clone()
+            } else {
+                other.rules.clone()
+            },
+            exclude_rules: union(&self.exclude_rules, &other.exclude_rules),
+            rule_severity: self
+                .rule_severity
+                .iter()
+                .chain(&other.rule_severity)
+                .map(|(rule, severity)| (rule.clone(), severity.clone()))
+                .collect(),
+            dialect: other.dialect.or(self.dialect),
+            max_line_length: other.max_line_length.or(self.max_line_length),
+            indent_width: other.indent_width.or(self.indent_width),
+            overrides: self.overrides.iter().chain(&other.overrides).cloned().collect(),
+            custom_rules: self.custom_rules.iter().chain(&other.custom_rules).cloned().collect(),
+        }
+    }
+
+    pub fn language(&self) -> Option<Language> {
+        self.language.as_deref().and_then(Language::from_code)
+    }
+
+    pub fn style(&self) -> StyleOptions {
+        let default = StyleOptions::default();
+        StyleOptions {
+            max_line_length: self.max_line_length.unwrap_or(default.max_line_length),
+            indent_width: self.indent_width.unwrap_or(default.indent_width),
+        }
+    }
+
+    /// `extends` のプロファイルを土台にしてフィルタを作る
+    pub fn filter(&self) -> Filter {
+        let config = match self.extends {
+            Some(profile) => profile.config(&self.custom_rules).merge(self),
+            None => self.clone(),
+        };
+        Filter {
+            severities: config.rule_severity,
+            min_severity: config.severity.unwrap_or(Severity::Info),
+            categories: config.categories,
+            exclude_categories: config.exclude_categories,
+            rules: config.rules,
+            exclude_rules: config.exclude_rules,
+        }
+    }
+}
+
+fn union<T: Clone + PartialEq>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut result = a.to_vec();
+    for item in b {
+        if !result.contains(item) {
+            result.push(item.clone());
+        }
+    }
+    result
+}
@@ -0,0 +1,330 @@
+// src/detect.rs
+use crate::i18n::{
+    Language, REASON_SHEBANG, REASON_MODELINE, REASON_FILE_NAME, REASON_EXTENSION, REASON_DEFAULT,
+    REASON_EMBEDDED, REASON_CONFIG,
+};
+use serde::Deserialize;
+use std::path::Path;
+
+/// シェルの方言
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Dialect {
+    Bash,
+    Sh,
+    Dash,
+    Ksh,
+    Zsh,
+}
+
+impl Dialect {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Dialect::Bash => "bash",
+            Dialect::Sh => "sh",
+            Dialect::Dash => "dash",
+            Dialect::Ksh => "ksh",
+            Dialect::Zsh => "zsh",
+        }
+    }
+
+    /// チェック対象とする方言か（zsh は構文が異なるため対象外）
+    pub fn is_supported(&self) -> bool {
+        !matches!(self, Dialect::Zsh)
+    }
+
+    /// POSIX sh 互換の方言か
+    pub fn is_posix(&self) -> bool {
+        matches!(self, Dialect::Sh | Dialect::Dash)
+    }
+
+    fn from_name(name: &str) -> Option<Dialect> {
+        // bash5 や ksh93 のようなバージョン付きの名前も受け付ける
+        let name = name.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+        match name {
+            "bash" => Some(Dialect::Bash),
+            "sh" => Some(Dialect::Sh),
+            "dash" | "ash" => Some(Dialect::Dash),
+            "ksh" | "mksh" | "pdksh" => Some(Dialect::Ksh),
+            "zsh" => Some(Dialect::Zsh),
+            _ => None,
+        }
+    }
+}
+
+/// 判定の根拠
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reason {
+    Shebang(String),
+    Modeline(String),
+    FileName(String),
+    Extension(String),
+    // 他の形式のファイルに埋め込まれたスクリプト（形式の名前）
+    Embedded(&'static str),
+    // 設定ファイルの `dialect` で指定された
+    Config,
+    Default,
+}
+
+impl Reason {
+    pub fn describe(&self, lang: &Language) -> String {
+        match self {
+            Reason::Shebang(line) => format!("{}: {}", REASON_SHEBANG.get(lang), line),
+            Reason::Modeline(line) => format!("{}: {}", REASON_MODELINE.get(lang), line),
+            Reason::FileName(name) => format!("{}: {}", REASON_FILE_NAME.get(lang), name),
+            Reason::Extension(ext) => format!("{}: .{}", REASON_EXTENSION.get(lang), ext),
+            Reason::Embedded(format) => REASON_EMBEDDED.format(lang, &[("format", (*format).into())]),
+            Reason::Config => REASON_CONFIG.get(lang),
+            Reason::Default => REASON_DEFAULT.get(lang),
+        }
+    }
+}
+
+/// ファイルの判定結果（`dialect` が `None` の場合はシェルスクリプトではない）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Detection {
+    pub dialect: Option<Dialect>,
+    pub reason: Reason,
+}
+
+impl Detection {
+    pub fn is_checked(&self) -> bool {
+        self.dialect.is_some_and(|d| d.is_supported())
+    }
+}
+
+const MODELINE_SCAN_LINES: usize = 5;
+
+/// シバン行・モードライン・ファイル名・拡張子の順にシェルの方言を判定する
+pub fn detect(path: &Path, content: &str) -> Detection {
+    let first_line = content.lines().next().unwrap_or("");
+    if first_line.starts_with("#!") {
+        return Detection {
+            dialect: shebang_dialect(first_line),
+            reason: Reason::Shebang(first_line.trim().to_string()),
+        };
+    }
+
+    if let Some((dialect, line)) = modeline_dialect(content) {
+        return Detection {
+            dialect: Some(dialect),
+            reason: Reason::Modeline(line),
+        };
+    }
+
+    let file_name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    if let Some(dialect) = file_name_dialect(&file_name) {
+        return Detection {
+            dialect: Some(dialect),
+            reason: Reason::FileName(file_name),
+        };
+    }
+
+    if let Some(ext) = path.extension().map(|e| e.to_string_lossy().to_string()) {
+        let dialect = match ext.as_str() {
+            // .sh は従来どおり bash として扱う
+            "sh" | "bash" => Some(Dialect::Bash),
+            "ksh" => Some(Dialect::Ksh),
+            "dash" => Some(Dialect::Dash),
+            "zsh" => Some(Dialect::Zsh),
+            _ => None,
+        };
+        if dialect.is_some() {
+            return Detection {
+                dialect,
+                reason: Reason::Extension(ext),
+            };
+        }
+    }
+
+    Detection {
+        dialect: None,
+        reason: Reason::Default,
+    }
+}
+
+/// シバン行のインタプリタから方言を判定する（`#! /bin/bash`、`#!/usr/bin/env -S bash -e` なども対応）
+fn shebang_dialect(line: &str) -> Option<Dialect> {
+    let mut words = line.trim_start_matches("#!").split_whitespace();
+    let interpreter = basename(words.next()?);
+
+    if interpreter != "env" {
+        return Dialect::from_name(interpreter);
+    }
+
+    // env のオプションと環境変数の代入を読み飛ばす
+    while let Some(word) = words.next() {
+        if word == "-u" || word == "--unset" {
+            words.next();
+        } else if word.starts_with('-') || word.contains('=') {
+            continue;
+        } else {
+            return Dialect::from_name(basename(word));
+        }
+    }
+    None
+}
+
+/// `/bin/bash` のようなインタプリタのパスから方言を判定する
+pub fn interpreter_dialect(command: &str) -> Option<Dialect> {
+    Dialect::from_name(basename(command))
+}
+
+fn basename(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+/// vim / emacs のモードラインから方言を判定する（先頭と末尾の数行のみ）
+fn modeline_dialect(content: &str) -> Option<(Dialect, String)> {
+    let lines: Vec<&str> = content.lines().collect();
+    let head = lines.iter().take(MODELINE_SCAN_LINES);
+    let tail = lines.iter().skip(lines.len().saturating_sub(MODELINE_SCAN_LINES));
+
+    for line in head.chain(tail) {
+        let trimmed = line.trim();
+        if !trimmed.starts_with('#') {
+            continue;
+        }
+        if let Some(dialect) = vim_modeline(trimmed).or_else(|| emacs_modeline(trimmed)) {
+            return Some((dialect, trimmed.to_string()));
+        }
+    }
+    None
+}
+
+fn vim_modeline(line: &str) -> Option<Dialect> {
+    let start = line.find("vim:").or_else(|| line.find("vi:")).or_else(|| line.find("ex:"))?;
+    let settings = &line[start..];
+    settings
+        .split(|c: char| c == ':' || c.is_whitespace())
+        .filter_map(|setting| {
+            setting
+                .strip_prefix("ft=")
+                .or_else(|| setting.strip_prefix("filetype="))
+                .or_else(|| setting.strip_prefix("syntax="))
+        })
+        .find_map(Dialect::from_name)
+}
+
+fn emacs_modeline(line: &str) -> Option<Dialect> {
+    let start = line.find("-*-")? + 3;
+    let end = start + line[start..].find("-*-")?;
+    let body = line[start..end].trim();
+
+    // -*- sh -*- 形式
+    if !body.contains(':') {
+        return Dialect::from_name(body.trim_end_matches("-mode"));
+    }
+
+    let mut mode = None;
+    let mut shell = None;
+    for setting in body.split(';') {
+        let Some((key, value)) = setting.split_once(':') else { continue };
+        match key.trim() {
+            "mode" => mode = Some(value.trim()),
+            "sh-shell" => shell = Some(value.trim()),
+            _ => {}
+        }
+    }
+    shell
+        .and_then(Dialect::from_name)
+        .or_else(|| mode.and_then(|m| Dialect::from_name(m.trim_end_matches("-mode"))))
+}
+
+fn file_name_dialect(name: &str) -> Option<Dialect> {
+    match name {
+        ".bashrc" | ".bash_profile" | ".bash_login" | ".bash_logout" | ".bash_aliases" | "bashrc"
+        | "bash.bashrc" => Some(Dialect::Bash),
+        ".profile" | "profile" => Some(Dialect::Sh),
+        ".kshrc" => Some(Dialect::Ksh),
+        ".zshrc" | ".zshenv" | ".zprofile" | ".zlogin" | ".zlogout" => Some(Dialect::Zsh),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dialect(path: &str, content: &str) -> Option<Dialect> {
+        detect(Path::new(path), content).dialect
+    }
+
+    #[test]
+    fn shebang_forms() {
+        let cases = [
+            ("#!/bin/bash", Some(Dialect::Bash)),
+            ("#! /bin/bash", Some(Dialect::Bash)),
+            ("#!/usr/local/bin/bash", Some(Dialect::Bash)),
+            ("#!/usr/bin/env bash", Some(Dialect::Bash)),
+            ("#!/usr/bin/env -S bash -e", Some(Dialect::Bash)),
+            ("#!/usr/bin/env -u HOME LANG=C sh", Some(Dialect::Sh)),
+            ("#!/bin/bash5", Some(Dialect::Bash)),
+            ("#!/bin/ksh93", Some(Dialect::Ksh)),
+            ("#!/bin/ash", Some(Dialect::Dash)),
+            ("#!/bin/zsh", Some(Dialect::Zsh)),
+            ("#!/usr/bin/env python3", None),
+            ("#!", None),
+        ];
+        for (shebang, expected) in cases {
+            let detection = detect(Path::new("script.sh"), &format!("{}\necho hi\n", shebang));
+            assert_eq!(detection.dialect, expected, "{}", shebang);
+            assert_eq!(detection.reason, Reason::Shebang(shebang.to_string()), "{}", shebang);
+        }
+    }
+
+    #[test]
+    fn shebang_wins_over_extension() {
+        let detection = detect(Path::new("prompt.sh"), "#!/usr/bin/env zsh\necho $HOME\n");
+        assert_eq!(detection.dialect, Some(Dialect::Zsh));
+        assert!(!detection.is_checked());
+    }
+
+    #[test]
+    fn modelines() {
+        let cases = [
+            ("# vim: set ft=sh:", Some(Dialect::Sh)),
+            ("# vim: filetype=zsh", Some(Dialect::Zsh)),
+            ("# vi: syntax=bash ts=4", Some(Dialect::Bash)),
+            ("# -*- sh -*-", Some(Dialect::Sh)),
+            ("# -*- ksh-mode -*-", Some(Dialect::Ksh)),
+            ("# -*- mode: sh; sh-shell: bash -*-", Some(Dialect::Bash)),
+            ("# -*- mode: sh -*-", Some(Dialect::Sh)),
+            ("# vim: ft=python", None),
+            ("echo 'vim: ft=sh'", None),
+        ];
+        for (line, expected) in cases {
+            assert_eq!(dialect("script", &format!("{}\necho hi\n", line)), expected, "{}", line);
+        }
+
+        // 末尾の数行も対象にする
+        let content = format!("echo a\n{}# vim: ft=ksh\n", "echo b\n".repeat(10));
+        assert_eq!(dialect("script", &content), Some(Dialect::Ksh));
+        let content = format!("echo a\n{}# vim: ft=ksh\n{}", "echo b\n".repeat(10), "echo c\n".repeat(5));
+        assert_eq!(dialect("script", &content), None);
+    }
+
+    #[test]
+    fn file_names_and_extensions() {
+        let cases = [
+            (".bashrc", Some(Dialect::Bash)),
+            ("home/.bash_profile", Some(Dialect::Bash)),
+            (".profile", Some(Dialect::Sh)),
+            (".kshrc", Some(Dialect::Ksh)),
+            (".zshrc", Some(Dialect::Zsh)),
+            ("deploy.sh", Some(Dialect::Bash)),
+            ("deploy.bash", Some(Dialect::Bash)),
+            ("deploy.ksh", Some(Dialect::Ksh)),
+            ("deploy.dash", Some(Dialect::Dash)),
+            ("deploy.zsh", Some(Dialect::Zsh)),
+            ("deploy.py", None),
+            ("Makefile", None),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(dialect(path, "echo hi\n"), expected, "{}", path);
+        }
+        assert_eq!(detect(Path::new(".profile"), "").reason, Reason::FileName(".profile".to_string()));
+        assert_eq!(detect(Path::new("a.sh"), "").reason, Reason::Extension("sh".to_string()));
+        assert_eq!(detect(Path::new("a"), "").reason, Reason::Default);
+    }
+}
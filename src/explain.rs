@@ -0,0 +1,145 @@
+// src/explain.rs
+// ルールの説明（explain、--list-rules、Markdown のルールリファレンス）
+use crate::i18n::{
+    DOC_BAD, DOC_CATEGORY, DOC_GOOD, DOC_RATIONALE, DOC_RULE, DOC_RULES_TITLE, DOC_SEVERITY, DOC_SUPPRESS,
+    DOC_SUPPRESS_CONFIG, DOC_SUPPRESS_FILE, DOC_SUPPRESS_LINE, Language, rule_doc,
+};
+use crate::rules;
+use crate::suppress;
+
+/// ルール ID、分類、既定の重大度の一覧
+pub fn list_rules(language: &Language) -> String {
+    let width = rules::ALL.iter().map(|rule| rule.len()).max().unwrap_or(0);
+    let category_width = rules::ALL
+        .iter()
+        .filter_map(|rule| rules::metadata(rule))
+        .map(|(category, _)| display_width(&category.label(language)))
+        .max()
+        .unwrap_or(0);
+
+    let mut output = String::new();
+    for rule in rules::ALL {
+        let Some((category, severity)) = rules::metadata(rule) else { continue };
+        let category = category.label(language);
+        let padding = category_width - display_width(&category);
+        output.push_str(&format!(
+            "{:width$}  {}{}  {}\n",
+            rule,
+            category,
+            " ".repeat(padding),
+            severity.label(language),
+            width = width
+        ));
+    }
+    output
+}
+
+/// ルールの説明をテキストで返す（不明なルールの場合は None）
+pub fn explain(rule: &str, language: &Language) -> Option<String> {
+    let doc = rule_doc(rule)?;
+    let (category, severity) = rules::metadata(rule)?;
+
+    let mut output = format!(
+        "{}\n{}: {}    {}: {}\n\n{}\n\n{}:\n{}\n",
+        rule,
+        DOC_CATEGORY.get(language),
+        category.label(language),
+        DOC_SEVERITY.get(language),
+        severity.label(language),
+        doc.summary.get(language),
+        DOC_RATIONALE.get(language),
+        indent(&doc.rationale.get(language))
+    );
+    for (heading, example) in [(&DOC_BAD, doc.bad), (&DOC_GOOD, doc.good)] {
+        output.push_str(&format!("\n{}:\n{}\n", heading.get(language), indent(example)));
+    }
+    output.push_str(&format!("\n{}:\n{}\n", DOC_SUPPRESS.get(language), indent(&suppress_example(rule, language))));
+    Some(output)
+}
+
+/// 全ルールのリファレンスを Markdown で返す
+pub fn markdown(language: &Language) -> String {
+    let mut output = format!(
+        "# {}\n\n| {} | {} | {} |\n| --- | --- | --- |\n",
+        DOC_RULES_TITLE.get(language),
+        DOC_RULE.get(language),
+        DOC_CATEGORY.get(language),
+        DOC_SEVERITY.get(language)
+    );
+    for rule in rules::ALL {
+        let Some((category, severity)) = rules::metadata(rule) else { continue };
+        output.push_str(&format!(
+            "| [`{}`](#{}) | {} | {} |\n",
+            rule,
+            rule,
+            category.label(language),
+            severity.label(language)
+        ));
+    }
+
+    for rule in rules::ALL {
+        let (Some(doc), Some((category, severity))) = (rule_doc(rule), rules::metadata(rule)) else { continue };
+        // GitHub Actions の例のみ YAML
+        let fence = if *rule == rules::EXPRESSION_INJECTION { "yaml" } else { "sh" };
+        output.push_str(&format!(
+            "\n## {}\n\n**{}:** {} · **{}:** {}\n\n{}\n\n### {}\n\n{}\n",
+            rule,
+            DOC_CATEGORY.get(language),
+            category.label(language),
+            DOC_SEVERITY.get(language),
+            severity.label(language),
+            doc.summary.get(language),
+            DOC_RATIONALE.get(language),
+            doc.rationale.get(language)
+        ));
+        for (heading, example) in [(&DOC_BAD, doc.bad), (&DOC_GOOD, doc.good)] {
+            output.push_str(&format!("\n### {}\n\n```{}\n{}\n```\n", heading.get(language), fence, example));
+        }
+        output.push_str(&format!(
+            "\n### {}\n\n```sh\n{}\n```\n",
+            DOC_SUPPRESS.get(language),
+            suppress_example(rule, language)
+        ));
+    }
+    output
+}
+
+/// 抑制コメントと設定ファイルでの無効化の例
+fn suppress_example(rule: &str, language: &Language) -> String {
+    let examples = [
+        (suppress::line_directive(rule, ""), DOC_SUPPRESS_LINE.get(language)),
+        (suppress::file_directive(rule), DOC_SUPPRESS_FILE.get(language)),
+        (
+            format!("exclude-rules = [\"{}\"]", rule),
+            format!(".shellchecker.toml: {}", DOC_SUPPRESS_CONFIG.get(language)),
+        ),
+    ];
+    let width = examples.iter().map(|(example, _)| example.len()).max().unwrap_or(0);
+    examples
+        .iter()
+        .map(|(example, note)| format!("{:width$}  # {}", example, note, width = width))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 端末での表示幅（CJK の全角文字は 2 桁、ウムラウトなどは 1 桁）
+pub(crate) fn display_width(text: &str) -> usize {
+    text.chars().map(|c| if is_wide(c) { 2 } else { 1 }).sum()
+}
+
+fn is_wide(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1100}'..='\u{115F}'
+            | '\u{2E80}'..='\u{A4CF}'
+            | '\u{AC00}'..='\u{D7A3}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{FE30}'..='\u{FE4F}'
+            | '\u{FF00}'..='\u{FF60}'
+            | '\u{FFE0}'..='\u{FFE6}'
+    )
+}
+
+fn indent(text: &str) -> String {
+    text.lines().map(|line| format!("    {}", line)).collect::<Vec<_>>().join("\n")
+}
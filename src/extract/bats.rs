@@ -0,0 +1,133 @@
+// src/extract/bats.rs
+// Bats のテストファイル
+use super::{Extractor, Snippet};
+use crate::detect::Dialect;
+use crate::rules;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::path::Path;
+
+lazy_static! {
+    // @test "name" { ... }（`{` は次の行でもよい）
+    static ref TEST_BLOCK: Regex =
+        Regex::new(r#"^(\s*)@test\s+(?:"(?:[^"\\]|\\.)*"|'[^']*'|[^{]*?)\s*(?:(\{)|$)"#).unwrap();
+    // run とそのオプション（-N で終了ステータス、! で失敗を期待する）
+    static ref RUN: Regex =
+        Regex::new(r"^(\s*)run(?:\s+(?:-[0-9]+|!|--separate-stderr|--keep-empty-lines|--))*\s+").unwrap();
+    // run が設定する終了ステータス（常に数値）
+    static ref STATUS: Regex = Regex::new(r"\$status\b").unwrap();
+}
+
+// bats はテストを set -e 付きで実行するため、失敗したコマンドでテストが終了する
+const BATS_DISABLED_RULES: &[&str] = &[rules::CD_WITHOUT_CHECK];
+
+pub struct Bats;
+
+impl Extractor for Bats {
+    fn name(&self) -> &'static str {
+        "Bats"
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        path.extension().is_some_and(|ext| ext == "bats")
+    }
+
+    fn dialect(&self) -> Dialect {
+        // bats はテストファイルを bash で読み込む
+        Dialect::Bash
+    }
+
+    fn extract(&self, content: &str) -> Vec<Snippet> {
+        let mut snippet = Snippet::new(self.dialect());
+        let mut tests = 0;
+
+        for (idx, line) in content.lines().enumerate() {
+            // `#!/usr/bin/env bats` は bash のシバン行ではない
+            if idx == 0 && line.starts_with("#!") {
+                snippet.push_line("", idx + 1);
+                continue;
+            }
+
+            // @test ブロックは関数として扱う（テスト名の括弧や引用符はシェルの構文ではない）
+            if let Some(caps) = TEST_BLOCK.captures(line) {
+                tests += 1;
+                let rest = &line[caps.get(0).map_or(0, |m| m.end())..];
+                let brace = if caps.get(2).is_some() { " {" } else { "" };
+                let function = format!("{}bats_test_{}(){}{}", &caps[1], tests, brace, rest);
+                snippet.push_line(&function, idx + 1);
+                continue;
+            }
+
+            // `run` で実行するコマンドをそのままチェックする（ユーザー定義のルールのパターンにも一致させる）
+            let line = RUN.replace(line, "$1");
+            // `[ $status -eq 0 ]` のように引用符なしで使用しても問題ない
+            snippet.push_line(&STATUS.replace_all(&line, "$?"), idx + 1);
+        }
+
+        if snippet.is_empty() { Vec::new() } else { vec![snippet] }
+    }
+
+    fn disabled_rules(&self) -> &[&'static str] {
+        BATS_DISABLED_RULES
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::{CustomRule, StyleOptions};
+    use crate::extract;
+    use crate::i18n::Language;
+
+    fn script(content: &str) -> String {
+        Bats.extract(content).into_iter().map(|snippet| snippet.script).collect()
+    }
+
+    fn rules(content: &str, custom_rules: &[CustomRule]) -> Vec<(usize, String)> {
+        extract::check(&Bats, content, &Language::ENGLISH, StyleOptions::default(), custom_rules)
+            .into_iter()
+            .map(|issue| (issue.line, issue.rule))
+            .collect()
+    }
+
+    #[test]
+    fn test_block_with_brace_on_same_line() {
+        assert_eq!(
+            script("#!/usr/bin/env bats\n@test \"[ok] it's \\\"quoted\\\"\" {\n  true\n}\n"),
+            "\nbats_test_1() {\n  true\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_block_with_brace_on_next_line() {
+        let content = "@test \"a (b\"\n{\n  true\n}\n@test unquoted name\n{\n  true\n}\n";
+        assert_eq!(script(content), "bats_test_1()\n{\n  true\n}\nbats_test_2()\n{\n  true\n}\n");
+        assert!(rules(content, &[]).is_empty());
+    }
+
+    #[test]
+    fn run_checks_the_invoked_command() {
+        let content = "@test \"x\" {\n  run -1 eval \"$cmd\"\n  run ! --separate-stderr rm -rf \"$dir\"\n  [ \"$status\" -eq 1 ]\n}\n";
+        assert_eq!(
+            script(content),
+            "bats_test_1() {\n  eval \"$cmd\"\n  rm -rf \"$dir\"\n  [ \"$?\" -eq 1 ]\n}\n"
+        );
+
+        let rule: CustomRule = toml::from_str(
+            "id = \"no-rm\"\nseverity = \"warning\"\ncategory = \"security\"\nmessage = \"rm\"\npattern = \"rm -rf\"",
+        )
+        .unwrap();
+        assert_eq!(rules(content, &[rule]), [(2, "eval-usage".to_string()), (3, "no-rm".to_string())]);
+    }
+
+    #[test]
+    fn run_is_only_stripped_as_a_command() {
+        assert_eq!(script("runner=1\necho run it\n"), "runner=1\necho run it\n");
+    }
+
+    #[test]
+    fn status_may_be_unquoted_but_output_may_not() {
+        let content = "@test \"x\" {\n  run true\n  [ $status -eq 0 ]\n  [ \"$output\" = \"\" ]\n  [ $output = \"\" ]\n}\n";
+        assert_eq!(rules(content, &[]), [(5, "unquoted-variable".to_string())]);
+    }
+}
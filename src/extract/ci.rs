@@ -0,0 +1,177 @@
+// src/extract/ci.rs
+// GitHub Actions の `run:` と GitLab CI の `script:`
+use super::yaml::{self, Node};
+use super::{EXPANSION_PLACEHOLDER, Extractor, Snippet};
+use crate::detect::{self, Dialect};
+use crate::rules;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::path::{Component, Path};
+
+lazy_static! {
+    // GitHub Actions の式 ${{ ... }}
+    static ref GITHUB_EXPRESSION: Regex = Regex::new(r"\$\{\{.*?\}\}").unwrap();
+    // 攻撃者が内容を制御できるコンテキスト（イベントのタイトルや本文、ブランチ名、入力値）
+    static ref GITHUB_UNTRUSTED: Regex = Regex::new(r"\bgithub\.event\.|\bgithub\.head_ref\b|\binputs\.").unwrap();
+    // GitLab CI/CD コンポーネントの入力 $[[ inputs.name ]]
+    static ref GITLAB_EXPRESSION: Regex = Regex::new(r"\$\[\[.*?\]\]").unwrap();
+}
+
+// GitHub Actions と GitLab CI はどちらも -e 付きのシェルで実行するため cd の失敗でジョブが止まる
+const CI_DISABLED_RULES: &[&str] = &[rules::CD_WITHOUT_CHECK];
+
+pub struct GithubActions;
+
+impl Extractor for GithubActions {
+    fn name(&self) -> &'static str {
+        "GitHub Actions"
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        let is_yaml = path.extension().is_some_and(|ext| ext == "yml" || ext == "yaml");
+        let file_name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+        let components: Vec<Component> = path.components().collect();
+        // .github/workflows/*.yml と複合アクションの action.yml
+        let in_workflows = components.len() >= 3
+            && components[components.len() - 3].as_os_str() == ".github"
+            && components[components.len() - 2].as_os_str() == "workflows";
+        is_yaml && (in_workflows || file_name == "action.yml" || file_name == "action.yaml")
+    }
+
+    fn dialect(&self) -> Dialect {
+        Dialect::Bash
+    }
+
+    fn hidden_dirs(&self) -> &[&'static str] {
+        &[".github"]
+    }
+
+    fn extract(&self, content: &str) -> Vec<Snippet> {
+        let root = yaml::parse(content);
+        let mut snippets = Vec::new();
+        let default_shell = run_shell(root.get("defaults"));
+
+        for (_, job) in root.get("jobs").entries() {
+            // Windows のランナーの既定のシェルは PowerShell
+            let windows = job.get("runs-on").as_str().is_some_and(|r| r.contains("windows"));
+            let job_shell = run_shell(job.get("defaults"))
+                .or(default_shell)
+                .or(windows.then_some("pwsh"));
+            github_steps(job.get("steps"), job_shell, &mut snippets);
+        }
+
+        // 複合アクションでは shell の指定が必須
+        github_steps(root.get("runs").get("steps"), None, &mut snippets);
+        snippets
+    }
+
+    fn disabled_rules(&self) -> &[&'static str] {
+        CI_DISABLED_RULES
+    }
+}
+
+/// `defaults.run.shell`
+fn run_shell(defaults: &Node) -> Option<&str> {
+    defaults.get("run").get("shell").as_str()
+}
+
+fn github_steps(steps: &Node, job_shell: Option<&str>, snippets: &mut Vec<Snippet>) {
+    for step in steps.items() {
+        let Node::Scalar(lines) = step.get("run") else { continue };
+        let shell = step.get("shell").as_str().or(job_shell);
+
+        // bash と sh 以外 (pwsh、python など) はチェックしない
+        let dialect = match shell {
+            None => Dialect::Bash,
+            Some(shell) => match shell.split_whitespace().next().and_then(detect::interpreter_dialect) {
+                Some(dialect) if dialect.is_supported() => dialect,
+                _ => continue,
+            },
+        };
+        snippets.push(script_snippet(lines, dialect, &GITHUB_EXPRESSION, Some(&GITHUB_UNTRUSTED)));
+    }
+}
+
+pub struct GitlabCi;
+
+// ジョブとして扱わないトップレベルのキーワード
+const GITLAB_KEYWORDS: &[&str] = &["stages", "variables", "include", "workflow", "spec", "image", "services", "cache"];
+const GITLAB_SCRIPT_KEYS: &[&str] = &["before_script", "script", "after_script"];
+
+impl Extractor for GitlabCi {
+    fn name(&self) -> &'static str {
+        "GitLab CI"
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        path.file_name()
+            .map(|n| n.to_string_lossy())
+            .is_some_and(|name| name == ".gitlab-ci.yml" || name.ends_with(".gitlab-ci.yml"))
+    }
+
+    fn dialect(&self) -> Dialect {
+        // GitLab Runner は bash があれば bash を使用する
+        Dialect::Bash
+    }
+
+    fn extract(&self, content: &str) -> Vec<Snippet> {
+        let root = yaml::parse(content);
+        let mut snippets = Vec::new();
+
+        for (key, node) in root.entries() {
+            if GITLAB_KEYWORDS.contains(&key.as_str()) {
+                continue;
+            }
+            // 従来のトップレベルの before_script / after_script
+            if GITLAB_SCRIPT_KEYS.contains(&key.as_str()) {
+                gitlab_script(node, &mut snippets);
+                continue;
+            }
+            // ジョブ、隠しジョブ (.template)、default
+            for script_key in GITLAB_SCRIPT_KEYS {
+                gitlab_script(node.get(script_key), &mut snippets);
+            }
+        }
+        snippets
+    }
+
+    fn disabled_rules(&self) -> &[&'static str] {
+        CI_DISABLED_RULES
+    }
+}
+
+/// 文字列または文字列のシーケンスの script をひとつのスクリプトとして取り出す
+fn gitlab_script(script: &Node, snippets: &mut Vec<Snippet>) {
+    let mut lines = Vec::new();
+    match script {
+        Node::Scalar(scalar) => lines.extend(scalar.iter().cloned()),
+        Node::Seq(items) => {
+            for item in items {
+                // !reference などのスカラー以外の要素は読み飛ばす
+                if let Node::Scalar(scalar) = item {
+                    lines.extend(scalar.iter().cloned());
+                }
+            }
+        }
+        _ => return,
+    }
+    if !lines.is_empty() {
+        snippets.push(script_snippet(&lines, Dialect::Bash, &GITLAB_EXPRESSION, None));
+    }
+}
+
+/// 式をプレースホルダーに置き換えた断片を作成し、信頼できない入力を展開する式を記録する
+///
+/// `untrusted` が None の場合はすべての式を記録する。
+fn script_snippet(lines: &[(usize, String)], dialect: Dialect, expression: &Regex, untrusted: Option<&Regex>) -> Snippet {
+    let mut snippet = Snippet::new(dialect);
+    for (line, text) in lines {
+        for expr in expression.find_iter(text) {
+            if untrusted.is_none_or(|untrusted| untrusted.is_match(expr.as_str())) {
+                snippet.injections.push((*line, expr.as_str().to_string()));
+            }
+        }
+        snippet.push_line(&expression.replace_all(text, EXPANSION_PLACEHOLDER), *line);
+    }
+    snippet
+}
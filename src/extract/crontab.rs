@@ -0,0 +1,171 @@
+// src/extract/crontab.rs
+// crontab のコマンド
+use super::{Extractor, Snippet};
+use crate::detect::{self, Dialect};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::path::Path;
+
+lazy_static! {
+    static ref ENVIRONMENT: Regex = Regex::new(r#"^([A-Za-z_][A-Za-z0-9_]*)\s*=\s*["']?([^"']*)["']?\s*$"#).unwrap();
+}
+
+// 分、時、日、月、曜日
+const SCHEDULE_FIELDS: usize = 5;
+
+pub struct Crontab;
+
+impl Extractor for Crontab {
+    fn name(&self) -> &'static str {
+        "crontab"
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        let file_name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+        !is_system(path) && (file_name == "crontab" || path.extension().is_some_and(|ext| ext == "crontab"))
+    }
+
+    fn dialect(&self) -> Dialect {
+        // cron は既定で SHELL=/bin/sh でコマンドを実行する
+        Dialect::Sh
+    }
+
+    fn extract(&self, content: &str) -> Vec<Snippet> {
+        entries(content, false, self.dialect())
+    }
+}
+
+/// /etc/crontab と /etc/cron.d のファイル（スケジュールの後にユーザー名の欄がある）
+pub struct SystemCrontab;
+
+impl Extractor for SystemCrontab {
+    fn name(&self) -> &'static str {
+        "system crontab"
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        is_system(path)
+    }
+
+    fn dialect(&self) -> Dialect {
+        Dialect::Sh
+    }
+
+    fn extract(&self, content: &str) -> Vec<Snippet> {
+        entries(content, true, self.dialect())
+    }
+}
+
+fn is_system(path: &Path) -> bool {
+    let parent = path.parent().and_then(|parent| parent.file_name());
+    let file_name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+    parent.is_some_and(|name| name == "cron.d") || (file_name == "crontab" && parent.is_some_and(|name| name == "etc"))
+}
+
+/// 各行のコマンドをそれぞれ断片として取り出す
+fn entries(content: &str, user_field: bool, default: Dialect) -> Vec<Snippet> {
+    let mut dialect = Some(default);
+    let mut snippets = Vec::new();
+
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if let Some(caps) = ENVIRONMENT.captures(trimmed) {
+            // SHELL= は以降の行に適用される（bash と sh 以外のシェルの行はチェックしない）
+            if &caps[1] == "SHELL" {
+                dialect = detect::interpreter_dialect(&caps[2]).filter(|d| d.is_supported());
+            }
+            continue;
+        }
+
+        let Some(dialect) = dialect else { continue };
+        let Some(command) = schedule_command(trimmed, user_field) else { continue };
+        let mut snippet = Snippet::new(dialect);
+        snippet.push_line(&command, idx + 1);
+        snippets.push(snippet);
+    }
+    snippets
+}
+
+/// スケジュール（`@daily` または 5 つの欄）とユーザー名の後のコマンド。`%` 以降は標準入力として渡される
+fn schedule_command(line: &str, user_field: bool) -> Option<String> {
+    let schedule = if line.starts_with('@') { 1 } else { SCHEDULE_FIELDS };
+    let fields = schedule + usize::from(user_field);
+    let mut rest = line;
+    for _ in 0..fields {
+        let (_, after) = rest.split_once(char::is_whitespace)?;
+        rest = after.trim_start();
+    }
+
+    let mut command = String::new();
+    let mut chars = rest.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('%') => command.push('%'),
+                Some(other) => {
+                    command.push('\\');
+                    command.push(other);
+                }
+                None => command.push('\\'),
+            },
+            '%' => break,
+            _ => command.push(c),
+        }
+    }
+    let command = command.trim_end();
+    (!command.is_empty()).then(|| command.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scripts(extractor: &dyn Extractor, content: &str) -> Vec<(String, Vec<usize>, Dialect)> {
+        extractor
+            .extract(content)
+            .into_iter()
+            .map(|snippet| (snippet.script, snippet.lines, snippet.dialect))
+            .collect()
+    }
+
+    #[test]
+    fn percent_ends_the_command_unless_escaped() {
+        assert_eq!(schedule_command("*/5 * * * * date +\\%F > x % input%more", false).as_deref(), Some("date +%F > x"));
+        assert_eq!(schedule_command("@daily  /usr/bin/run --x", false).as_deref(), Some("/usr/bin/run --x"));
+        assert_eq!(schedule_command("0 1 * * * root run \\n", true).as_deref(), Some("run \\n"));
+        assert_eq!(schedule_command("* * * * * % only stdin", false), None);
+        assert_eq!(schedule_command("0 1 * * *", false), None);
+        assert_eq!(schedule_command("@reboot", false), None);
+    }
+
+    #[test]
+    fn shell_assignment_applies_to_the_following_lines() {
+        let content = "# m h dom mon dow command\nMAILTO=\"\"\n0 1 * * * echo $HOME\nSHELL=/bin/bash\n@daily [[ -f x ]] && run\nSHELL=/usr/bin/fish\n@hourly echo fish\nSHELL = \"/bin/sh\"\n*/5 * * * * echo sh\n";
+        assert_eq!(
+            scripts(&Crontab, content),
+            [
+                ("echo $HOME\n".to_string(), vec![3], Dialect::Sh),
+                ("[[ -f x ]] && run\n".to_string(), vec![5], Dialect::Bash),
+                ("echo sh\n".to_string(), vec![9], Dialect::Sh),
+            ]
+        );
+    }
+
+    #[test]
+    fn system_crontab_has_a_user_field() {
+        assert!(SystemCrontab.matches(Path::new("/etc/crontab")));
+        assert!(SystemCrontab.matches(Path::new("/etc/cron.d/backup")));
+        assert!(Crontab.matches(Path::new("deploy/crontab")));
+        assert!(Crontab.matches(Path::new("jobs.crontab")));
+        assert!(!Crontab.matches(Path::new("/etc/crontab")));
+
+        let content = "SHELL=/bin/bash\n17 * * * * root cd / && run-parts /etc/cron.hourly\n";
+        assert_eq!(
+            scripts(&SystemCrontab, content),
+            [("cd / && run-parts /etc/cron.hourly\n".to_string(), vec![2], Dialect::Bash)]
+        );
+    }
+}
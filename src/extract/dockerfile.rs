@@ -0,0 +1,292 @@
+// src/extract/dockerfile.rs
+// Dockerfile の RUN 命令（シェル形式とヒアドキュメント）
+use super::{Extractor, Snippet, script_dialect};
+use crate::detect::{self, Dialect};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::path::Path;
+
+lazy_static! {
+    static ref HEREDOC: Regex = Regex::new(r#"<<(-?)\s*["']?([A-Za-z_][A-Za-z0-9_]*)["']?"#).unwrap();
+    static ref ESCAPE_DIRECTIVE: Regex = Regex::new(r"^#\s*escape\s*=\s*(\S)").unwrap();
+}
+
+pub struct Dockerfile;
+
+impl Extractor for Dockerfile {
+    fn name(&self) -> &'static str {
+        "Dockerfile"
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        let Some(name) = path.file_name().map(|n| n.to_string_lossy().to_lowercase()) else { return false };
+        name == "dockerfile"
+            || name == "containerfile"
+            || name.starts_with("dockerfile.")
+            || name.ends_with(".dockerfile")
+    }
+
+    fn dialect(&self) -> Dialect {
+        // 既定の SHELL は /bin/sh -c
+        Dialect::Sh
+    }
+
+    fn extract(&self, content: &str) -> Vec<Snippet> {
+        let lines: Vec<&str> = content.lines().collect();
+        let escape = escape_char(&lines);
+        // None は SHELL でシェル以外（PowerShell など）が指定されたステージ
+        let mut dialect = Some(self.dialect());
+        let mut snippets = Vec::new();
+
+        let mut idx = 0;
+        while idx < lines.len() {
+            let trimmed = lines[idx].trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                idx += 1;
+                continue;
+            }
+
+            // 継続行を含めた命令の範囲（コメント行と空行は Docker が取り除く）
+            let start = idx;
+            let mut body: Vec<(usize, &str)> = Vec::new();
+            loop {
+                let line = lines[idx];
+                let trimmed = line.trim();
+                let skipped = idx != start && (trimmed.is_empty() || trimmed.starts_with('#'));
+                if !skipped {
+                    body.push((idx + 1, line));
+                }
+                idx += 1;
+                if idx >= lines.len() || (!skipped && !line.trim_end().ends_with(escape)) {
+                    break;
+                }
+            }
+
+            // ヒアドキュメントを使用できるのは RUN / COPY / ADD のみ
+            let (keyword, first) = instruction(lines[start]);
+            let heredocs = match keyword.as_str() {
+                "RUN" | "COPY" | "ADD" => heredoc_markers(&body),
+                _ => Vec::new(),
+            };
+
+            // ヒアドキュメントの本文は命令の後に続く
+            let mut bodies: Vec<Vec<(usize, &str)>> = Vec::new();
+            for (strip_tabs, word) in &heredocs {
+                let mut heredoc = Vec::new();
+                while idx < lines.len() {
+                    let line = lines[idx];
+                    idx += 1;
+                    let candidate = if *strip_tabs { line.trim_start_matches('\t') } else { line };
+                    if candidate == word {
+                        heredoc.push((idx, line));
+                        break;
+                    }
+                    heredoc.push((idx, line));
+                }
+                bodies.push(heredoc);
+            }
+
+            match keyword.as_str() {
+                // SHELL はビルドステージごとに既定に戻る
+                "FROM" => dialect = Some(self.dialect()),
+                "SHELL" => dialect = shell_dialect(&body, escape).filter(|d| d.is_supported()),
+                "RUN" => {
+                    let Some(dialect) = dialect else { continue };
+                    let mut snippet = Snippet::new(dialect);
+                    let first = strip_flags(first);
+
+                    if first.starts_with('[') {
+                        // exec 形式はシェルを介さない
+                        continue;
+                    }
+
+                    if body.len() == 1
+                        && heredocs.len() == 1
+                        && HEREDOC.find(first).is_some_and(|m| m.as_str() == first.trim())
+                    {
+                        // RUN <<EOF はヒアドキュメントの本文をスクリプトとして実行する
+                        let (strip_tabs, _) = heredocs[0];
+                        let script = &bodies[0];
+                        let text: String = script.iter().map(|(_, l)| format!("{}\n", l)).collect();
+                        // シバン行があればそのインタプリタで実行される
+                        let Some(heredoc_dialect) = script_dialect(&text, dialect) else { continue };
+                        snippet.dialect = heredoc_dialect;
+                        // 終端行は含めない
+                        for &(line, text) in script.iter().take(script.len().saturating_sub(1)) {
+                            let text = if strip_tabs { text.trim_start_matches('\t') } else { text };
+                            snippet.push_line(text, line);
+                        }
+                    } else {
+                        // コマンドに渡すヒアドキュメントの本文はデータなのでチェックしない
+                        for (i, &(line, text)) in body.iter().enumerate() {
+                            snippet.push_line(if i == 0 { first } else { text }, line);
+                        }
+                    }
+
+                    if !snippet.is_empty() {
+                        snippets.push(snippet);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        snippets
+    }
+}
+
+/// `# escape=`\`` パーサーディレクティブで指定されたエスケープ文字
+fn escape_char(lines: &[&str]) -> char {
+    for line in lines {
+        let trimmed = line.trim();
+        if let Some(caps) = ESCAPE_DIRECTIVE.captures(trimmed) {
+            return caps[1].chars().next().unwrap_or('\\');
+        }
+        // パーサーディレクティブはファイル先頭のコメントのみ
+        if !trimmed.starts_with('#') {
+            break;
+        }
+    }
+    '\\'
+}
+
+/// 命令のキーワード（大文字）と引数部分を返す（ONBUILD は読み飛ばす）
+fn instruction(line: &str) -> (String, &str) {
+    let trimmed = line.trim_start();
+    let (keyword, rest) = trimmed.split_once(char::is_whitespace).unwrap_or((trimmed, ""));
+    let keyword = keyword.to_uppercase();
+    if keyword == "ONBUILD" {
+        return instruction(rest);
+    }
+    (keyword, rest.trim_start())
+}
+
+/// `--mount=...` などの RUN のオプションを取り除く
+fn strip_flags(mut text: &str) -> &str {
+    while text.starts_with("--") {
+        text = text.split_once(char::is_whitespace).map_or("", |(_, rest)| rest).trim_start();
+    }
+    text
+}
+
+/// 命令に含まれるヒアドキュメントの (タブを取り除くか, 終端語)
+fn heredoc_markers(body: &[(usize, &str)]) -> Vec<(bool, String)> {
+    let mut markers = Vec::new();
+    for (_, line) in body {
+        for caps in HEREDOC.captures_iter(line) {
+            let start = caps.get(0).unwrap().start();
+            // ヒアストリング (<<<) は対象外
+            if line[..start].ends_with('<') {
+                continue;
+            }
+            markers.push((&caps[1] == "-", caps[2].to_string()));
+        }
+    }
+    markers
+}
+
+/// `SHELL ["/bin/bash", "-o", "pipefail", "-c"]` からシェルの方言を判定する
+fn shell_dialect(body: &[(usize, &str)], escape: char) -> Option<Dialect> {
+    let text = body
+        .iter()
+        .map(|(_, line)| line.trim_end().trim_end_matches(escape))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let (_, args) = instruction(&text);
+    let shell: Vec<String> = serde_json::from_str(args.trim()).ok()?;
+    detect::interpreter_dialect(shell.first()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::StyleOptions;
+    use crate::extract;
+    use crate::i18n::Language;
+
+    fn scripts(content: &str) -> Vec<(String, Vec<usize>)> {
+        Dockerfile
+            .extract(content)
+            .into_iter()
+            .map(|snippet| (snippet.script, snippet.lines))
+            .collect()
+    }
+
+    fn dialects(content: &str) -> Vec<Dialect> {
+        Dockerfile.extract(content).into_iter().map(|snippet| snippet.dialect).collect()
+    }
+
+    #[test]
+    fn continuation_skips_comment_and_empty_lines() {
+        let content = "FROM alpine\nRUN apk add curl \\\n    # install tools\n    && echo $HOME \\\n\n    && true\nCMD [\"sh\"]\n";
+        assert_eq!(
+            scripts(content),
+            [("apk add curl \\\n    && echo $HOME \\\n    && true\n".to_string(), vec![2, 4, 6])]
+        );
+
+        let report = extract::check(&Dockerfile, content, &Language::ENGLISH, StyleOptions::default(), &[]);
+        let issues: Vec<(usize, &str)> = report.iter().map(|issue| (issue.line, issue.rule.as_str())).collect();
+        assert_eq!(issues, [(4, "unquoted-variable")]);
+    }
+
+    #[test]
+    fn build_flags_are_stripped_and_exec_form_is_skipped() {
+        let content = "RUN --mount=type=cache,target=/root/.cache --network=none pip install x\nRUN [\"echo\", \"$HOME\"]\nRUN --mount=type=secret,id=a [\"cat\", \"/run/secrets/a\"]\nonbuild run make\n";
+        assert_eq!(
+            scripts(content),
+            [("pip install x\n".to_string(), vec![1]), ("make\n".to_string(), vec![4])]
+        );
+    }
+
+    #[test]
+    fn heredoc_script_lines_map_to_the_dockerfile() {
+        let content = "RUN <<EOF\nset -e\necho $HOME\nEOF\nRUN <<-'EOT'\n\t\techo tab\n\tEOT\nRUN echo after\n";
+        assert_eq!(
+            scripts(content),
+            [
+                ("set -e\necho $HOME\n".to_string(), vec![2, 3]),
+                ("echo tab\n".to_string(), vec![6]),
+                ("echo after\n".to_string(), vec![8]),
+            ]
+        );
+    }
+
+    #[test]
+    fn heredoc_passed_to_a_command_is_data() {
+        let content = "RUN cat <<EOF > /etc/motd\n$(rm -rf /)\nEOF\nRUN echo after\n";
+        assert_eq!(
+            scripts(content),
+            [("cat <<EOF > /etc/motd\n".to_string(), vec![1]), ("echo after\n".to_string(), vec![4])]
+        );
+    }
+
+    #[test]
+    fn heredoc_shebang_selects_or_skips_the_interpreter() {
+        let content = "RUN <<EOF\n#!/usr/bin/env python3\nprint('$x')\nEOF\nRUN <<EOF\n#!/bin/bash\n[[ -n $x ]]\nEOF\n";
+        assert_eq!(dialects(content), [Dialect::Bash]);
+        assert_eq!(scripts(content)[0].1, [6, 7]);
+    }
+
+    #[test]
+    fn escape_directive_changes_the_continuation_character() {
+        let content = "# escape=`\nFROM mcr.microsoft.com/windows\nRUN echo a `\n    && echo b\nRUN echo c \\\nRUN echo d\n";
+        assert_eq!(
+            scripts(content),
+            [
+                ("echo a `\n    && echo b\n".to_string(), vec![3, 4]),
+                ("echo c \\\n".to_string(), vec![5]),
+                ("echo d\n".to_string(), vec![6]),
+            ]
+        );
+        // ディレクティブはファイルの先頭のコメントのみ
+        assert_eq!(scripts("FROM a\n# escape=`\nRUN echo a `\nRUN echo b\n").len(), 2);
+    }
+
+    #[test]
+    fn shell_applies_until_the_next_stage() {
+        let content = "FROM a\nSHELL [\"/bin/bash\", \"-o\", \"pipefail\", \\\n  \"-c\"]\nRUN echo a\nFROM b\nRUN echo b\nSHELL [\"pwsh\", \"-Command\"]\nRUN Write-Host $env:c\nFROM c\nRUN echo d\n";
+        assert_eq!(dialects(content), [Dialect::Bash, Dialect::Sh, Dialect::Sh]);
+        let lines: Vec<Vec<usize>> = scripts(content).into_iter().map(|(_, lines)| lines).collect();
+        assert_eq!(lines, [[4], [6], [10]]);
+    }
+}
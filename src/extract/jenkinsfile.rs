@@ -0,0 +1,307 @@
+// src/extract/jenkinsfile.rs
+// Jenkinsfile の `sh` ステップ
+use super::{EXPANSION_PLACEHOLDER, Extractor, Snippet, script_dialect};
+use crate::detect::Dialect;
+use crate::rules;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::path::Path;
+
+lazy_static! {
+    // sh '...'、sh(script: '...')、sh label: 'x', script: """...""" など（文字列リテラルの直前まで）
+    static ref SH_STEP: Regex = Regex::new(
+        r#"(?:^|[^.\w])sh\s*\(?\s*(?:\w+\s*:\s*(?:'[^'\n]*'|"[^"\n]*"|[\w.]+)\s*,\s*)*(?:script\s*:\s*)?['"]"#
+    )
+    .unwrap();
+    // Groovy の文字列補間 ${...} と $name.property
+    static ref INTERPOLATION: Regex = Regex::new(r"\$\{[^}]*\}|\$[A-Za-z_][\w.]*").unwrap();
+    // ビルドのパラメーターとプルリクエストの情報は外部から指定できる
+    static ref UNTRUSTED: Regex = Regex::new(r"\bparams\.|\benv\.(?:CHANGE_|BRANCH_NAME\b|TAG_NAME\b)").unwrap();
+}
+
+// Jenkins は `sh -xe` でスクリプトを実行し、文字列のインデントは Groovy のコードに合わせて書かれる
+const JENKINS_DISABLED_RULES: &[&str] = &[
+    rules::CD_WITHOUT_CHECK,
+    rules::TAB_INDENTATION,
+    rules::INCONSISTENT_INDENTATION,
+];
+
+pub struct Jenkinsfile;
+
+impl Extractor for Jenkinsfile {
+    fn name(&self) -> &'static str {
+        "Jenkinsfile"
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        let Some(name) = path.file_name().map(|n| n.to_string_lossy()) else { return false };
+        name == "Jenkinsfile" || name.starts_with("Jenkinsfile.") || name.ends_with(".jenkinsfile")
+    }
+
+    fn dialect(&self) -> Dialect {
+        Dialect::Sh
+    }
+
+    fn extract(&self, content: &str) -> Vec<Snippet> {
+        let mut snippets = Vec::new();
+        for literal in sh_literals(content) {
+            let mut snippet = Snippet::new(self.dialect());
+            for (line, text) in &literal.lines {
+                if literal.interpolated {
+                    for expr in INTERPOLATION.find_iter(text) {
+                        if UNTRUSTED.is_match(expr.as_str()) {
+                            snippet.injections.push((*line, expr.as_str().to_string()));
+                        }
+                    }
+                    snippet.push_line(&INTERPOLATION.replace_all(text, EXPANSION_PLACEHOLDER), *line);
+                } else {
+                    snippet.push_line(text, *line);
+                }
+            }
+            // シバン行があればそのインタプリタで実行される
+            let Some(dialect) = script_dialect(&snippet.script, snippet.dialect) else { continue };
+            snippet.dialect = dialect;
+            if !snippet.is_empty() {
+                snippets.push(snippet);
+            }
+        }
+        snippets
+    }
+
+    fn disabled_rules(&self) -> &[&'static str] {
+        JENKINS_DISABLED_RULES
+    }
+
+    // Groovy には `#` のコメントがない
+    fn file_directive(&self, _rule: &str) -> Option<String> {
+        None
+    }
+
+    // 挿入した行が複数行の文字列リテラルの中に入る場合のみ、スクリプトのコメントになる
+    fn accepts_line_directive(&self, content: &str, line: usize) -> bool {
+        sh_literals(content)
+            .iter()
+            .any(|literal| literal.start < line && literal.lines.iter().any(|(number, _)| *number == line))
+    }
+}
+
+/// `sh` ステップのスクリプトの文字列リテラル
+fn sh_literals(content: &str) -> Vec<Literal> {
+    let code = mask_comments(content);
+    let mut literals = Vec::new();
+
+    let mut pos = 0;
+    while let Some(step) = SH_STEP.find_at(&code, pos) {
+        // 文字列リテラルの開始位置
+        let start = step.end() - 1;
+        let Some((literal, end)) = string_literal(content, start) else {
+            pos = step.end();
+            continue;
+        };
+        pos = end;
+        literals.push(literal);
+    }
+    literals
+}
+
+/// Groovy の文字列リテラルの内容
+struct Literal {
+    // 開始の引用符の行番号
+    start: usize,
+    // 各行の (行番号, 内容)
+    lines: Vec<(usize, String)>,
+    // ダブルクォートの文字列 (GString) は補間される
+    interpolated: bool,
+}
+
+/// `start` の位置から始まる文字列リテラルを読み、内容と終了位置を返す
+fn string_literal(content: &str, start: usize) -> Option<(Literal, usize)> {
+    let rest = &content[start..];
+    let quote = rest.chars().next()?;
+    let triple = rest.starts_with(if quote == '"' { "\"\"\"" } else { "'''" });
+    let delimiter_len = if triple { 3 } else { 1 };
+
+    let first_line = content[..start].matches('\n').count() + 1;
+    let mut line = first_line;
+    let mut lines = vec![(line, String::new())];
+    let mut chars = rest[delimiter_len..].char_indices();
+    while let Some((offset, c)) = chars.next() {
+        let remaining = &rest[delimiter_len + offset..];
+        let closed = if triple { remaining.starts_with(&rest[..3]) } else { c == quote };
+        if closed {
+            // 開始の引用符の直後で改行している場合、最初の空行は含めない
+            if lines.len() > 1 && lines[0].1.trim().is_empty() {
+                lines.remove(0);
+            }
+            // 終了の引用符だけの行のインデント
+            if lines.len() > 1 && lines.last().is_some_and(|(_, text)| text.trim().is_empty()) {
+                lines.pop();
+            }
+            let literal = Literal {
+                start: first_line,
+                lines,
+                interpolated: quote == '"',
+            };
+            return Some((literal, start + delimiter_len + offset + delimiter_len));
+        }
+
+        match c {
+            '\n' if !triple => return None,
+            '\n' => {
+                line += 1;
+                lines.push((line, String::new()));
+            }
+            '\\' => {
+                let (_, escaped) = chars.next()?;
+                let current = &mut lines.last_mut()?.1;
+                match escaped {
+                    '\\' | '\'' | '"' | '$' => current.push(escaped),
+                    // `\` と改行は行の継続
+                    '\n' => line += 1,
+                    other => {
+                        current.push('\\');
+                        current.push(other);
+                    }
+                }
+            }
+            _ => lines.last_mut()?.1.push(c),
+        }
+    }
+    None
+}
+
+/// コメントを空白に置き換える（文字列リテラル内の `//` は残す）
+fn mask_comments(content: &str) -> String {
+    let bytes = content.as_bytes();
+    let mut masked = bytes.to_vec();
+    let mut idx = 0;
+    let mut quote: Option<&[u8]> = None;
+
+    while idx < bytes.len() {
+        let rest = &bytes[idx..];
+        if let Some(delimiter) = quote {
+            if rest[0] == b'\\' {
+                idx += 2;
+                continue;
+            }
+            if rest.starts_with(delimiter) {
+                quote = None;
+                idx += delimiter.len();
+                continue;
+            }
+            idx += 1;
+            continue;
+        }
+
+        let comment_end = if rest.starts_with(b"//") {
+            rest.iter().position(|b| *b == b'\n').unwrap_or(rest.len())
+        } else if rest.starts_with(b"/*") {
+            rest.windows(2).position(|w| w == b"*/").map_or(rest.len(), |n| n + 2)
+        } else {
+            quote = [&b"'''"[..], b"\"\"\"", b"'", b"\""].into_iter().find(|q| rest.starts_with(q));
+            idx += quote.map_or(1, |q| q.len());
+            continue;
+        };
+        for b in &mut masked[idx..idx + comment_end] {
+            if *b != b'\n' {
+                *b = b' ';
+            }
+        }
+        idx += comment_end;
+    }
+    String::from_utf8(masked).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scripts(content: &str) -> Vec<(String, Vec<usize>)> {
+        Jenkinsfile
+            .extract(content)
+            .into_iter()
+            .map(|snippet| (snippet.script, snippet.lines))
+            .collect()
+    }
+
+    #[test]
+    fn mask_comments_keeps_strings_and_byte_offsets() {
+        let content = "sh 'a // b' // sh 'c'\n/* sh 'd'\n ü */ sh \"http://x\"\n";
+        let masked = mask_comments(content);
+        assert_eq!(masked.len(), content.len());
+        // 2 バイトの `ü` は 2 つの空白になる
+        assert_eq!(
+            masked,
+            format!("sh 'a // b'{}\n{}\n{} sh \"http://x\"\n", " ".repeat(10), " ".repeat(9), " ".repeat(6))
+        );
+    }
+
+    #[test]
+    fn mask_comments_in_triple_quoted_and_escaped_strings() {
+        let content = "sh '''\n// kept\n''' // gone\nsh \"a \\\" // kept\"\n";
+        assert_eq!(mask_comments(content), "sh '''\n// kept\n'''        \nsh \"a \\\" // kept\"\n");
+    }
+
+    #[test]
+    fn triple_quoted_literal_drops_delimiter_lines() {
+        let content = "sh '''\n    echo a\n    echo b\n    '''\n";
+        let (literal, end) = string_literal(content, 3).unwrap();
+        assert_eq!(literal.start, 1);
+        assert_eq!(literal.lines, [(2, "    echo a".to_string()), (3, "    echo b".to_string())]);
+        assert!(!literal.interpolated);
+        assert_eq!(&content[end..], "\n");
+    }
+
+    #[test]
+    fn literal_escapes_and_line_continuation() {
+        let content = "sh \"\"\"echo \\$HOME \\\\ \\\"q\\\" \\\n  done\necho c\"\"\"";
+        let (literal, end) = string_literal(content, 3).unwrap();
+        assert!(literal.interpolated);
+        assert_eq!(literal.lines, [(1, "echo $HOME \\ \"q\"   done".to_string()), (3, "echo c".to_string())]);
+        assert_eq!(end, content.len());
+    }
+
+    #[test]
+    fn unterminated_single_line_literal() {
+        assert!(string_literal("sh 'echo\n'", 3).is_none());
+    }
+
+    #[test]
+    fn extract_replaces_interpolation_and_records_untrusted_input() {
+        let content = "sh \"echo ${params.NAME} $env.BRANCH_NAME ${WORKSPACE}\"\n";
+        let snippets = Jenkinsfile.extract(content);
+        assert_eq!(snippets.len(), 1);
+        assert_eq!(snippets[0].script, format!("echo {0} {0} {0}\n", EXPANSION_PLACEHOLDER));
+        assert_eq!(
+            snippets[0].injections,
+            [(1, "${params.NAME}".to_string()), (1, "$env.BRANCH_NAME".to_string())]
+        );
+        // シングルクォートの文字列は補間されない
+        assert_eq!(scripts("sh 'echo ${params.NAME}'\n"), [("echo ${params.NAME}\n".to_string(), vec![1])]);
+    }
+
+    #[test]
+    fn extract_skips_commented_steps_and_handles_non_ascii() {
+        let content = "// sh 'rm -rf /'\necho \"日本語\"; sh(script: \"echo ü\", label: 'x')\n/* sh 'a' */ sh label: 'é', script: '''\n  echo 終\n'''\n";
+        assert_eq!(
+            scripts(content),
+            [("echo ü\n".to_string(), vec![2]), ("  echo 終\n".to_string(), vec![4])]
+        );
+    }
+
+    #[test]
+    fn line_directive_only_inside_multiline_literal() {
+        let content = "sh 'echo a'\nsh \"\"\"echo b\n  echo c\n\"\"\"\nsh '''\n  echo d\n'''\n";
+        let accepted: Vec<usize> = (1..=7).filter(|line| Jenkinsfile.accepts_line_directive(content, *line)).collect();
+        assert_eq!(accepted, [3, 6]);
+    }
+
+    #[test]
+    fn extract_skips_scripts_for_other_interpreters() {
+        let content = "sh '''#!/usr/bin/env python3\nprint($x)\n'''\nsh '''#!/bin/bash\n[[ -n $x ]]\n'''\nsh 'echo $x'\n";
+        let snippets = Jenkinsfile.extract(content);
+        let dialects: Vec<(Dialect, Vec<usize>)> =
+            snippets.into_iter().map(|snippet| (snippet.dialect, snippet.lines)).collect();
+        assert_eq!(dialects, [(Dialect::Bash, vec![4, 5]), (Dialect::Sh, vec![7])]);
+    }
+}
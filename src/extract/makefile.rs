@@ -0,0 +1,212 @@
+// src/extract/makefile.rs
+// Makefile のレシピ
+use super::{EXPANSION_PLACEHOLDER, Extractor, Snippet};
+use crate::detect::{self, Dialect};
+use crate::rules;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::path::Path;
+
+lazy_static! {
+    // 変数の代入 (VAR = x, VAR := x, override VAR += x など)
+    static ref ASSIGNMENT: Regex =
+        Regex::new(r"^(?:(?:override|export|private)\s+)*[^\s:#=]+\s*(?:=|:=|::=|:::=|\?=|\+=|!=)").unwrap();
+    static ref SHELL_ASSIGNMENT: Regex = Regex::new(r"^(?:override\s+)?SHELL\s*(?::{0,3}=|\?=)\s*(\S+)").unwrap();
+    static ref CONDITIONAL: Regex = Regex::new(r"^(?:ifeq|ifneq|ifdef|ifndef|else|endif)\b").unwrap();
+}
+
+// レシピはタブでインデントする必要があり、継続行のインデントも自由に書かれる
+const MAKEFILE_DISABLED_RULES: &[&str] = &[
+    rules::TAB_INDENTATION,
+    rules::INCONSISTENT_INDENTATION,
+];
+
+pub struct Makefile;
+
+impl Extractor for Makefile {
+    fn name(&self) -> &'static str {
+        "Makefile"
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        let Some(name) = path.file_name().map(|n| n.to_string_lossy()) else { return false };
+        name == "Makefile" || name == "makefile" || name == "GNUmakefile" || name.ends_with(".mk")
+    }
+
+    fn dialect(&self) -> Dialect {
+        // make は既定で /bin/sh -c でレシピを実行する
+        Dialect::Sh
+    }
+
+    fn extract(&self, content: &str) -> Vec<Snippet> {
+        let lines: Vec<&str> = content.lines().collect();
+        // SHELL はレシピの実行時に展開されるため、定義の位置によらず全体に適用される
+        let dialect = shell_dialect(&lines).unwrap_or(self.dialect());
+        let mut snippets = Vec::new();
+        let mut recipe: Option<Snippet> = None;
+
+        let mut idx = 0;
+        while idx < lines.len() {
+            let line = lines[idx];
+
+            // レシピの行（タブで始まる行）。継続行の先頭のタブは make が取り除く
+            if let (Some(snippet), Some(text)) = (recipe.as_mut(), line.strip_prefix('\t')) {
+                let mut command = strip_prefixes(text);
+                loop {
+                    snippet.push_line(&substitute_variables(command), idx + 1);
+                    idx += 1;
+                    if !command.trim_end().ends_with('\\') || idx >= lines.len() {
+                        break;
+                    }
+                    command = lines[idx].strip_prefix('\t').unwrap_or(lines[idx]);
+                }
+                continue;
+            }
+
+            let trimmed = line.trim();
+            // 空行、make のコメント、条件分岐はレシピを終わらせない
+            if trimmed.is_empty() || trimmed.starts_with('#') || CONDITIONAL.is_match(trimmed) {
+                idx += 1;
+                continue;
+            }
+
+            // 継続行を含めた make の構文の行
+            let start = idx;
+            while lines[idx].trim_end().ends_with('\\') && idx + 1 < lines.len() {
+                idx += 1;
+            }
+            idx += 1;
+
+            snippets.extend(recipe.take().filter(|snippet| !snippet.is_empty()));
+
+            if trimmed == "define" || trimmed.starts_with("define ") {
+                // 複数行の変数定義はシェルのコードではない
+                while idx < lines.len() && lines[idx].trim() != "endef" {
+                    idx += 1;
+                }
+                idx += 1;
+            } else if !ASSIGNMENT.is_match(trimmed) && is_rule(trimmed) {
+                let mut snippet = Snippet::new(dialect);
+                // `target: prerequisites ; command` の形式のレシピ
+                if start + 1 == idx
+                    && let Some((_, command)) = trimmed.split_once(';')
+                {
+                    snippet.push_line(&substitute_variables(strip_prefixes(command.trim_start())), start + 1);
+                }
+                recipe = Some(snippet);
+            }
+        }
+
+        snippets.extend(recipe.filter(|snippet| !snippet.is_empty()));
+        snippets
+    }
+
+    fn disabled_rules(&self) -> &[&'static str] {
+        MAKEFILE_DISABLED_RULES
+    }
+}
+
+/// `SHELL = /bin/bash` で指定されたシェルの方言
+fn shell_dialect(lines: &[&str]) -> Option<Dialect> {
+    lines
+        .iter()
+        .rev()
+        .filter_map(|line| SHELL_ASSIGNMENT.captures(line.trim()))
+        .filter_map(|caps| detect::interpreter_dialect(&caps[1]))
+        .find(|dialect| dialect.is_supported())
+}
+
+/// ルールの行（`target: prerequisites`）か
+fn is_rule(line: &str) -> bool {
+    let line = line.split_once('#').map_or(line, |(before, _)| before);
+    line.contains(':')
+}
+
+/// レシピの先頭の `@`（エコーしない）、`-`（エラーを無視）、`+`（常に実行）を取り除く
+fn strip_prefixes(text: &str) -> &str {
+    text.trim_start_matches(['@', '-', '+', ' ', '\t'])
+}
+
+/// `$$` を `$` に戻し、make の変数と関数をプレースホルダーに置き換える
+fn substitute_variables(text: &str) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('$') => result.push('$'),
+            Some(open @ ('(' | '{')) => {
+                let close = if open == '(' { ')' } else { '}' };
+                // $(shell ...) や $(patsubst ...) の入れ子の括弧
+                let mut depth = 1;
+                for c in chars.by_ref() {
+                    if c == open {
+                        depth += 1;
+                    } else if c == close {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                }
+                result.push_str(EXPANSION_PLACEHOLDER);
+            }
+            // $@、$<、$^ などの自動変数と 1 文字の変数
+            Some(_) => result.push_str(EXPANSION_PLACEHOLDER),
+            None => result.push('$'),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scripts(content: &str) -> Vec<(String, Vec<usize>, Dialect)> {
+        Makefile
+            .extract(content)
+            .into_iter()
+            .map(|snippet| (snippet.script, snippet.lines, snippet.dialect))
+            .collect()
+    }
+
+    #[test]
+    fn substitute_make_variables_and_functions() {
+        assert_eq!(
+            substitute_variables("echo $$HOME $(CC) ${X} $(shell echo $(Y)) $@ $"),
+            format!("echo $HOME {0} {0} {0} {0} $", EXPANSION_PLACEHOLDER)
+        );
+    }
+
+    #[test]
+    fn recipe_prefixes_continuations_and_line_mapping() {
+        let content = "build: deps\n\t@echo $$HOME \\\n\t  && $(CC) -o $@ main.c\n\t-rm -f tmp\n\n# comment\n\t+make sub\nclean: ; @rm -rf out\nSHELL := /bin/bash\n";
+        assert_eq!(
+            scripts(content),
+            [
+                (
+                    format!("echo $HOME \\\n  && {0} -o {0} main.c\nrm -f tmp\nmake sub\n", EXPANSION_PLACEHOLDER),
+                    vec![2, 3, 4, 7],
+                    Dialect::Bash,
+                ),
+                ("rm -rf out\n".to_string(), vec![8], Dialect::Bash),
+            ]
+        );
+    }
+
+    #[test]
+    fn define_blocks_and_assignments_are_not_recipes() {
+        let content = "define HELP\n\techo $(x)\nall:\nendef\nall:\n\techo a\nVAR = a:b\n\techo not a recipe\nifdef DEBUG\nlint: ; sh -n x.sh\nendif\n";
+        assert_eq!(
+            scripts(content),
+            [
+                ("echo a\n".to_string(), vec![6], Dialect::Sh),
+                ("sh -n x.sh\n".to_string(), vec![10], Dialect::Sh),
+            ]
+        );
+    }
+}
@@ -0,0 +1,214 @@
+// src/extract/markdown.rs
+// Markdown のコードブロック (```bash / ```sh / ```shell / ```console)
+use super::{Extractor, Snippet};
+use crate::detect::Dialect;
+use crate::rules;
+use crate::suppress;
+use std::path::Path;
+
+// 手順書のコード例は断片なので、スクリプト全体に対するルールとスタイルのルールは報告しない
+const MARKDOWN_DISABLED_RULES: &[&str] = &[
+    rules::TAB_INDENTATION,
+    rules::INCONSISTENT_INDENTATION,
+    rules::LINE_TOO_LONG,
+    rules::FUNCTION_NAMING,
+    rules::VARIABLE_NAMING,
+];
+
+const PROMPT: &str = "$ ";
+
+pub struct Markdown;
+
+impl Extractor for Markdown {
+    fn name(&self) -> &'static str {
+        "Markdown"
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        path.extension().is_some_and(|ext| ext == "md" || ext == "markdown")
+    }
+
+    fn dialect(&self) -> Dialect {
+        Dialect::Bash
+    }
+
+    fn extract(&self, content: &str) -> Vec<Snippet> {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut snippets = Vec::new();
+
+        let mut idx = 0;
+        while idx < lines.len() {
+            let Some(fence) = Fence::open(lines[idx]) else {
+                idx += 1;
+                continue;
+            };
+            idx += 1;
+
+            let mut block = Vec::new();
+            while idx < lines.len() && !fence.is_close(lines[idx]) {
+                block.push((idx + 1, fence.strip_indent(lines[idx])));
+                idx += 1;
+            }
+            // 閉じフェンス
+            idx += 1;
+
+            let snippet = match fence.language.as_str() {
+                "bash" | "shell" => code_snippet(&block, Dialect::Bash),
+                "sh" => code_snippet(&block, Dialect::Sh),
+                "console" => console_snippet(&block),
+                _ => continue,
+            };
+            if !snippet.is_empty() {
+                snippets.push(snippet);
+            }
+        }
+        snippets
+    }
+
+    fn disabled_rules(&self) -> &[&'static str] {
+        MARKDOWN_DISABLED_RULES
+    }
+
+    // `#` で始まる行は見出しになるため HTML コメントで書く
+    fn file_directive(&self, rule: &str) -> Option<String> {
+        Some(suppress::html_file_directive(rule))
+    }
+}
+
+/// コードブロックの開始フェンス
+struct Fence {
+    marker: char,
+    length: usize,
+    indent: usize,
+    language: String,
+}
+
+impl Fence {
+    fn open(line: &str) -> Option<Fence> {
+        let trimmed = line.trim_start();
+        let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+        let length = trimmed.chars().take_while(|c| *c == marker).count();
+        if length < 3 {
+            return None;
+        }
+
+        let info = trimmed[length..].trim();
+        // バッククォートのフェンスの情報文字列にはバッククォートを含められない
+        if marker == '`' && info.contains('`') {
+            return None;
+        }
+        // `bash title="x"` や `{.bash}` のような情報文字列の最初の単語が言語
+        let language = info
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .next()
+            .unwrap_or("")
+            .trim_matches(|c| c == '{' || c == '}' || c == '.')
+            .to_lowercase();
+
+        Some(Fence {
+            marker,
+            length,
+            indent: line.len() - trimmed.len(),
+            language,
+        })
+    }
+
+    fn is_close(&self, line: &str) -> bool {
+        let trimmed = line.trim();
+        let length = trimmed.chars().take_while(|c| *c == self.marker).count();
+        length >= self.length && trimmed.len() == length
+    }
+
+    /// 開始フェンスと同じ深さまでのインデントを取り除く（リスト内のコードブロック用）
+    fn strip_indent<'a>(&self, line: &'a str) -> &'a str {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        &line[indent.min(self.indent)..]
+    }
+}
+
+fn code_snippet(block: &[(usize, &str)], dialect: Dialect) -> Snippet {
+    let mut snippet = Snippet::new(dialect);
+    for &(line, text) in block {
+        snippet.push_line(text, line);
+    }
+    snippet
+}
+
+/// `$ ` で始まるコマンド行（と継続行）のみを取り出し、出力の行は除く
+fn console_snippet(block: &[(usize, &str)]) -> Snippet {
+    let mut snippet = Snippet::new(Dialect::Bash);
+    let mut continued = false;
+    for &(line, text) in block {
+        let command = if continued {
+            Some(text.strip_prefix("> ").unwrap_or(text))
+        } else {
+            text.strip_prefix(PROMPT).or_else(|| (text == "$").then_some(""))
+        };
+        match command {
+            Some(command) => {
+                continued = command.trim_end().ends_with('\\');
+                snippet.push_line(command, line);
+            }
+            None => continued = false,
+        }
+    }
+    snippet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scripts(content: &str) -> Vec<(String, Vec<usize>, Dialect)> {
+        Markdown
+            .extract(content)
+            .into_iter()
+            .map(|snippet| (snippet.script, snippet.lines, snippet.dialect))
+            .collect()
+    }
+
+    #[test]
+    fn fences_select_the_dialect_and_skip_other_languages() {
+        let content = "# Setup\n\n```bash\necho $HOME\n```\n\n~~~sh\necho a\n~~~\n\n```python\nprint(1)\n```\n\n```\nls\n```\n";
+        assert_eq!(
+            scripts(content),
+            [
+                ("echo $HOME\n".to_string(), vec![4], Dialect::Bash),
+                ("echo a\n".to_string(), vec![8], Dialect::Sh),
+            ]
+        );
+    }
+
+    #[test]
+    fn info_string_and_closing_fence() {
+        assert_eq!(Fence::open("```Bash title=\"install.sh\"").unwrap().language, "bash");
+        assert_eq!(Fence::open("``` {.shell}").unwrap().language, "shell");
+        assert_eq!(Fence::open("```sh,linenos").unwrap().language, "sh");
+        assert!(Fence::open("``bash").is_none());
+        assert!(Fence::open("```bash `x`").is_none());
+
+        // 閉じフェンスは同じ記号で開始フェンス以上の長さ
+        let content = "````bash\n```\necho a\n~~~~\n````\necho b\n";
+        assert_eq!(scripts(content), [("```\necho a\n~~~~\n".to_string(), vec![2, 3, 4], Dialect::Bash)]);
+    }
+
+    #[test]
+    fn indented_fence_in_a_list() {
+        let content = "1. Install\n\n   ```sh\n   if true; then\n       echo a\n   fi\n   ```\n";
+        assert_eq!(
+            scripts(content),
+            [("if true; then\n    echo a\nfi\n".to_string(), vec![4, 5, 6], Dialect::Sh)]
+        );
+    }
+
+    #[test]
+    fn console_keeps_commands_and_continuations() {
+        let content = "```console\n$ cd /tmp\n$\n$ curl -fsSL $URL \\\n>   | sh\ninstalled\n> not a continuation\n```\n";
+        assert_eq!(
+            scripts(content),
+            [("cd /tmp\n\ncurl -fsSL $URL \\\n  | sh\n".to_string(), vec![2, 3, 4, 5], Dialect::Bash)]
+        );
+        // 出力だけのブロックは空
+        assert!(scripts("```console\nHello\n```\n").is_empty());
+    }
+}
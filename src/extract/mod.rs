@@ -0,0 +1,212 @@
+// src/extract/mod.rs
+// 他の形式のファイルに埋め込まれたシェルスクリプトの抽出
+mod bats;
+mod ci;
+mod crontab;
+mod dockerfile;
+mod jenkinsfile;
+mod makefile;
+mod markdown;
+mod systemd;
+mod yaml;
+
+use crate::checker::{Checker, CustomRule, StyleOptions};
+use crate::detect::{self, Dialect};
+use crate::i18n::{Language, MSG_EXPRESSION_INJECTION};
+use crate::parser::ScriptParser;
+use crate::report::{Category, Report, Severity};
+use crate::rules;
+use crate::suppress;
+use std::path::Path;
+
+/// 取り出したスクリプトの断片
+pub struct Snippet {
+    pub script: String,
+    // スクリプトの各行に対応する元ファイルの行番号
+    pub lines: Vec<usize>,
+    pub dialect: Dialect,
+    // 実行前にスクリプトへ展開される信頼できない式 (元ファイルの行番号, 式)
+    pub injections: Vec<(usize, String)>,
+}
+
+impl Snippet {
+    pub fn new(dialect: Dialect) -> Self {
+        Snippet {
+            script: String::new(),
+            lines: Vec::new(),
+            dialect,
+            injections: Vec::new(),
+        }
+    }
+
+    pub fn push_line(&mut self, text: &str, line: usize) {
+        self.script.push_str(text);
+        self.script.push('\n');
+        self.lines.push(line);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+/// 実行前にテキストとして展開される値（make の変数、systemd の指定子、Groovy の補間、CI の式）の置き換え先
+///
+/// 展開はシェルがスクリプトを解釈する前に行われるため、シェルからは値全体が 1 つの単語に見える。
+pub const EXPANSION_PLACEHOLDER: &str = "__expanded__";
+
+// シバン行や set オプションは埋め込まれたスクリプトでは不要
+const EMBEDDED_DISABLED_RULES: &[&str] = &[
+    rules::MISSING_SHEBANG,
+    rules::MISSING_SET_E,
+    rules::MISSING_SET_U,
+    rules::MISSING_PIPEFAIL,
+];
+
+/// ファイル形式ごとのスクリプトの抽出方法
+pub trait Extractor: Sync {
+    /// 形式の名前（一覧表示に使用）
+    fn name(&self) -> &'static str;
+
+    /// このファイルを扱うか（ファイル名で判定する）
+    fn matches(&self, path: &Path) -> bool;
+
+    /// 断片で特に指定がない場合の方言
+    fn dialect(&self) -> Dialect;
+
+    fn extract(&self, content: &str) -> Vec<Snippet>;
+
+    /// 隠しディレクトリでも走査するディレクトリ名（`.github` など）
+    fn hidden_dirs(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// 埋め込まれたスクリプトに共通のもの（シバン行と set オプション）に加えて報告しないルール
+    fn disabled_rules(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// ファイルの先頭に挿入する抑制コメント（ファイル形式に `#` のコメントがない場合は形式に合わせる）
+    ///
+    /// 抑制コメントを書けない形式では None を返す。
+    fn file_directive(&self, rule: &str) -> Option<String> {
+        Some(suppress::file_directive(rule))
+    }
+
+    /// 行番号 `line` の行の前に `#` の行の抑制コメントを挿入できるか
+    fn accepts_line_directive(&self, _content: &str, _line: usize) -> bool {
+        true
+    }
+}
+
+// 新しい形式は Extractor を実装してここに追加する（走査、一覧表示、LSP はこの一覧を参照する）
+static EXTRACTORS: &[&dyn Extractor] = &[
+    &dockerfile::Dockerfile,
+    &ci::GithubActions,
+    &ci::GitlabCi,
+    &makefile::Makefile,
+    &markdown::Markdown,
+    &systemd::SystemdUnit,
+    &crontab::SystemCrontab,
+    &crontab::Crontab,
+    &jenkinsfile::Jenkinsfile,
+    &bats::Bats,
+];
+
+/// ファイルに対応する抽出方法を探す
+pub fn find(path: &Path) -> Option<&'static dyn Extractor> {
+    EXTRACTORS.iter().copied().find(|extractor| extractor.matches(path))
+}
+
+/// 隠しファイル・隠しディレクトリを走査対象に含めるか（`.github`、`.gitlab-ci.yml` など）
+pub fn wants_hidden(path: &Path, is_dir: bool) -> bool {
+    if !is_dir {
+        return find(path).is_some();
+    }
+    let Some(name) = path.file_name() else { return false };
+    EXTRACTORS
+        .iter()
+        .any(|extractor| extractor.hidden_dirs().iter().any(|dir| name == *dir))
+}
+
+/// 断片のシバン行で指定されたシェル（シバン行がなければ `default`）
+///
+/// python などのシェル以外のインタプリタとチェック対象外のシェルでは None を返す。
+fn script_dialect(script: &str, default: Dialect) -> Option<Dialect> {
+    let script = script.trim_start();
+    if !script.starts_with("#!") {
+        return Some(default);
+    }
+    detect::detect(Path::new(""), script).dialect.filter(|d| d.is_supported())
+}
+
+fn is_disabled(extractor: &dyn Extractor, rule: &str) -> bool {
+    EMBEDDED_DISABLED_RULES.contains(&rule) || extractor.disabled_rules().contains(&rule)
+}
+
+/// 埋め込まれたスクリプトをチェックし、元ファイルの行番号に対応付けたレポートを返す
+pub fn check(
+    extractor: &dyn Extractor,
+    content: &str,
+    language: &Language,
+    style: StyleOptions,
+    custom_rules: &[CustomRule],
+) -> Report {
+    let source: Vec<&str> = content.lines().collect();
+    let mut report = Report::new();
+
+    for snippet in extractor.extract(content) {
+        let snippet_report = Checker::new(&snippet.script, language.clone())
+            .with_dialect(snippet.dialect)
+            .with_style(style)
+            .with_custom_rules(custom_rules.to_vec())
+            .check();
+        let snippet_lines: Vec<&str> = snippet.script.lines().collect();
+
+        if !is_disabled(extractor, rules::EXPRESSION_INJECTION) {
+            for (line, expression) in &snippet.injections {
+                report.add_issue(
+                    *line,
+                    rules::EXPRESSION_INJECTION,
+                    Severity::Error,
+                    Category::Security,
+                    &MSG_EXPRESSION_INJECTION.format(language, &[("expression", expression.into())]),
+                );
+            }
+        }
+
+        for issue in snippet_report {
+            if is_disabled(extractor, &issue.rule) {
+                continue;
+            }
+            let Some(&line) = snippet.lines.get(issue.line.saturating_sub(1)) else { continue };
+
+            // 修正案は元ファイルの行がスクリプトの行をインデントしたものである場合のみ使用できる
+            let indent = match (source.get(line - 1), snippet_lines.get(issue.line.saturating_sub(1))) {
+                (Some(source_line), Some(snippet_line)) => source_line
+                    .strip_suffix(snippet_line)
+                    .filter(|prefix| prefix.trim().is_empty()),
+                _ => None,
+            };
+            let mut issue = issue;
+            issue.line = line;
+            issue.fix = match (issue.fix, indent) {
+                (Some(mut fix), Some(indent)) => {
+                    fix.replacement = fix
+                        .replacement
+                        .lines()
+                        .map(|l| format!("{}{}", indent, l))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    Some(fix)
+                }
+                _ => None,
+            };
+            report.push(issue);
+        }
+    }
+
+    // 抑制コメントは元ファイルのコメントとして書く
+    suppress::apply(&ScriptParser::new(content), &mut report);
+    report
+}
@@ -0,0 +1,221 @@
+// src/extract/systemd.rs
+// systemd ユニットの ExecStart= などに書かれた `/bin/bash -c '...'`
+use super::{EXPANSION_PLACEHOLDER, Extractor, Snippet};
+use crate::detect::{self, Dialect};
+use crate::rules;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::path::Path;
+
+lazy_static! {
+    static ref EXEC_KEY: Regex =
+        Regex::new(r"^(?:ExecStart|ExecStartPre|ExecStartPost|ExecStop|ExecStopPost|ExecReload|ExecCondition)\s*=").unwrap();
+}
+
+const UNIT_EXTENSIONS: &[&str] = &["service", "socket", "mount", "swap"];
+
+// 継続行のインデントはユニットファイルの書式によるもの
+const SYSTEMD_DISABLED_RULES: &[&str] = &[
+    rules::TAB_INDENTATION,
+    rules::INCONSISTENT_INDENTATION,
+];
+
+/// 元ファイルの行番号付きの文字
+type Chars = Vec<(char, usize)>;
+
+pub struct SystemdUnit;
+
+impl Extractor for SystemdUnit {
+    fn name(&self) -> &'static str {
+        "systemd unit"
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        path.extension()
+            .is_some_and(|ext| UNIT_EXTENSIONS.iter().any(|unit| ext == *unit))
+    }
+
+    fn dialect(&self) -> Dialect {
+        Dialect::Sh
+    }
+
+    fn extract(&self, content: &str) -> Vec<Snippet> {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut snippets = Vec::new();
+
+        let mut idx = 0;
+        while idx < lines.len() {
+            let Some(key) = EXEC_KEY.find(lines[idx].trim_start()) else {
+                idx += 1;
+                continue;
+            };
+
+            // 行末の `\` による継続行は空白で連結される
+            let mut value: Chars = Vec::new();
+            let mut text = &lines[idx].trim_start()[key.end()..];
+            loop {
+                let continued = text.trim_end().ends_with('\\');
+                let text_part = if continued { &text.trim_end()[..text.trim_end().len() - 1] } else { text };
+                value.extend(text_part.chars().map(|c| (c, idx + 1)));
+                idx += 1;
+                if !continued || idx >= lines.len() {
+                    break;
+                }
+                value.push((' ', idx));
+                text = lines[idx];
+            }
+
+            // Type=oneshot では ` ; ` で複数のコマンドを指定できる
+            for command in split_words(&value).split(|word| word_text(word) == ";") {
+                if let Some(snippet) = shell_snippet(command) {
+                    snippets.push(snippet);
+                }
+            }
+        }
+        snippets
+    }
+
+    fn disabled_rules(&self) -> &[&'static str] {
+        SYSTEMD_DISABLED_RULES
+    }
+}
+
+/// `bash -c 'script'` の script を断片として取り出す
+fn shell_snippet(command: &[Chars]) -> Option<Snippet> {
+    let (first, rest) = command.split_first()?;
+    // 実行ファイルの前の `@`（argv[0] を指定）、`-`、`:`、`+`、`!` のプレフィックス
+    let executable = word_text(first);
+    let prefixes = executable.len() - executable.trim_start_matches(['@', '-', ':', '+', '!']).len();
+    let mut args = rest.iter();
+    if executable[..prefixes].contains('@') {
+        args.next();
+    }
+
+    let dialect = detect::interpreter_dialect(&executable[prefixes..]).filter(|d| d.is_supported())?;
+    // -c の後の最初の引数がスクリプト (-c、-ec、-lc など)。オプション以外の引数の後はスクリプトの引数
+    args.by_ref()
+        .map(|arg| word_text(arg))
+        .take_while(|arg| arg.starts_with('-'))
+        .find(|arg| !arg.starts_with("--") && arg.contains('c'))?;
+    let script = args.next()?;
+
+    let mut snippet = Snippet::new(dialect);
+    let expanded = expand(script);
+    let mut start = 0;
+    while start < expanded.len() {
+        let line = expanded[start].1;
+        let end = expanded[start..].iter().position(|(_, l)| *l != line).map_or(expanded.len(), |n| start + n);
+        let text: String = expanded[start..end].iter().map(|(c, _)| c).collect();
+        snippet.push_line(text.trim_end(), line);
+        start = end;
+    }
+    (!snippet.is_empty()).then_some(snippet)
+}
+
+/// 引用符とバックスラッシュを解釈して単語に分割する
+fn split_words(value: &[(char, usize)]) -> Vec<Chars> {
+    let mut words = Vec::new();
+    let mut word: Chars = Vec::new();
+    let mut in_word = false;
+    let mut quote = None;
+
+    let mut chars = value.iter().copied();
+    while let Some((c, line)) = chars.next() {
+        match (quote, c) {
+            (None, ' ' | '\t') => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (Some(q), _) if c == q => quote = None,
+            (_, '\\') => {
+                word.extend(chars.next());
+                in_word = true;
+            }
+            _ => {
+                word.push((c, line));
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    words
+}
+
+fn word_text(word: &[(char, usize)]) -> String {
+    word.iter().map(|(c, _)| c).collect()
+}
+
+/// `$$` と `%%` を戻し、環境変数 `${NAME}` と指定子 `%i` をプレースホルダーに置き換える
+fn expand(script: &[(char, usize)]) -> Chars {
+    let mut result = Vec::new();
+    let mut idx = 0;
+    while idx < script.len() {
+        let (c, line) = script[idx];
+        let next = script.get(idx + 1).map(|(c, _)| *c);
+        match (c, next) {
+            ('$', Some('$')) | ('%', Some('%')) => {
+                result.push((c, line));
+                idx += 2;
+            }
+            ('$', Some('{')) => {
+                let close = script[idx..].iter().position(|(c, _)| *c == '}').map_or(script.len(), |n| idx + n);
+                result.extend(EXPANSION_PLACEHOLDER.chars().map(|c| (c, line)));
+                idx = close + 1;
+            }
+            ('%', Some(_)) => {
+                result.extend(EXPANSION_PLACEHOLDER.chars().map(|c| (c, line)));
+                idx += 2;
+            }
+            _ => {
+                result.push((c, line));
+                idx += 1;
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scripts(content: &str) -> Vec<(String, Vec<usize>, Dialect)> {
+        SystemdUnit
+            .extract(content)
+            .into_iter()
+            .map(|snippet| (snippet.script, snippet.lines, snippet.dialect))
+            .collect()
+    }
+
+    #[test]
+    fn exec_prefixes_and_continuation_lines() {
+        let content = "[Service]\nExecStartPre=-/bin/sh -c 'echo $$HOME \\\n  && test -d %h'\nExecStart=@/bin/bash my-name -ec \"echo ${FOO} %%d\"\nExecStop=/usr/bin/kill $MAINPID\n";
+        assert_eq!(
+            scripts(content),
+            [
+                (format!("echo $HOME\n  && test -d {}\n", EXPANSION_PLACEHOLDER), vec![2, 3], Dialect::Sh),
+                (format!("echo {} %d\n", EXPANSION_PLACEHOLDER), vec![4], Dialect::Bash),
+            ]
+        );
+    }
+
+    #[test]
+    fn script_follows_the_first_c_option() {
+        let content = "ExecReload=/bin/bash --norc -x -c 'echo a' ; /bin/sh -c 'echo b'\nExecStart=/bin/bash /opt/run.sh -c x\nExecStart=/bin/bash -c\n";
+        assert_eq!(
+            scripts(content),
+            [
+                ("echo a\n".to_string(), vec![1], Dialect::Bash),
+                ("echo b\n".to_string(), vec![1], Dialect::Sh),
+            ]
+        );
+    }
+}
@@ -0,0 +1,426 @@
+// src/extract/yaml.rs
+// CI 設定ファイル用の最小限の YAML パーサー
+//
+// ブロック形式のマッピング・シーケンス、ブロックスカラー (| と >)、1 行のスカラーのみを扱い、
+// スカラーの各行の行番号を保持する。フロー形式のシーケンス ([...]) は要素ごとのスカラーとして読み、
+// フロー形式のマッピング ({...}) はスカラーとして扱う。アンカー (&name) は読み飛ばし、
+// エイリアス (*name) は解決しない。
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref KEY: Regex =
+        Regex::new(r##"^("(?:[^"\\]|\\.)*"|'(?:[^']|'')*'|[^\s'"#\-?:,\[\]{}][^'"#]*?|-[^\s'"#][^'"#]*?)\s*:(?:\s+(.*))?$"##)
+            .unwrap();
+}
+
+pub enum Node {
+    Null,
+    // 各行の (行番号, 内容)
+    Scalar(Vec<(usize, String)>),
+    Map(Vec<(String, Node)>),
+    Seq(Vec<Node>),
+}
+
+impl Node {
+    pub fn get(&self, key: &str) -> &Node {
+        match self {
+            Node::Map(entries) => entries.iter().find(|(k, _)| k == key).map_or(&Node::Null, |(_, v)| v),
+            _ => &Node::Null,
+        }
+    }
+
+    pub fn entries(&self) -> &[(String, Node)] {
+        match self {
+            Node::Map(entries) => entries,
+            _ => &[],
+        }
+    }
+
+    pub fn items(&self) -> &[Node] {
+        match self {
+            Node::Seq(items) => items,
+            _ => &[],
+        }
+    }
+
+    /// 1 行のスカラーの値
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Node::Scalar(lines) => lines.first().map(|(_, text)| text.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// YAML を解析する（複数ドキュメントの場合は最初のドキュメントのみ）
+pub fn parse(content: &str) -> Node {
+    let mut parser = Parser {
+        lines: content.lines().map(|l| l.to_string()).collect(),
+        idx: 0,
+        started: false,
+    };
+    match parser.next_significant() {
+        Some((_, indent)) => parser.parse_node(indent),
+        None => Node::Null,
+    }
+}
+
+struct Parser {
+    lines: Vec<String>,
+    idx: usize,
+    // 最初のドキュメントの内容を読み始めたか（先頭のコメントの後の `---` は区切りではない）
+    started: bool,
+}
+
+impl Parser {
+    /// 空行とコメント行を読み飛ばし、次の行の (位置, インデント) を返す
+    fn next_significant(&mut self) -> Option<(usize, usize)> {
+        while self.idx < self.lines.len() {
+            let line = &self.lines[self.idx];
+            let trimmed = line.trim_start();
+            if trimmed == "---" && self.started {
+                return None;
+            }
+            if trimmed.is_empty() || trimmed.starts_with('#') || trimmed == "---" {
+                self.idx += 1;
+                continue;
+            }
+            self.started = true;
+            return Some((self.idx, line.len() - trimmed.len()));
+        }
+        None
+    }
+
+    fn parse_node(&mut self, indent: usize) -> Node {
+        let Some((idx, _)) = self.next_significant() else { return Node::Null };
+        let content = self.lines[idx].trim_start();
+        if content == "-" || content.starts_with("- ") {
+            self.parse_seq(indent)
+        } else if KEY.is_match(content) {
+            self.parse_map(indent)
+        } else {
+            // マッピングでもシーケンスでもない行はスカラー
+            let value = content.to_string();
+            self.idx += 1;
+            self.parse_value(&value, idx, indent.saturating_sub(1), false)
+        }
+    }
+
+    fn parse_map(&mut self, indent: usize) -> Node {
+        let mut entries = Vec::new();
+        while let Some((idx, line_indent)) = self.next_significant() {
+            if line_indent != indent {
+                break;
+            }
+            let content = self.lines[idx][indent..].to_string();
+            let Some(caps) = KEY.captures(&content) else { break };
+            let key = unquote(&caps[1]);
+            let value = caps.get(2).map_or("", |m| m.as_str()).to_string();
+            self.idx += 1;
+            entries.push((key, self.parse_value(&value, idx, indent, true)));
+        }
+        Node::Map(entries)
+    }
+
+    fn parse_seq(&mut self, indent: usize) -> Node {
+        let mut items = Vec::new();
+        while let Some((idx, line_indent)) = self.next_significant() {
+            let content = self.lines[idx][line_indent..].to_string();
+            if line_indent != indent || !(content == "-" || content.starts_with("- ")) {
+                break;
+            }
+
+            let rest = content[1..].trim_start();
+            let column = self.lines[idx].len() - rest.len();
+            if KEY.is_match(rest) {
+                // `- key: value` は "-" を空白に置き換えてマッピングとして読む
+                self.lines[idx].replace_range(indent..indent + 1, " ");
+                items.push(self.parse_map(column));
+            } else if rest.starts_with("- ") {
+                self.lines[idx].replace_range(indent..indent + 1, " ");
+                items.push(self.parse_seq(column));
+            } else {
+                let rest = rest.to_string();
+                self.idx += 1;
+                items.push(self.parse_value(&rest, idx, indent, false));
+            }
+        }
+        Node::Seq(items)
+    }
+
+    /// キーまたはシーケンス要素の値を読む（`parent` は親のインデント）
+    fn parse_value(&mut self, value: &str, idx: usize, parent: usize, is_key: bool) -> Node {
+        let value = strip_anchor(strip_comment(value));
+        if value.starts_with('*') {
+            return Node::Null;
+        }
+        if value.is_empty() {
+            return match self.next_significant() {
+                Some((_, indent)) if indent > parent => self.parse_node(indent),
+                // キーと同じインデントのシーケンスも許容される
+                Some((next, indent))
+                    if is_key && indent == parent && self.lines[next][indent..].starts_with('-') =>
+                {
+                    self.parse_seq(indent)
+                }
+                _ => Node::Null,
+            };
+        }
+
+        if value.starts_with('|') || value.starts_with('>') {
+            return Node::Scalar(self.block_scalar(parent));
+        }
+        if value.starts_with('[') {
+            return self.flow_seq(value, idx);
+        }
+
+        let mut lines = vec![(idx + 1, unquote(value))];
+        // 複数行にわたるプレーンスカラーは空白で連結する
+        if !value.starts_with('"') && !value.starts_with('\'') {
+            while let Some((next, indent)) = self.next_significant() {
+                if indent <= parent || KEY.is_match(self.lines[next].trim_start()) {
+                    break;
+                }
+                let text = self.lines[next].trim().to_string();
+                lines[0].1.push(' ');
+                lines[0].1.push_str(&text);
+                self.idx += 1;
+            }
+        }
+        Node::Scalar(lines)
+    }
+
+    /// 親より深くインデントされた行をブロックスカラーの本文として読む
+    fn block_scalar(&mut self, parent: usize) -> Vec<(usize, String)> {
+        let mut lines = Vec::new();
+        let mut content_indent = None;
+        while self.idx < self.lines.len() {
+            let line = &self.lines[self.idx];
+            let trimmed = line.trim_start();
+            let indent = line.len() - trimmed.len();
+            if !trimmed.is_empty() && indent <= parent {
+                break;
+            }
+            let text = if trimmed.is_empty() {
+                String::new()
+            } else {
+                // 本文のインデントは最初の空行以外の行で決まる
+                let content_indent = *content_indent.get_or_insert(indent);
+                line[content_indent.min(indent)..].to_string()
+            };
+            lines.push((self.idx + 1, text));
+            self.idx += 1;
+        }
+
+        // 末尾の空行は本文に含めない
+        while lines.last().is_some_and(|(_, text)| text.is_empty()) {
+            lines.pop();
+        }
+        lines
+    }
+
+    /// フロー形式のシーケンスを `]` まで読む（入れ子の括弧は要素の文字列に含める）
+    ///
+    /// 各要素の行番号は要素が始まる行になる。
+    fn flow_seq(&mut self, value: &str, idx: usize) -> Node {
+        let mut items = Vec::new();
+        let mut item = String::new();
+        let mut item_line = idx + 1;
+        let mut quote = None;
+        let mut depth = 0;
+        let mut text = value.to_string();
+        let mut line = idx + 1;
+
+        loop {
+            let mut chars = text.chars().peekable();
+            let mut previous = ' ';
+            while let Some(c) = chars.next() {
+                if quote.is_none() && depth == 1 && item.trim().is_empty() && !c.is_whitespace() {
+                    item_line = line;
+                }
+                match (quote, c) {
+                    (Some('\''), '\'') if chars.peek() == Some(&'\'') => {
+                        item.push(c);
+                        item.push(chars.next().unwrap_or(c));
+                    }
+                    (Some('"'), '\\') => {
+                        item.push(c);
+                        item.extend(chars.next());
+                    }
+                    (Some(q), _) if c == q => {
+                        quote = None;
+                        item.push(c);
+                    }
+                    (Some(_), _) => item.push(c),
+                    (None, '"' | '\'') if item.trim().is_empty() => {
+                        quote = Some(c);
+                        item.push(c);
+                    }
+                    (None, '#') if previous.is_whitespace() => break,
+                    (None, '[' | '{') => {
+                        depth += 1;
+                        if depth > 1 {
+                            item.push(c);
+                        }
+                    }
+                    (None, ']' | '}') if depth > 1 => {
+                        depth -= 1;
+                        item.push(c);
+                    }
+                    (None, ']') => {
+                        push_flow_item(&mut items, &item, item_line);
+                        return Node::Seq(items);
+                    }
+                    (None, ',') if depth == 1 => {
+                        push_flow_item(&mut items, &item, item_line);
+                        item.clear();
+                    }
+                    (None, _) => item.push(c),
+                }
+                previous = c;
+            }
+
+            // 閉じられていないシーケンスは次の行に続く
+            if self.idx >= self.lines.len() {
+                push_flow_item(&mut items, &item, item_line);
+                return Node::Seq(items);
+            }
+            item.push(' ');
+            text = self.lines[self.idx].trim().to_string();
+            line = self.idx + 1;
+            self.idx += 1;
+        }
+    }
+}
+
+fn push_flow_item(items: &mut Vec<Node>, item: &str, line: usize) {
+    let item = strip_anchor(item.trim());
+    if item.starts_with('*') {
+        items.push(Node::Null);
+    } else if !item.is_empty() {
+        items.push(Node::Scalar(vec![(line, unquote(item))]));
+    }
+}
+
+/// 値の先頭のアンカー (`&name`) を取り除く
+fn strip_anchor(value: &str) -> &str {
+    match value.strip_prefix('&') {
+        Some(rest) => rest.find(char::is_whitespace).map_or("", |pos| rest[pos..].trim_start()),
+        None => value,
+    }
+}
+
+fn strip_comment(value: &str) -> &str {
+    let value = value.trim();
+    if value.starts_with('"') || value.starts_with('\'') {
+        return value;
+    }
+    match value.find(" #") {
+        Some(pos) => value[..pos].trim_end(),
+        None if value.starts_with('#') => "",
+        None => value,
+    }
+}
+
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        let mut result = String::new();
+        let mut chars = value[1..value.len() - 1].chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                result.push(c);
+                continue;
+            }
+            match chars.next() {
+                // 行番号を保つため改行には展開しない
+                Some('n') => result.push_str("\\n"),
+                Some('t') => result.push('\t'),
+                Some(other) => result.push(other),
+                None => {}
+            }
+        }
+        result
+    } else if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+        value[1..value.len() - 1].replace("''", "'")
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scalar(node: &Node) -> Vec<(usize, &str)> {
+        match node {
+            Node::Scalar(lines) => lines.iter().map(|(line, text)| (*line, text.as_str())).collect(),
+            _ => panic!("not a scalar"),
+        }
+    }
+
+    #[test]
+    fn literal_block_scalar_keeps_source_line_numbers() {
+        let root = parse("job:\n  script: |\n    echo a\n\n      echo b\n\n  stage: test\n");
+        assert_eq!(scalar(root.get("job").get("script")), [(3, "echo a"), (4, ""), (5, "  echo b")]);
+        assert_eq!(root.get("job").get("stage").as_str(), Some("test"));
+    }
+
+    #[test]
+    fn folded_and_chomped_block_scalars_are_read_line_by_line() {
+        let root = parse("a: >\n  echo a\n  echo b\nb: |-\n  echo c\n\nc: x\n");
+        assert_eq!(scalar(root.get("a")), [(2, "echo a"), (3, "echo b")]);
+        assert_eq!(scalar(root.get("b")), [(5, "echo c")]);
+        assert_eq!(root.get("c").as_str(), Some("x"));
+    }
+
+    #[test]
+    fn block_scalar_in_sequence_item() {
+        let root = parse("steps:\n  - name: build\n    run: |\n      make\n  - run: make test\n");
+        let steps = root.get("steps").items();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(scalar(steps[0].get("run")), [(4, "make")]);
+        assert_eq!(scalar(steps[1].get("run")), [(5, "make test")]);
+    }
+
+    #[test]
+    fn flow_sequence_of_scripts() {
+        let root = parse("job:\n  script: [\"echo a, b\", 'it''s', make # build\n    , make test]\n  stage: test\n");
+        let items: Vec<_> = root.get("job").get("script").items().iter().map(scalar).collect();
+        assert_eq!(items, [vec![(2, "echo a, b")], vec![(2, "it's")], vec![(2, "make")], vec![(3, "make test")]]);
+        assert_eq!(root.get("job").get("stage").as_str(), Some("test"));
+    }
+
+    #[test]
+    fn nested_brackets_stay_inside_flow_item() {
+        let root = parse("script: [test [ -f a ], {x: y}]\n");
+        let items: Vec<_> = root.get("script").items().iter().map(scalar).collect();
+        assert_eq!(items, [vec![(1, "test [ -f a ]")], vec![(1, "{x: y}")]]);
+    }
+
+    #[test]
+    fn anchors_are_skipped_and_aliases_are_ignored() {
+        let root = parse(".base: &base\n  script: &commands\n    - make\njob:\n  <<: *base\n  script: *commands\n");
+        let items: Vec<_> = root.get(".base").get("script").items().iter().map(scalar).collect();
+        assert_eq!(items, [vec![(3, "make")]]);
+        assert!(matches!(root.get("job").get("<<"), Node::Null));
+        assert!(matches!(root.get("job").get("script"), Node::Null));
+    }
+
+    #[test]
+    fn comments_quoted_keys_and_later_documents_are_ignored() {
+        let root = parse("# comment\n---\n\"on\": push # trigger\nrun: 'echo # not a comment'\n---\nother: 1\n");
+        assert_eq!(root.get("on").as_str(), Some("push"));
+        assert_eq!(scalar(root.get("run")), [(4, "echo # not a comment")]);
+        assert!(matches!(root.get("other"), Node::Null));
+    }
+
+    #[test]
+    fn sequence_at_key_indentation_and_plain_multiline_scalar() {
+        let root = parse("script:\n- echo a\n- echo b\n  --verbose\nafter: x\n");
+        let items: Vec<_> = root.get("script").items().iter().map(scalar).collect();
+        assert_eq!(items, [vec![(2, "echo a")], vec![(3, "echo b --verbose")]]);
+        assert_eq!(root.get("after").as_str(), Some("x"));
+    }
+}
@@ -0,0 +1,138 @@
+// src/git.rs
+use crate::report::Report;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// 指定リビジョンからの変更内容
+pub struct Changes {
+    // 変更された行の範囲 (開始行, 行数)。None は新規ファイルで全行が変更扱い
+    files: BTreeMap<PathBuf, Option<Vec<(usize, usize)>>>,
+}
+
+impl Changes {
+    /// `rev` から作業ツリーまでに変更・追加されたファイルと行を取得する
+    ///
+    /// パスはカレントディレクトリからの相対パスで、カレントディレクトリ配下のみが対象。
+    pub fn since(rev: &str) -> Result<Self, String> {
+        let mut files = BTreeMap::new();
+
+        let names = git(&["diff", "--name-only", "-z", "--diff-filter=ACMR", "--relative", rev, "--"])?;
+        for name in names.split('\0').filter(|n| !n.is_empty()) {
+            files.insert(PathBuf::from(name), Some(Vec::new()));
+        }
+
+        let diff = git(&[
+            "diff", "-U0", "--no-color", "--no-ext-diff", "--no-prefix", "--diff-filter=ACMR", "--relative", rev,
+            "--",
+        ])?;
+        let mut current: Option<PathBuf> = None;
+        for line in diff.lines() {
+            if let Some(path) = line.strip_prefix("+++ ") {
+                current = (path != "/dev/null").then(|| PathBuf::from(path.trim_matches('"')));
+            } else if line.starts_with("@@ ")
+                && let Some(path) = &current
+                && let Some(range) = parse_hunk_header(line)
+                && let Some(Some(ranges)) = files.get_mut(path)
+            {
+                ranges.push(range);
+            }
+        }
+
+        // 未追跡のファイルは全行が変更されたものとして扱う
+        let untracked = git(&["ls-files", "--others", "--exclude-standard", "-z"])?;
+        for name in untracked.split('\0').filter(|n| !n.is_empty()) {
+            files.insert(PathBuf::from(name), None);
+        }
+
+        Ok(Changes { files })
+    }
+
+    pub fn files(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(|p| p.as_path())
+    }
+
+    pub fn contains_line(&self, path: &Path, line: usize) -> bool {
+        match self.files.get(path) {
+            Some(Some(ranges)) => ranges.iter().any(|&(start, count)| line >= start && line < start + count),
+            Some(None) => true,
+            None => false,
+        }
+    }
+
+    /// 変更された行以外の問題をレポートから取り除く
+    pub fn retain_changed_lines(&self, path: &Path, report: &mut Report) {
+        report.retain(|issue| self.contains_line(path, issue.line));
+    }
+}
+
+/// インデックスに追加・変更としてステージされたファイル（カレントディレクトリからの相対パス）
+pub fn staged_files() -> Result<Vec<PathBuf>, String> {
+    let names = git(&["diff", "--cached", "--name-only", "-z", "--diff-filter=ACMR", "--relative", "--"])?;
+    Ok(names.split('\0').filter(|n| !n.is_empty()).map(PathBuf::from).collect())
+}
+
+/// 作業ツリーではなくインデックス上のファイル内容を取得する
+pub fn staged_content(path: &Path) -> Result<String, String> {
+    git(&["show", &format!(":./{}", path.to_string_lossy())])
+}
+
+/// フックを配置するディレクトリ (core.hooksPath の設定も反映される)
+pub fn hooks_dir() -> Result<PathBuf, String> {
+    let dir = git(&["rev-parse", "--git-path", "hooks"])?;
+    Ok(PathBuf::from(dir.trim()))
+}
+
+/// `@@ -a,b +c,d @@` から追加側の (開始行, 行数) を取り出す
+fn parse_hunk_header(line: &str) -> Option<(usize, usize)> {
+    let added = line.split_whitespace().find(|w| w.starts_with('+'))?;
+    let mut parts = added[1..].split(',');
+    let start = parts.next()?.parse().ok()?;
+    let count = match parts.next() {
+        Some(count) => count.parse().ok()?,
+        None => 1,
+    };
+    Some((start, count))
+}
+
+fn git(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| format!("git: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hunk_header_with_and_without_counts() {
+        assert_eq!(parse_hunk_header("@@ -10,2 +12,3 @@ fn main() {"), Some((12, 3)));
+        assert_eq!(parse_hunk_header("@@ -10 +12 @@"), Some((12, 1)));
+        assert_eq!(parse_hunk_header("@@ -0,0 +1,5 @@"), Some((1, 5)));
+        // 削除のみの hunk は追加側の行数が 0
+        assert_eq!(parse_hunk_header("@@ -7,2 +6,0 @@ echo +x"), Some((6, 0)));
+        assert_eq!(parse_hunk_header("@@ -1 @@"), None);
+        assert_eq!(parse_hunk_header("@@ -1 +a,b @@"), None);
+    }
+
+    #[test]
+    fn contains_line_uses_hunk_ranges() {
+        let changes = Changes {
+            files: BTreeMap::from([
+                (PathBuf::from("a.sh"), Some(vec![(3, 2), (6, 0)])),
+                (PathBuf::from("new.sh"), None),
+            ]),
+        };
+        let changed: Vec<usize> = (1..=8).filter(|line| changes.contains_line(Path::new("a.sh"), *line)).collect();
+        assert_eq!(changed, [3, 4]);
+        assert!(changes.contains_line(Path::new("new.sh"), 100));
+        assert!(!changes.contains_line(Path::new("other.sh"), 1));
+    }
+}
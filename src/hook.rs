@@ -0,0 +1,58 @@
+// src/hook.rs
+use crate::git;
+use std::fs;
+use std::path::PathBuf;
+
+const HOOK_MARKER: &str = "# Installed by shellchecker hook install";
+
+pub enum HookError {
+    // 既存のフックがあり、上書きが指定されていない
+    Exists(PathBuf),
+    Git(String),
+    Io(PathBuf, std::io::Error),
+}
+
+/// ステージされたシェルファイルをチェックする pre-commit フックを作成する
+pub fn install(force: bool, extra_args: &[String]) -> Result<PathBuf, HookError> {
+    let dir = git::hooks_dir().map_err(HookError::Git)?;
+    let path = dir.join("pre-commit");
+
+    // 以前にインストールしたフックは確認なしで更新する
+    if path.exists() && !force {
+        let existing = fs::read_to_string(&path).unwrap_or_default();
+        if !existing.contains(HOOK_MARKER) {
+            return Err(HookError::Exists(path));
+        }
+    }
+
+    let exe = std::env::current_exe().map_err(|e| HookError::Io(path.clone(), e))?;
+    let mut command = vec![shell_quote(&exe.to_string_lossy()), "--staged".to_string()];
+    command.extend(extra_args.iter().map(|arg| shell_quote(arg)));
+
+    let script = format!("#!/bin/sh\n{}\nexec {}\n", HOOK_MARKER, command.join(" "));
+
+    fs::create_dir_all(&dir).map_err(|e| HookError::Io(dir.clone(), e))?;
+    fs::write(&path, script).map_err(|e| HookError::Io(path.clone(), e))?;
+    make_executable(&path).map_err(|e| HookError::Io(path.clone(), e))?;
+
+    Ok(path)
+}
+
+fn shell_quote(arg: &str) -> String {
+    if arg.chars().all(|c| c.is_ascii_alphanumeric() || "-_./=:,".contains(c)) {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+#[cfg(unix)]
+fn make_executable(path: &std::path::Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &std::path::Path) -> std::io::Result<()> {
+    Ok(())
+}
@@ -1,225 +1,587 @@
 // src/i18n.rs
-#[derive(Debug, Clone, PartialEq)]
-pub enum Language {
-    English,
-    Japanese,
+// メッセージの翻訳（Fluent 形式のカタログ）
+use crate::rules;
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use lazy_static::lazy_static;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use unic_langid::LanguageIdentifier;
+
+// ビルド時に埋め込むカタログ（locales/<言語コード>.ftl）
+const BUILTIN_CATALOGS: &[(&str, &str)] = &[
+    ("en", include_str!("../locales/en.ftl")),
+    ("ja", include_str!("../locales/ja.ftl")),
+    ("zh-Hans", include_str!("../locales/zh-Hans.ftl")),
+    ("ko", include_str!("../locales/ko.ftl")),
+    ("de", include_str!("../locales/de.ftl")),
+    ("es", include_str!("../locales/es.ftl")),
+];
+
+// カタログにないメッセージは英語で表示する
+const FALLBACK_LANGUAGE: &str = "en";
+
+// 出力言語を判定するロケールの環境変数（先頭が優先）
+const LOCALE_ENV: &[&str] = &["LC_ALL", "LC_MESSAGES", "LANG"];
+
+// 実行時に読み込むカタログのディレクトリを指定する環境変数
+pub const LOCALE_DIR_ENV: &str = "SHELLCHECKER_LOCALE_DIR";
+
+type Bundle = FluentBundle<FluentResource>;
+
+lazy_static! {
+    static ref CATALOGS: RwLock<BTreeMap<String, Bundle>> = RwLock::new(builtin_catalogs());
 }
 
-pub struct Message {
-    pub en: &'static str,
-    pub ja: &'static str,
+fn builtin_catalogs() -> BTreeMap<String, Bundle> {
+    let mut catalogs = BTreeMap::new();
+    for (code, source) in BUILTIN_CATALOGS {
+        let resource = FluentResource::try_new(source.to_string()).expect("built-in catalog must be valid");
+        let mut bundle = new_bundle(code).expect("built-in language code must be valid");
+        bundle.add_resource_overriding(resource);
+        catalogs.insert(code.to_string(), bundle);
+    }
+    catalogs
+}
+
+fn new_bundle(code: &str) -> Option<Bundle> {
+    let locale: LanguageIdentifier = code.parse().ok()?;
+    let mut bundle = FluentBundle::new_concurrent(vec![locale]);
+    // 端末に出力するため Unicode の方向制御文字は挿入しない
+    bundle.set_use_isolating(false);
+    Some(bundle)
+}
+
+/// カタログを追加する（同じ言語のカタログがあれば、同じ ID のメッセージを上書きする）
+///
+/// 構文エラーのあるエントリは読み飛ばし、エラーの内容を「行番号: 内容」の形式で返す。
+pub fn add_catalog(code: &str, source: &str) -> Result<(), Vec<String>> {
+    let (resource, errors) = match FluentResource::try_new(source.to_string()) {
+        Ok(resource) => (resource, Vec::new()),
+        Err((resource, errors)) => {
+            let errors = errors
+                .iter()
+                .map(|e| format!("{}: {}", source[..e.pos.start].matches('\n').count() + 1, e))
+                .collect();
+            (resource, errors)
+        }
+    };
+
+    let mut catalogs = CATALOGS.write().unwrap_or_else(|e| e.into_inner());
+    if !catalogs.contains_key(code) {
+        let Some(bundle) = new_bundle(code) else {
+            return Err(vec![format!("invalid language code '{}'", code)]);
+        };
+        catalogs.insert(code.to_string(), bundle);
+    }
+    if let Some(bundle) = catalogs.get_mut(code) {
+        bundle.add_resource_overriding(resource);
+    }
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// ディレクトリ内の `<言語コード>.ftl` をすべて読み込み、読み込めなかったファイルとエラーを返す
+pub fn load_catalogs(dir: &Path) -> Vec<(PathBuf, String)> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => return vec![(dir.to_path_buf(), e.to_string())],
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ftl"))
+        .collect();
+    paths.sort();
+
+    let mut failures = Vec::new();
+    for path in paths {
+        let Some(code) = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()) else { continue };
+        let result = fs::read_to_string(&path)
+            .map_err(|e| vec![e.to_string()])
+            .and_then(|source| add_catalog(&code, &source));
+        if let Err(errors) = result {
+            failures.extend(errors.into_iter().map(|e| (path.clone(), e)));
+        }
+    }
+    failures
+}
+
+/// 出力言語（カタログの言語コード）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Language {
+    code: Cow<'static, str>,
+}
+
+impl Language {
+    pub const ENGLISH: Language = Language {
+        code: Cow::Borrowed(FALLBACK_LANGUAGE),
+    };
+
+    /// `ja` / `zh-CN` / `de_DE.UTF-8` などの言語コードやロケール名から言語を取得する（カタログがない場合は None）
+    pub fn from_code(code: &str) -> Option<Language> {
+        let catalogs = CATALOGS.read().unwrap_or_else(|e| e.into_inner());
+        candidates(code)
+            .into_iter()
+            .find(|candidate| catalogs.contains_key(candidate))
+            .map(|code| Language { code: Cow::Owned(code) })
+    }
+
+    /// 環境変数 LC_ALL、LC_MESSAGES、LANG のロケールから言語を取得する
+    pub fn from_env() -> Option<Language> {
+        // 最初に設定されている変数のみを使用する（C / POSIX などカタログのないロケールは None）
+        let locale = LOCALE_ENV
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())?;
+        Language::from_code(&locale)
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// カタログのある言語コードの一覧
+    pub fn available() -> Vec<String> {
+        let catalogs = CATALOGS.read().unwrap_or_else(|e| e.into_inner());
+        catalogs.keys().cloned().collect()
+    }
 }
 
+impl Default for Language {
+    fn default() -> Self {
+        Language::ENGLISH
+    }
+}
+
+/// ロケール名から、カタログを探す言語コードの候補を優先順に返す
+fn candidates(locale: &str) -> Vec<String> {
+    // 文字コード (.UTF-8) と修飾子 (@euro) は言語の判定に使用しない
+    let tag = locale.split(['.', '@']).next().unwrap_or_default().replace('_', "-");
+    let Ok(id) = tag.parse::<LanguageIdentifier>() else { return vec![tag] };
+
+    let language = id.language.as_str();
+    let mut candidates = vec![id.to_string()];
+    // 中国語のカタログは字体ごとに分かれているため、地域から字体を判断する
+    let script = id.script.map(|script| script.as_str().to_string()).or_else(|| {
+        (language == "zh").then(|| match id.region.as_ref().map(|region| region.as_str()) {
+            Some("TW" | "HK" | "MO") => "Hant".to_string(),
+            _ => "Hans".to_string(),
+        })
+    });
+    if let Some(script) = script {
+        candidates.push(format!("{}-{}", language, script));
+    }
+    candidates.push(language.to_string());
+    candidates
+}
+
+/// カタログのメッセージ ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Message(&'static str);
+
 impl Message {
-    pub fn get(&self, lang: &Language) -> &str {
-        match lang {
-            Language::English => self.en,
-            Language::Japanese => self.ja,
+    pub fn get(&self, lang: &Language) -> String {
+        self.format(lang, &[])
+    }
+
+    /// 引数を埋め込んだメッセージ（言語のカタログになければ英語、英語にもなければ ID を返す）
+    pub fn format(&self, lang: &Language, args: &[(&str, FluentValue)]) -> String {
+        let args: Option<FluentArgs> =
+            (!args.is_empty()).then(|| args.iter().map(|(name, value)| (*name, value.clone())).collect());
+
+        let catalogs = CATALOGS.read().unwrap_or_else(|e| e.into_inner());
+        for code in [lang.code(), FALLBACK_LANGUAGE] {
+            let Some(bundle) = catalogs.get(code) else { continue };
+            let Some(pattern) = bundle.get_message(self.0).and_then(|message| message.value()) else { continue };
+            let mut errors = Vec::new();
+            return bundle.format_pattern(pattern, args.as_ref(), &mut errors).into_owned();
         }
+        self.0.to_string()
     }
 }
 
 // Severity levels
-pub const SEVERITY_ERROR: Message = Message {
-    en: "ERROR",
-    ja: "エラー",
-};
-
-pub const SEVERITY_WARNING: Message = Message {
-    en: "WARNING",
-    ja: "警告",
-};
-
-pub const SEVERITY_INFO: Message = Message {
-    en: "INFO",
-    ja: "情報",
-};
-
-// Summary messages
-pub const NO_ISSUES: Message = Message {
-    en: "✓ No issues found",
-    ja: "✓ 問題は見つかりませんでした",
-};
-
-pub const SUMMARY: Message = Message {
-    en: "Summary",
-    ja: "サマリ",
-};
-
-pub const ERRORS: Message = Message {
-    en: "error(s)",
-    ja: "個のエラー",
-};
-
-pub const WARNINGS: Message = Message {
-    en: "warning(s)",
-    ja: "個の警告",
-};
+pub const SEVERITY_ERROR: Message = Message("severity-error");
+pub const SEVERITY_WARNING: Message = Message("severity-warning");
+pub const SEVERITY_INFO: Message = Message("severity-info");
+
+// Summary
+pub const NO_ISSUES: Message = Message("no-issues");
+pub const SUMMARY: Message = Message("summary");
 
 // Categories
-pub const CAT_SYNTAX: Message = Message {
-    en: "Syntax",
-    ja: "構文",
-};
-
-pub const CAT_BEST_PRACTICE: Message = Message {
-    en: "Best Practice",
-    ja: "ベストプラクティス",
-};
-
-pub const CAT_SECURITY: Message = Message {
-    en: "Security",
-    ja: "セキュリティ",
-};
-
-pub const CAT_STYLE: Message = Message {
-    en: "Style",
-    ja: "スタイル",
-};
-
-// Error messages - Syntax
-pub const MSG_MISSING_SHEBANG: Message = Message {
-    en: "Missing shebang line (#!/bin/bash or #!/bin/sh)",
-    ja: "シバン行がありません (#!/bin/bash または #!/bin/sh)",
-};
-
-pub const MSG_INVALID_SHEBANG: Message = Message {
-    en: "Shebang does not specify bash or sh",
-    ja: "シバン行でbashまたはshが指定されていません",
-};
-
-pub const MSG_UNMATCHED_BRACKET: Message = Message {
-    en: "Unmatched closing bracket ']'",
-    ja: "対応しない閉じ括弧 ']'",
-};
-
-pub const MSG_UNCLOSED_BRACKET: Message = Message {
-    en: "Unclosed bracket '['",
-    ja: "閉じられていない括弧 '['",
-};
-
-pub const MSG_UNCLOSED_BRACE: Message = Message {
-    en: "Unclosed brace '{'",
-    ja: "閉じられていない波括弧 '{'",
-};
-
-pub const MSG_UNCLOSED_PAREN: Message = Message {
-    en: "Unclosed parenthesis '('",
-    ja: "閉じられていない丸括弧 '('",
-};
-
-pub const MSG_UNCLOSED_SINGLE_QUOTE: Message = Message {
-    en: "Unclosed single quote",
-    ja: "閉じられていないシングルクォート",
-};
-
-pub const MSG_UNCLOSED_DOUBLE_QUOTE: Message = Message {
-    en: "Unclosed double quote",
-    ja: "閉じられていないダブルクォート",
-};
-
-pub const MSG_UNCLOSED_VAR_EXPANSION: Message = Message {
-    en: "Unclosed variable expansion ${...}",
-    ja: "閉じられていない変数展開 ${...}",
-};
-
-pub const MSG_UNCLOSED_CMD_SUBST: Message = Message {
-    en: "Unclosed command substitution $(...)",
-    ja: "閉じられていないコマンド置換 $(...)",
-};
-
-// Best Practice messages
-pub const MSG_USE_SET_E: Message = Message {
-    en: "Consider using 'set -e' to exit on errors",
-    ja: "'set -e' の使用を検討してください（エラー時に終了）",
-};
-
-pub const MSG_USE_SET_U: Message = Message {
-    en: "Consider using 'set -u' to treat unset variables as errors",
-    ja: "'set -u' の使用を検討してください（未定義変数をエラーとして扱う）",
-};
-
-pub const MSG_USE_SET_PIPEFAIL: Message = Message {
-    en: "Consider using 'set -o pipefail' to catch errors in pipelines",
-    ja: "'set -o pipefail' の使用を検討してください（パイプライン内のエラーを検出）",
-};
-
-pub const MSG_UNQUOTED_VARIABLE: Message = Message {
-    en: "Unquoted variable usage - consider using \"$variable\" to prevent word splitting",
-    ja: "クォートされていない変数 - 単語分割を防ぐため \"$variable\" の使用を検討してください",
-};
-
-pub const MSG_CD_WITHOUT_CHECK: Message = Message {
-    en: "cd command without error checking - consider using 'cd dir || exit 1'",
-    ja: "cdコマンドにエラーチェックがありません - 'cd dir || exit 1' の使用を検討してください",
-};
-
-pub const MSG_USE_DOLLAR_PAREN: Message = Message {
-    en: "Use $(...) instead of backticks for command substitution",
-    ja: "コマンド置換にはバッククォートではなく $(...) を使用してください",
-};
-
-// Security messages
-pub const MSG_EVAL_DANGEROUS: Message = Message {
-    en: "Usage of 'eval' is dangerous - avoid dynamic code execution",
-    ja: "'eval' の使用は危険です - 動的なコード実行を避けてください",
-};
-
-pub const MSG_CURL_PIPE_SH: Message = Message {
-    en: "Piping curl/wget directly to shell is dangerous - download and inspect first",
-    ja: "curl/wgetを直接シェルにパイプするのは危険です - まずダウンロードして検査してください",
-};
-
-pub const MSG_DANGEROUS_RM: Message = Message {
-    en: "Dangerous rm -rf usage with variable or root path - add proper validation",
-    ja: "変数またはルートパスでの危険な rm -rf の使用 - 適切な検証を追加してください",
-};
-
-pub const MSG_USER_INPUT_IN_CMD: Message = Message {
-    en: "User input used in command execution - validate and sanitize input",
-    ja: "ユーザー入力がコマンド実行で使用されています - 入力の検証とサニタイズを行ってください",
-};
-
-// Style messages
-pub const MSG_USE_SPACES: Message = Message {
-    en: "Use spaces instead of tabs for indentation",
-    ja: "インデントにはタブではなくスペースを使用してください",
-};
-
-pub const MSG_INCONSISTENT_INDENT: Message = Message {
-    en: "Inconsistent indentation - use 2 or 4 spaces",
-    ja: "インデントが不統一です - 2または4スペースを使用してください",
-};
-
-pub fn msg_line_too_long(length: usize, max: usize, lang: &Language) -> String {
-    match lang {
-        Language::English => format!("Line too long ({} > {} characters)", length, max),
-        Language::Japanese => format!("行が長すぎます ({} > {} 文字)", length, max),
-    }
+pub const CAT_SYNTAX: Message = Message("cat-syntax");
+pub const CAT_BEST_PRACTICE: Message = Message("cat-best-practice");
+pub const CAT_SECURITY: Message = Message("cat-security");
+pub const CAT_STYLE: Message = Message("cat-style");
+
+// Syntax
+pub const MSG_MISSING_SHEBANG: Message = Message("msg-missing-shebang");
+pub const MSG_INVALID_SHEBANG: Message = Message("msg-invalid-shebang");
+pub const MSG_UNMATCHED_BRACKET: Message = Message("msg-unmatched-bracket");
+pub const MSG_UNCLOSED_BRACKET: Message = Message("msg-unclosed-bracket");
+pub const MSG_UNCLOSED_BRACE: Message = Message("msg-unclosed-brace");
+pub const MSG_UNCLOSED_PAREN: Message = Message("msg-unclosed-paren");
+pub const MSG_UNCLOSED_SINGLE_QUOTE: Message = Message("msg-unclosed-single-quote");
+pub const MSG_UNCLOSED_DOUBLE_QUOTE: Message = Message("msg-unclosed-double-quote");
+pub const MSG_UNCLOSED_VAR_EXPANSION: Message = Message("msg-unclosed-var-expansion");
+pub const MSG_UNCLOSED_CMD_SUBST: Message = Message("msg-unclosed-cmd-subst");
+pub const MSG_BASHISM: Message = Message("msg-bashism");
+
+// Best practice
+pub const MSG_USE_SET_E: Message = Message("msg-use-set-e");
+pub const MSG_USE_SET_U: Message = Message("msg-use-set-u");
+pub const MSG_USE_SET_PIPEFAIL: Message = Message("msg-use-set-pipefail");
+pub const MSG_UNQUOTED_VARIABLE: Message = Message("msg-unquoted-variable");
+pub const MSG_CD_WITHOUT_CHECK: Message = Message("msg-cd-without-check");
+pub const MSG_USE_DOLLAR_PAREN: Message = Message("msg-use-dollar-paren");
+
+// Security
+pub const MSG_EVAL_DANGEROUS: Message = Message("msg-eval-dangerous");
+pub const MSG_CURL_PIPE_SH: Message = Message("msg-curl-pipe-sh");
+pub const MSG_DANGEROUS_RM: Message = Message("msg-dangerous-rm");
+pub const MSG_USER_INPUT_IN_CMD: Message = Message("msg-user-input-in-cmd");
+pub const MSG_EXPRESSION_INJECTION: Message = Message("msg-expression-injection");
+
+// Style
+pub const MSG_USE_SPACES: Message = Message("msg-use-spaces");
+pub const MSG_INCONSISTENT_INDENT: Message = Message("msg-inconsistent-indent");
+pub const MSG_LINE_TOO_LONG: Message = Message("msg-line-too-long");
+pub const MSG_FUNCTION_NAMING: Message = Message("msg-function-naming");
+pub const MSG_VARIABLE_NAMING: Message = Message("msg-variable-naming");
+
+// Baseline
+pub const BASELINE_FIXED: Message = Message("baseline-fixed");
+pub const BASELINE_WRITTEN: Message = Message("baseline-written");
+pub const BASELINE_SUPPRESSED: Message = Message("baseline-suppressed");
+
+// Script detection
+pub const REASON_SHEBANG: Message = Message("reason-shebang");
+pub const REASON_MODELINE: Message = Message("reason-modeline");
+pub const REASON_FILE_NAME: Message = Message("reason-file-name");
+pub const REASON_EXTENSION: Message = Message("reason-extension");
+pub const REASON_DEFAULT: Message = Message("reason-default");
+pub const REASON_EMBEDDED: Message = Message("reason-embedded");
+pub const REASON_CONFIG: Message = Message("reason-config");
+pub const SKIPPED: Message = Message("skipped");
+
+// Git hook
+pub const HOOK_INSTALLED: Message = Message("hook-installed");
+
+// Watch mode
+pub const WATCH_LAST_CHECKED: Message = Message("watch-last-checked");
+pub const WATCH_WAITING: Message = Message("watch-waiting");
+
+// Quick fixes and editor actions
+pub const FIX_DOLLAR_PAREN: Message = Message("fix-dollar-paren");
+pub const FIX_CD_EXIT: Message = Message("fix-cd-exit");
+pub const FIX_QUOTE_VARIABLE: Message = Message("fix-quote-variable");
+pub const FIX_TABS: Message = Message("fix-tabs");
+pub const FIX_INSERT: Message = Message("fix-insert");
+pub const FIX_REPLACE: Message = Message("fix-replace");
+pub const FIX_DISABLE_LINE: Message = Message("fix-disable-line");
+pub const FIX_DISABLE_FILE: Message = Message("fix-disable-file");
+
+// Command line
+pub const CHECKING: Message = Message("checking");
+pub const ERROR_INVALID_CONFIG: Message = Message("error-invalid-config");
+pub const ERROR_UNKNOWN_RULE: Message = Message("error-unknown-rule");
+pub const ERROR_INVALID_GLOB: Message = Message("error-invalid-glob");
+pub const ERROR_GIT_CHANGES: Message = Message("error-git-changes");
+pub const ERROR_PATH_NOT_FOUND: Message = Message("error-path-not-found");
+pub const ERROR_READING_FILE: Message = Message("error-reading-file");
+pub const ERROR_READING_STDIN: Message = Message("error-reading-stdin");
+pub const ERROR_WALKING_DIRECTORY: Message = Message("error-walking-directory");
+pub const ERROR_READING_BASELINE: Message = Message("error-reading-baseline");
+pub const ERROR_WRITING_BASELINE: Message = Message("error-writing-baseline");
+pub const ERROR_STAGED_FILES: Message = Message("error-staged-files");
+pub const ERROR_READING_STAGED_FILE: Message = Message("error-reading-staged-file");
+pub const ERROR_HOOK_EXISTS: Message = Message("error-hook-exists");
+pub const ERROR_NOT_GIT_REPOSITORY: Message = Message("error-not-git-repository");
+pub const ERROR_WRITING_HOOK: Message = Message("error-writing-hook");
+pub const ERROR_WATCH: Message = Message("error-watch");
+pub const ERROR_CATALOG: Message = Message("error-catalog");
+pub const ERROR_UNSUPPORTED_LANGUAGE: Message = Message("error-unsupported-language");
+pub const ERROR_LANGUAGE_SERVER: Message = Message("error-language-server");
+
+// Configuration file
+pub const CONFIG_UNSUPPORTED_LANGUAGE: Message = Message("config-unsupported-language");
+pub const CONFIG_UNKNOWN_RULE: Message = Message("config-unknown-rule");
+pub const CONFIG_INVALID_CUSTOM_RULE: Message = Message("config-invalid-custom-rule");
+pub const CONFIG_INVALID_REGEX: Message = Message("config-invalid-regex");
+
+// Command-line arguments
+pub const ERROR_UNEXPECTED_ARGUMENT: Message = Message("error-unexpected-argument");
+pub const ERROR_MISSING_VALUE: Message = Message("error-missing-value");
+pub const ERROR_INVALID_VALUE: Message = Message("error-invalid-value");
+pub const ERROR_UNEXPECTED_VALUE: Message = Message("error-unexpected-value");
+pub const ERROR_MISSING_ARGUMENTS: Message = Message("error-missing-arguments");
+pub const ERROR_ARGUMENT_CONFLICT: Message = Message("error-argument-conflict");
+pub const ERROR_ARGUMENT_REPEATED: Message = Message("error-argument-repeated");
+pub const ERROR_UNKNOWN_SUBCOMMAND: Message = Message("error-unknown-subcommand");
+pub const POSSIBLE_VALUES: Message = Message("possible-values");
+pub const DID_YOU_MEAN: Message = Message("did-you-mean");
+pub const TRY_HELP: Message = Message("try-help");
+
+// Help
+pub const HELP_ABOUT: Message = Message("help-about");
+pub const HELP_VERSION_INFO: Message = Message("help-version-info");
+pub const HELP_USAGE: Message = Message("help-usage");
+pub const HELP_COMMANDS: Message = Message("help-commands");
+pub const HELP_ARGUMENTS: Message = Message("help-arguments");
+pub const HELP_OPTIONS: Message = Message("help-options");
+pub const HELP_HELP: Message = Message("help-help");
+pub const HELP_VERSION: Message = Message("help-version");
+pub const HELP_HELP_COMMAND: Message = Message("help-help-command");
+pub const HELP_HOOK: Message = Message("help-hook");
+pub const HELP_HOOK_INSTALL: Message = Message("help-hook-install");
+pub const HELP_HOOK_INSTALL_FORCE: Message = Message("help-hook-install-force");
+pub const HELP_HOOK_INSTALL_ARGS: Message = Message("help-hook-install-args");
+pub const HELP_LSP: Message = Message("help-lsp");
+pub const HELP_EXPLAIN: Message = Message("help-explain");
+pub const HELP_EXPLAIN_RULE: Message = Message("help-explain-rule");
+pub const HELP_EXPLAIN_MARKDOWN: Message = Message("help-explain-markdown");
+pub const HELP_PATHS: Message = Message("help-paths");
+pub const HELP_STDIN_FILENAME: Message = Message("help-stdin-filename");
+pub const HELP_RECURSIVE: Message = Message("help-recursive");
+pub const HELP_NO_IGNORE: Message = Message("help-no-ignore");
+pub const HELP_HIDDEN: Message = Message("help-hidden");
+pub const HELP_INCLUDE: Message = Message("help-include");
+pub const HELP_EXCLUDE: Message = Message("help-exclude");
+pub const HELP_LIST_FILES: Message = Message("help-list-files");
+pub const HELP_LIST_RULES: Message = Message("help-list-rules");
+pub const HELP_CHANGED_SINCE: Message = Message("help-changed-since");
+pub const HELP_DIFF_LINES_ONLY: Message = Message("help-diff-lines-only");
+pub const HELP_STAGED: Message = Message("help-staged");
+pub const HELP_WATCH: Message = Message("help-watch");
+pub const HELP_JOBS: Message = Message("help-jobs");
+pub const HELP_SEVERITY: Message = Message("help-severity");
+pub const HELP_ERRORS_ONLY: Message = Message("help-errors-only");
+pub const HELP_CATEGORY: Message = Message("help-category");
+pub const HELP_EXCLUDE_CATEGORY: Message = Message("help-exclude-category");
+pub const HELP_RULE: Message = Message("help-rule");
+pub const HELP_EXCLUDE_RULE: Message = Message("help-exclude-rule");
+pub const HELP_PROFILE: Message = Message("help-profile");
+pub const HELP_LANGUAGE: Message = Message("help-language");
+pub const HELP_BASELINE: Message = Message("help-baseline");
+pub const HELP_WRITE_BASELINE: Message = Message("help-write-baseline");
+pub const HELP_FAIL_ON: Message = Message("help-fail-on");
+pub const HELP_MAX_WARNINGS: Message = Message("help-max-warnings");
+
+// Rule documentation
+pub const DOC_RULES_TITLE: Message = Message("doc-rules-title");
+pub const DOC_RULE: Message = Message("doc-rule");
+pub const DOC_CATEGORY: Message = Message("doc-category");
+pub const DOC_SEVERITY: Message = Message("doc-severity");
+pub const DOC_RATIONALE: Message = Message("doc-rationale");
+pub const DOC_BAD: Message = Message("doc-bad");
+pub const DOC_GOOD: Message = Message("doc-good");
+pub const DOC_SUPPRESS: Message = Message("doc-suppress");
+pub const DOC_SUPPRESS_LINE: Message = Message("doc-suppress-line");
+pub const DOC_SUPPRESS_FILE: Message = Message("doc-suppress-file");
+pub const DOC_SUPPRESS_CONFIG: Message = Message("doc-suppress-config");
+
+/// ルールの説明（`explain` とルールのリファレンスで使用する）
+pub struct RuleDoc {
+    pub summary: Message,
+    pub rationale: Message,
+    // 問題のある例と修正例（言語によらない）
+    pub bad: &'static str,
+    pub good: &'static str,
 }
 
-pub fn msg_function_naming(func_name: &str, lang: &Language) -> String {
-    match lang {
-        Language::English => format!(
-            "Function name '{}' should use snake_case (lowercase with underscores)",
-            func_name
-        ),
-        Language::Japanese => format!(
-            "関数名 '{}' はスネークケース（小文字とアンダースコア）を使用してください",
-            func_name
-        ),
-    }
+pub fn rule_doc(rule: &str) -> Option<RuleDoc> {
+    let doc = match rule {
+        rules::MISSING_SHEBANG => RuleDoc {
+            summary: Message("doc-missing-shebang-summary"),
+            rationale: Message("doc-missing-shebang-rationale"),
+            bad: "echo \"Deploying $APP\"",
+            good: "#!/bin/bash\necho \"Deploying $APP\"",
+        },
+        rules::INVALID_SHEBANG => RuleDoc {
+            summary: Message("doc-invalid-shebang-summary"),
+            rationale: Message("doc-invalid-shebang-rationale"),
+            bad: "#!/bin/bsh",
+            good: "#!/usr/bin/env bash",
+        },
+        rules::UNMATCHED_BRACKET => RuleDoc {
+            summary: Message("doc-unmatched-bracket-summary"),
+            rationale: Message("doc-unmatched-bracket-rationale"),
+            bad: "if -f \"$file\" ]; then",
+            good: "if [ -f \"$file\" ]; then",
+        },
+        rules::UNCLOSED_BRACKET => RuleDoc {
+            summary: Message("doc-unclosed-bracket-summary"),
+            rationale: Message("doc-unclosed-bracket-rationale"),
+            bad: "if [ -f \"$file\"; then",
+            good: "if [ -f \"$file\" ]; then",
+        },
+        rules::UNCLOSED_BRACE => RuleDoc {
+            summary: Message("doc-unclosed-brace-summary"),
+            rationale: Message("doc-unclosed-brace-rationale"),
+            bad: "greet() {\n    echo \"hello\"",
+            good: "greet() {\n    echo \"hello\"\n}",
+        },
+        rules::UNCLOSED_PAREN => RuleDoc {
+            summary: Message("doc-unclosed-paren-summary"),
+            rationale: Message("doc-unclosed-paren-rationale"),
+            bad: "files=(a.txt b.txt",
+            good: "files=(a.txt b.txt)",
+        },
+        rules::UNCLOSED_SINGLE_QUOTE => RuleDoc {
+            summary: Message("doc-unclosed-single-quote-summary"),
+            rationale: Message("doc-unclosed-single-quote-rationale"),
+            bad: "echo 'it's done'",
+            good: "echo \"it's done\"",
+        },
+        rules::UNCLOSED_DOUBLE_QUOTE => RuleDoc {
+            summary: Message("doc-unclosed-double-quote-summary"),
+            rationale: Message("doc-unclosed-double-quote-rationale"),
+            bad: "echo \"Hello, $name",
+            good: "echo \"Hello, $name\"",
+        },
+        rules::UNCLOSED_VAR_EXPANSION => RuleDoc {
+            summary: Message("doc-unclosed-var-expansion-summary"),
+            rationale: Message("doc-unclosed-var-expansion-rationale"),
+            bad: "echo \"${HOME/bin\"",
+            good: "echo \"${HOME}/bin\"",
+        },
+        rules::UNCLOSED_CMD_SUBST => RuleDoc {
+            summary: Message("doc-unclosed-cmd-subst-summary"),
+            rationale: Message("doc-unclosed-cmd-subst-rationale"),
+            bad: "today=$(date +%F",
+            good: "today=$(date +%F)",
+        },
+        rules::BASHISM => RuleDoc {
+            summary: Message("doc-bashism-summary"),
+            rationale: Message("doc-bashism-rationale"),
+            bad: "#!/bin/sh\nif [[ -n $1 ]]; then\n    echo \"$1\"\nfi",
+            good: "#!/bin/sh\nif [ -n \"$1\" ]; then\n    echo \"$1\"\nfi",
+        },
+        rules::MISSING_SET_E => RuleDoc {
+            summary: Message("doc-missing-set-e-summary"),
+            rationale: Message("doc-missing-set-e-rationale"),
+            bad: "#!/bin/bash\ncp app.conf /etc/app/\nsystemctl restart app",
+            good: "#!/bin/bash\nset -e\ncp app.conf /etc/app/\nsystemctl restart app",
+        },
+        rules::MISSING_SET_U => RuleDoc {
+            summary: Message("doc-missing-set-u-summary"),
+            rationale: Message("doc-missing-set-u-rationale"),
+            bad: "#!/bin/bash\nrm -rf \"$buld_dir/\"",
+            good: "#!/bin/bash\nset -u\nrm -rf \"$buld_dir/\"",
+        },
+        rules::MISSING_PIPEFAIL => RuleDoc {
+            summary: Message("doc-missing-pipefail-summary"),
+            rationale: Message("doc-missing-pipefail-rationale"),
+            bad: "#!/bin/bash\nset -e\ncurl -fsS \"$url\" | tar xz",
+            good: "#!/bin/bash\nset -e -o pipefail\ncurl -fsS \"$url\" | tar xz",
+        },
+        rules::UNQUOTED_VARIABLE => RuleDoc {
+            summary: Message("doc-unquoted-variable-summary"),
+            rationale: Message("doc-unquoted-variable-rationale"),
+            bad: "rm $file",
+            good: "rm \"$file\"",
+        },
+        rules::CD_WITHOUT_CHECK => RuleDoc {
+            summary: Message("doc-cd-without-check-summary"),
+            rationale: Message("doc-cd-without-check-rationale"),
+            bad: "cd \"$build_dir\"\nrm -rf ./*",
+            good: "cd \"$build_dir\" || exit 1\nrm -rf ./*",
+        },
+        rules::BACKTICK_SUBSTITUTION => RuleDoc {
+            summary: Message("doc-backtick-substitution-summary"),
+            rationale: Message("doc-backtick-substitution-rationale"),
+            bad: "today=`date +%F`",
+            good: "today=$(date +%F)",
+        },
+        rules::EVAL_USAGE => RuleDoc {
+            summary: Message("doc-eval-usage-summary"),
+            rationale: Message("doc-eval-usage-rationale"),
+            bad: "eval \"ls $options $dir\"",
+            good: "options=(-l -a)\nls \"${options[@]}\" \"$dir\"",
+        },
+        rules::CURL_PIPE_SHELL => RuleDoc {
+            summary: Message("doc-curl-pipe-shell-summary"),
+            rationale: Message("doc-curl-pipe-shell-rationale"),
+            bad: "curl -fsSL https://example.com/install.sh | sh",
+            good: "curl -fsSL -o install.sh https://example.com/install.sh\nsha256sum -c install.sh.sha256\nsh install.sh",
+        },
+        rules::DANGEROUS_RM => RuleDoc {
+            summary: Message("doc-dangerous-rm-summary"),
+            rationale: Message("doc-dangerous-rm-rationale"),
+            bad: "rm -rf \"$dir\"/*",
+            good: "rm -rf \"${dir:?}\"/*",
+        },
+        rules::USER_INPUT_IN_COMMAND => RuleDoc {
+            summary: Message("doc-user-input-in-command-summary"),
+            rationale: Message("doc-user-input-in-command-rationale"),
+            bad: "eval \"ls $1\"",
+            good: "case \"$1\" in\n    -*) echo \"invalid directory: $1\" >&2; exit 1 ;;\nesac\nls -- \"$1\"",
+        },
+        rules::EXPRESSION_INJECTION => RuleDoc {
+            summary: Message("doc-expression-injection-summary"),
+            rationale: Message("doc-expression-injection-rationale"),
+            bad: "- run: echo \"${{ github.event.issue.title }}\"",
+            good: "- env:\n    TITLE: ${{ github.event.issue.title }}\n  run: echo \"$TITLE\"",
+        },
+        rules::TAB_INDENTATION => RuleDoc {
+            summary: Message("doc-tab-indentation-summary"),
+            rationale: Message("doc-tab-indentation-rationale"),
+            bad: "if [ -f \"$file\" ]; then\n\tcat \"$file\"\nfi",
+            good: "if [ -f \"$file\" ]; then\n    cat \"$file\"\nfi",
+        },
+        rules::INCONSISTENT_INDENTATION => RuleDoc {
+            summary: Message("doc-inconsistent-indentation-summary"),
+            rationale: Message("doc-inconsistent-indentation-rationale"),
+            bad: "if [ -f \"$file\" ]; then\n   cat \"$file\"\nfi",
+            good: "if [ -f \"$file\" ]; then\n    cat \"$file\"\nfi",
+        },
+        rules::LINE_TOO_LONG => RuleDoc {
+            summary: Message("doc-line-too-long-summary"),
+            rationale: Message("doc-line-too-long-rationale"),
+            bad: "docker run --rm --name app --network host --volume \"$PWD/data:/data\" --env-file .env --restart unless-stopped app:latest",
+            good: "docker run --rm --name app \\\n    --network host \\\n    --volume \"$PWD/data:/data\" \\\n    --env-file .env \\\n    --restart unless-stopped \\\n    app:latest",
+        },
+        rules::FUNCTION_NAMING => RuleDoc {
+            summary: Message("doc-function-naming-summary"),
+            rationale: Message("doc-function-naming-rationale"),
+            bad: "BuildApp() {\n    make all\n}",
+            good: "build_app() {\n    make all\n}",
+        },
+        rules::VARIABLE_NAMING => RuleDoc {
+            summary: Message("doc-variable-naming-summary"),
+            rationale: Message("doc-variable-naming-rationale"),
+            bad: "OutputDir=/tmp/build",
+            good: "output_dir=/tmp/build",
+        },
+        _ => return None,
+    };
+    Some(doc)
 }
 
-pub fn msg_variable_naming(var_name: &str, lang: &Language) -> String {
-    match lang {
-        Language::English => format!(
-            "Local variable '{}' should use lowercase with underscores",
-            var_name
-        ),
-        Language::Japanese => format!(
-            "ローカル変数 '{}' は小文字とアンダースコアを使用してください",
-            var_name
-        ),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_catalog_has_all_english_messages() {
+        let english = FluentResource::try_new(include_str!("../locales/en.ftl").to_string()).unwrap();
+        let ids: Vec<&str> = english
+            .entries()
+            .filter_map(|entry| match entry {
+                fluent_syntax::ast::Entry::Message(message) => Some(message.id.name),
+                _ => None,
+            })
+            .collect();
+
+        let catalogs = CATALOGS.read().unwrap();
+        for (code, _) in BUILTIN_CATALOGS {
+            let missing: Vec<&str> = ids.iter().copied().filter(|id| !catalogs[*code].has_message(id)).collect();
+            assert!(missing.is_empty(), "{} is missing {:?}", code, missing);
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,170 @@
+// src/lib.rs
+//! シェルスクリプトのチェッカー
+//!
+//! 文字列またはファイルをチェックし、見つかった問題を [`Report`] として返す。
+//!
+//! ```
+//! use shellchecker::{Language, Severity};
+//!
+//! let report = shellchecker::check_str("#!/bin/bash\necho $HOME\n", Language::ENGLISH);
+//! for issue in &report {
+//!     println!("L{} {} {}", issue.line, issue.rule, issue.message);
+//! }
+//! assert!(report.iter().any(|issue| issue.rule == "unquoted-variable"));
+//! assert_eq!(report.count(&Severity::Error), 0);
+//! ```
+//!
+//! 行単位の解析結果が必要な場合は [`ScriptParser`] を、シェルの種類を指定してチェックする場合は
+//! [`Checker::with_dialect`] を使用する。
+
+pub mod checker;
+pub mod config;
+pub mod detect;
+pub mod parser;
+pub mod profile;
+pub mod report;
+pub mod rules;
+
+mod baseline;
+mod explain;
+mod extract;
+mod git;
+mod hook;
+mod i18n;
+mod lsp;
+mod runner;
+mod scan;
+mod suppress;
+mod watch;
+
+#[doc(hidden)]
+pub mod cli;
+
+pub use checker::{Checker, CustomRule, StyleOptions};
+pub use config::{Config, ConfigError};
+pub use detect::Dialect;
+pub use i18n::Language;
+pub use parser::{ScriptLine, ScriptParser};
+pub use profile::Profile;
+pub use report::{Category, Filter, Fix, Issue, Report, Severity};
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use detect::{Detection, Reason};
+use i18n::ERROR_INVALID_CONFIG;
+
+/// [`check_path`] のエラー
+#[derive(Debug)]
+pub enum CheckError {
+    /// ファイルを読み込めない、または UTF-8 ではない
+    Io(io::Error),
+    /// `.shellchecker.toml` が不正
+    Config(ConfigError),
+}
+
+impl CheckError {
+    /// 指定した言語のエラーメッセージ
+    pub fn message(&self, lang: &Language) -> String {
+        match self {
+            CheckError::Io(e) => e.to_string(),
+            CheckError::Config(e) => ERROR_INVALID_CONFIG.format(lang, &[("error", e.message(lang).into())]),
+        }
+    }
+}
+
+impl fmt::Display for CheckError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message(&Language::ENGLISH))
+    }
+}
+
+impl std::error::Error for CheckError {}
+
+/// スクリプトの内容を bash としてチェックする
+///
+/// 設定ファイルは参照せず、すべてのルールの結果を返す。
+pub fn check_str(content: &str, language: Language) -> Report {
+    Checker::new(content, language).check()
+}
+
+/// ファイルをコマンドラインと同じ方法でチェックする
+///
+/// シェルの種類はシバン行などから判定し（判定できない場合は bash）、
+/// ファイルのディレクトリから探索した `.shellchecker.toml` のフィルタを適用する。
+/// Dockerfile などは埋め込まれたスクリプトを取り出してチェックする。
+pub fn check_path(path: &Path, language: Language) -> Result<Report, CheckError> {
+    let content = fs::read_to_string(path).map_err(CheckError::Io)?;
+
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let dir = std::path::absolute(dir).map_err(CheckError::Io)?;
+    let config = match Config::find(&dir) {
+        Some(config_path) => Config::load(&config_path).map_err(CheckError::Config)?,
+        None => Config::default(),
+    };
+
+    let config = config.resolve(path);
+    let (_, report) = check_content(path, &content, &language, &config, false);
+    let mut report = report.unwrap_or_default();
+    config.filter().apply(&mut report);
+    Ok(report)
+}
+
+/// ファイルの扱いを判定する（埋め込まれたスクリプトの形式、設定ファイルの `dialect`、シバン行などの順）
+///
+/// 設定ファイルの `dialect` はシェルスクリプトと判定されたファイルか、`detect` が false のファイル
+/// （明示的に指定されたファイル）に適用する。
+pub(crate) fn detect_content(path: &Path, content: &str, config: &Config, detect: bool) -> Detection {
+    if let Some(extractor) = extract::find(path) {
+        return Detection {
+            dialect: Some(extractor.dialect()),
+            reason: Reason::Embedded(extractor.name()),
+        };
+    }
+
+    let detection = detect::detect(path, content);
+    match config.dialect {
+        Some(dialect) if detection.dialect.is_some() || !detect => Detection {
+            dialect: Some(dialect),
+            reason: Reason::Config,
+        },
+        _ => detection,
+    }
+}
+
+/// ファイルを判定してチェックし、行番号順に並べたレポートを返す（フィルタは適用しない）
+///
+/// コマンドライン、[`check_path`]、言語サーバーで共通の処理で、解決済みの設定の `dialect`、スタイル、
+/// ユーザー定義のルールを適用する。チェック対象外の方言 (zsh) と判定された場合と、`detect` が true で
+/// シェルスクリプトと判定されなかった場合は None を返す。明示的に指定されたファイルは判定できなくても
+/// bash としてチェックする。
+pub(crate) fn check_content(
+    path: &Path,
+    content: &str,
+    language: &Language,
+    config: &Config,
+    detect: bool,
+) -> (Detection, Option<Report>) {
+    let detection = detect_content(path, content, config, detect);
+    let mut report = match extract::find(path) {
+        Some(extractor) => extract::check(extractor, content, language, config.style(), &config.custom_rules),
+        None if detection.dialect.is_some_and(|d| !d.is_supported()) => return (detection, None),
+        None if detect && detection.dialect.is_none() => return (detection, None),
+        None => {
+            let dialect = detection.dialect.unwrap_or(Dialect::Bash);
+            Checker::new(content, language.clone())
+                .with_dialect(dialect)
+                .with_style(config.style())
+                .with_custom_rules(config.custom_rules.clone())
+                .check()
+        }
+    };
+    // チェックを実行する順序に出力が左右されないようにする
+    report.sort();
+    (detection, Some(report))
+}
@@ -0,0 +1,504 @@
+// src/lsp.rs
+// `shellchecker lsp`: 標準入出力で Language Server Protocol を話すサーバー
+use crate::config::Config;
+use crate::extract;
+use crate::i18n::{ERROR_INVALID_CONFIG, FIX_DISABLE_FILE, FIX_DISABLE_LINE, Language, rule_doc};
+use crate::report::{Issue, Severity};
+use crate::suppress;
+use lsp_server::{Connection, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
+    Notification as _, PublishDiagnostics,
+};
+use lsp_types::request::{CodeActionRequest, HoverRequest, Request as _};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionProviderCapability, Diagnostic,
+    DiagnosticSeverity, Hover, HoverContents, HoverParams, HoverProviderCapability, InitializeParams,
+    MarkupContent, MarkupKind, NumberOrString, Position, PublishDiagnosticsParams, Range, SaveOptions,
+    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions, TextEdit,
+    Url, WorkspaceEdit,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+const SOURCE: &str = "shellchecker";
+
+/// クライアントが `initializationOptions` で渡せる設定
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct InitializationOptions {
+    language: Option<String>,
+}
+
+struct Document {
+    path: PathBuf,
+    content: String,
+    issues: Vec<Issue>,
+    language: Language,
+}
+
+struct Server {
+    connection: Connection,
+    // コマンドライン引数または initializationOptions で指定された言語
+    language: Option<Language>,
+    workspace_roots: Vec<PathBuf>,
+    documents: HashMap<Url, Document>,
+}
+
+/// 標準入出力で言語サーバーを起動し、クライアントが終了するまで処理する
+pub fn run(language: Option<Language>) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Options(TextDocumentSyncOptions {
+            open_close: Some(true),
+            change: Some(TextDocumentSyncKind::FULL),
+            save: Some(SaveOptions { include_text: Some(true) }.into()),
+            ..Default::default()
+        })),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        ..Default::default()
+    };
+    let params = connection.initialize(serde_json::to_value(capabilities)?)?;
+    let params: InitializeParams = serde_json::from_value(params)?;
+
+    let options: InitializationOptions = params
+        .initialization_options
+        .and_then(|options| serde_json::from_value(options).ok())
+        .unwrap_or_default();
+    let workspace_roots = params
+        .workspace_folders
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|folder| folder.uri.to_file_path().ok())
+        .collect();
+
+    let mut server = Server {
+        connection,
+        language: language.or_else(|| options.language.as_deref().and_then(Language::from_code)),
+        workspace_roots,
+        documents: HashMap::new(),
+    };
+    server.main_loop()?;
+    drop(server);
+
+    io_threads.join()?;
+    Ok(())
+}
+
+impl Server {
+    fn main_loop(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&mut self, request: Request) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let response = match request.method.as_str() {
+            CodeActionRequest::METHOD => {
+                let (id, params) = request.extract::<CodeActionParams>(CodeActionRequest::METHOD)?;
+                Response::new_ok(id, self.code_actions(&params))
+            }
+            HoverRequest::METHOD => {
+                let (id, params) = request.extract::<HoverParams>(HoverRequest::METHOD)?;
+                Response::new_ok(id, self.hover(&params))
+            }
+            _ => method_not_found(request.id, &request.method),
+        };
+        self.connection.sender.send(Message::Response(response))?;
+        Ok(())
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Result<(), Box<dyn Error + Send + Sync>> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: lsp_types::DidOpenTextDocumentParams = serde_json::from_value(notification.params)?;
+                self.update(params.text_document.uri, params.text_document.text)?;
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: lsp_types::DidChangeTextDocumentParams = serde_json::from_value(notification.params)?;
+                // 全文同期のため最後の変更が文書全体になる
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    self.update(params.text_document.uri, change.text)?;
+                }
+            }
+            DidSaveTextDocument::METHOD => {
+                let params: lsp_types::DidSaveTextDocumentParams = serde_json::from_value(notification.params)?;
+                let uri = params.text_document.uri;
+                // 保存時は設定ファイルの変更も反映するため再チェックする
+                let text = params.text.or_else(|| self.documents.get(&uri).map(|doc| doc.content.clone()));
+                if let Some(text) = text {
+                    self.update(uri, text)?;
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: lsp_types::DidCloseTextDocumentParams = serde_json::from_value(notification.params)?;
+                self.documents.remove(&params.text_document.uri);
+                self.publish(params.text_document.uri, Vec::new())?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// 文書をチェックし、診断結果をクライアントに送信する
+    fn update(&mut self, uri: Url, content: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = uri.to_file_path().ok();
+        let report_path = path.clone().unwrap_or_else(|| PathBuf::from(uri.path()));
+        let config = self.config_for(path.as_deref()).resolve(&report_path);
+        let language = self
+            .language
+            .clone()
+            .or_else(|| config.language())
+            .or_else(Language::from_env)
+            .unwrap_or_default();
+
+        let (_, report) = crate::check_content(&report_path, &content, &language, &config, false);
+        let mut report = report.unwrap_or_default();
+        config.filter().apply(&mut report);
+
+        let lines: Vec<&str> = content.lines().collect();
+        let diagnostics = report
+            .issues()
+            .iter()
+            .map(|issue| Diagnostic {
+                range: line_range(&lines, issue.line),
+                severity: Some(severity(&issue.severity)),
+                code: Some(NumberOrString::String(issue.rule.clone())),
+                source: Some(SOURCE.to_string()),
+                message: issue.message.clone(),
+                ..Default::default()
+            })
+            .collect();
+
+        self.documents.insert(
+            uri.clone(),
+            Document {
+                path: report_path,
+                issues: report.issues().to_vec(),
+                content,
+                language,
+            },
+        );
+        self.publish(uri, diagnostics)
+    }
+
+    fn publish(&self, uri: Url, diagnostics: Vec<Diagnostic>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let params = PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version: None,
+        };
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+        self.connection.sender.send(Message::Notification(notification))?;
+        Ok(())
+    }
+
+    /// 文書のディレクトリ（ファイル以外の文書はワークスペース）から設定ファイルを探す
+    fn config_for(&self, path: Option<&Path>) -> Config {
+        let start = match path.and_then(|p| p.parent()) {
+            Some(dir) => dir.to_path_buf(),
+            None => match self.workspace_roots.first() {
+                Some(root) => root.clone(),
+                None => return Config::default(),
+            },
+        };
+
+        match Config::find(&start).map(|config_path| Config::load(&config_path)) {
+            Some(Ok(config)) => config,
+            Some(Err(e)) => {
+                // 標準エラー出力はクライアントのログに表示される。設定ファイルの言語は読めないため使わない
+                let language = self.language.clone().or_else(Language::from_env).unwrap_or_default();
+                let error = e.message(&language);
+                eprintln!("shellchecker: {}", ERROR_INVALID_CONFIG.format(&language, &[("error", error.into())]));
+                Config::default()
+            }
+            None => Config::default(),
+        }
+    }
+
+    fn code_actions(&self, params: &CodeActionParams) -> Vec<CodeActionOrCommand> {
+        let uri = &params.text_document.uri;
+        let Some(doc) = self.documents.get(uri) else { return Vec::new() };
+        let lines: Vec<&str> = doc.content.lines().collect();
+        let has_shebang = lines.first().is_some_and(|line| line.starts_with("#!"));
+        let range = params.range;
+        let extractor = extract::find(&doc.path);
+
+        let mut actions = Vec::new();
+        for issue in doc.issues.iter().filter(|issue| {
+            let line = issue.line.saturating_sub(1) as u32;
+            line >= range.start.line && line <= range.end.line
+        }) {
+            let diagnostic = params
+                .context
+                .diagnostics
+                .iter()
+                .find(|d| d.code == Some(NumberOrString::String(issue.rule.clone())))
+                .cloned();
+
+            if let Some(fix) = &issue.fix {
+                let edit = TextEdit {
+                    range: line_range(&lines, issue.line),
+                    new_text: fix.replacement.clone(),
+                };
+                actions.push(code_action(&fix.title, uri, edit, diagnostic.clone(), true));
+            }
+
+            // シバン行の前と、挿入した行がスクリプトのコメントにならない位置には挿入できない
+            let accepts_line_directive =
+                extractor.is_none_or(|extractor| extractor.accepts_line_directive(&doc.content, issue.line));
+            if !(issue.line == 1 && has_shebang) && accepts_line_directive {
+                let index = issue.line.saturating_sub(1);
+                let line = lines.get(index).copied().unwrap_or("");
+                let indent = &line[..line.len() - line.trim_start().len()];
+                let edit = TextEdit {
+                    range: insert_at(index),
+                    new_text: format!("{}\n", suppress::line_directive(&issue.rule, indent)),
+                };
+                let title = FIX_DISABLE_LINE.format(&doc.language, &[("rule", issue.rule.as_str().into())]);
+                actions.push(code_action(&title, uri, edit, diagnostic.clone(), false));
+            }
+
+            // 抑制コメントはファイル形式のコメントで書く
+            let directive = match extractor {
+                Some(extractor) => extractor.file_directive(&issue.rule),
+                None => Some(suppress::file_directive(&issue.rule)),
+            };
+            if let Some(directive) = directive {
+                let edit = TextEdit {
+                    range: insert_at(if has_shebang { 1 } else { 0 }),
+                    new_text: format!("{}\n", directive),
+                };
+                let title = FIX_DISABLE_FILE.format(&doc.language, &[("rule", issue.rule.as_str().into())]);
+                actions.push(code_action(&title, uri, edit, diagnostic, false));
+            }
+        }
+        actions
+    }
+
+    fn hover(&self, params: &HoverParams) -> Option<Hover> {
+        let position = &params.text_document_position_params;
+        let doc = self.documents.get(&position.text_document.uri)?;
+        let line = position.position.line as usize + 1;
+
+        let sections: Vec<String> = doc
+            .issues
+            .iter()
+            .filter(|issue| issue.line == line)
+            .map(|issue| {
+                let mut section = format!(
+                    "**{}** ({}, {})\n\n{}",
+                    issue.rule,
+                    issue.category.label(&doc.language),
+                    issue.severity.label(&doc.language),
+                    issue.message
+                );
+                // ルールの説明（`shellchecker explain` と同じ理由）
+                if let Some(rule_doc) = rule_doc(&issue.rule) {
+                    section.push_str(&format!("\n\n{}", rule_doc.rationale.get(&doc.language)));
+                }
+                section
+            })
+            .collect();
+        if sections.is_empty() {
+            return None;
+        }
+
+        let lines: Vec<&str> = doc.content.lines().collect();
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: sections.join("\n\n---\n\n"),
+            }),
+            range: Some(line_range(&lines, line)),
+        })
+    }
+}
+
+fn severity(severity: &Severity) -> DiagnosticSeverity {
+    match severity {
+        Severity::Error => DiagnosticSeverity::ERROR,
+        Severity::Warning => DiagnosticSeverity::WARNING,
+        Severity::Info => DiagnosticSeverity::INFORMATION,
+    }
+}
+
+/// 行全体の範囲（LSP の列は UTF-16 単位）
+fn line_range(lines: &[&str], line: usize) -> Range {
+    let index = line.saturating_sub(1);
+    let length = lines.get(index).map_or(0, |l| l.encode_utf16().count());
+    Range::new(Position::new(index as u32, 0), Position::new(index as u32, length as u32))
+}
+
+fn insert_at(index: usize) -> Range {
+    let position = Position::new(index as u32, 0);
+    Range::new(position, position)
+}
+
+fn code_action(
+    title: &str,
+    uri: &Url,
+    edit: TextEdit,
+    diagnostic: Option<Diagnostic>,
+    preferred: bool,
+) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title: title.to_string(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: diagnostic.map(|d| vec![d]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), vec![edit])])),
+            ..Default::default()
+        }),
+        is_preferred: preferred.then_some(true),
+        ..Default::default()
+    })
+}
+
+fn method_not_found(id: RequestId, method: &str) -> Response {
+    Response::new_err(
+        id,
+        lsp_server::ErrorCode::MethodNotFound as i32,
+        format!("unhandled method: {}", method),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Message as I18nMessage;
+    use lsp_types::{CodeActionContext, TextDocumentIdentifier};
+
+    // クライアント側の接続は診断結果の送信先として保持しておく
+    fn server() -> (Server, Connection) {
+        let (connection, client) = Connection::memory();
+        let server = Server {
+            connection,
+            language: Some(Language::ENGLISH),
+            workspace_roots: Vec::new(),
+            documents: HashMap::new(),
+        };
+        (server, client)
+    }
+
+    fn actions_on_line(server: &Server, uri: &Url, line: u32) -> Vec<CodeAction> {
+        let params = CodeActionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            range: Range::new(Position::new(line, 0), Position::new(line, 0)),
+            context: CodeActionContext::default(),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        server
+            .code_actions(&params)
+            .into_iter()
+            .filter_map(|action| match action {
+                CodeActionOrCommand::CodeAction(action) => Some(action),
+                CodeActionOrCommand::Command(_) => None,
+            })
+            .collect()
+    }
+
+    fn disable_edit(actions: &[CodeAction], message: I18nMessage, rule: &str) -> Option<TextEdit> {
+        let title = message.format(&Language::ENGLISH, &[("rule", rule.into())]);
+        let action = actions.iter().find(|action| action.title == title)?;
+        let changes = action.edit.as_ref()?.changes.as_ref()?;
+        changes.values().next()?.first().cloned()
+    }
+
+    fn disable_file_edit(actions: &[CodeAction], rule: &str) -> Option<TextEdit> {
+        disable_edit(actions, FIX_DISABLE_FILE, rule)
+    }
+
+    fn disable_line_edit(actions: &[CodeAction], rule: &str) -> Option<TextEdit> {
+        disable_edit(actions, FIX_DISABLE_LINE, rule)
+    }
+
+    /// 行頭への挿入を適用する
+    fn insert(content: &str, edit: &TextEdit) -> String {
+        let mut lines: Vec<&str> = content.lines().collect();
+        let text = edit.new_text.trim_end_matches('\n');
+        lines.insert(edit.range.start.line as usize, text);
+        lines.join("\n") + "\n"
+    }
+
+    #[test]
+    fn disable_file_action_in_markdown_uses_html_comment() {
+        let (mut server, _client) = server();
+        let uri = Url::parse("file:///nonexistent/README.md").unwrap();
+        let content = "# Install\n\n```bash\neval \"$1\"\n```\n";
+        server.update(uri.clone(), content.to_string()).unwrap();
+        assert!(server.documents[&uri].issues.iter().any(|issue| issue.rule == "eval-usage"));
+
+        let edit = disable_file_edit(&actions_on_line(&server, &uri, 3), "eval-usage").unwrap();
+        assert_eq!(edit.new_text, "<!-- shellchecker disable-file=eval-usage -->\n");
+
+        let suppressed = insert(content, &edit);
+        assert!(suppressed.starts_with("<!-- shellchecker disable-file=eval-usage -->\n# Install\n"));
+        server.update(uri.clone(), suppressed).unwrap();
+        assert!(server.documents[&uri].issues.iter().all(|issue| issue.rule != "eval-usage"));
+    }
+
+    #[test]
+    fn no_disable_file_action_in_jenkinsfile() {
+        let (mut server, _client) = server();
+        let uri = Url::parse("file:///nonexistent/Jenkinsfile").unwrap();
+        server.update(uri.clone(), "node {\n    sh 'eval \"$1\"'\n}\n".to_string()).unwrap();
+        assert!(server.documents[&uri].issues.iter().any(|issue| issue.rule == "eval-usage"));
+
+        assert!(disable_file_edit(&actions_on_line(&server, &uri, 1), "eval-usage").is_none());
+    }
+
+    #[test]
+    fn disable_line_action_in_jenkinsfile_only_inside_multiline_literal() {
+        let (mut server, _client) = server();
+        let uri = Url::parse("file:///nonexistent/Jenkinsfile").unwrap();
+        let content = "node {\n    sh 'eval \"$1\"'\n    sh \"\"\"\n        eval \"$2\"\n    \"\"\"\n}\n";
+        server.update(uri.clone(), content.to_string()).unwrap();
+        let lines: Vec<usize> = server.documents[&uri]
+            .issues
+            .iter()
+            .filter(|issue| issue.rule == "eval-usage")
+            .map(|issue| issue.line)
+            .collect();
+        assert_eq!(lines, [2, 4]);
+
+        // 1 行の文字列の前に挿入すると Groovy のコードになる
+        assert!(disable_line_edit(&actions_on_line(&server, &uri, 1), "eval-usage").is_none());
+
+        let edit = disable_line_edit(&actions_on_line(&server, &uri, 3), "eval-usage").unwrap();
+        server.update(uri.clone(), insert(content, &edit)).unwrap();
+        let lines: Vec<usize> = server.documents[&uri]
+            .issues
+            .iter()
+            .filter(|issue| issue.rule == "eval-usage")
+            .map(|issue| issue.line)
+            .collect();
+        assert_eq!(lines, [2]);
+    }
+
+    #[test]
+    fn zsh_document_has_no_diagnostics() {
+        let (mut server, _client) = server();
+        let uri = Url::parse("file:///nonexistent/z.sh").unwrap();
+        server.update(uri.clone(), "#!/usr/bin/env zsh\necho $HOME\n".to_string()).unwrap();
+        assert!(server.documents[&uri].issues.is_empty());
+
+        server.update(uri.clone(), "#!/bin/bash\necho $HOME\n".to_string()).unwrap();
+        assert!(!server.documents[&uri].issues.is_empty());
+    }
+}
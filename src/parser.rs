@@ -1,4 +1,9 @@
 // src/parser.rs
+use std::iter::Peekable;
+use std::ops::Range;
+use std::str::CharIndices;
+
+/// スクリプトの1行
 #[derive(Debug, Clone)]
 pub struct ScriptLine {
     pub number: usize,
@@ -6,6 +11,7 @@ pub struct ScriptLine {
     pub trimmed: String,
 }
 
+/// スクリプトを行単位に分割した結果
 pub struct ScriptParser {
     lines: Vec<ScriptLine>,
 }
@@ -28,4 +34,293 @@ impl ScriptParser {
     pub fn lines(&self) -> &[ScriptLine] {
         &self.lines
     }
-}
\ No newline at end of file
+}
+/// 1 行に含まれる単純コマンド（`|`、`;`、`&&`、`||`、`&` などで区切られた 1 つのコマンド）
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimpleCommand {
+    /// クォートとエスケープを取り除いた単語（先頭の変数代入とリダイレクトは含まない）
+    pub words: Vec<String>,
+    /// コマンド名から最後の単語までの行内の範囲（バイト位置）
+    pub range: Range<usize>,
+    /// 引数の開始位置（引数がなければ `range.end`）
+    pub args_start: usize,
+}
+
+impl SimpleCommand {
+    /// コマンド名（パスを除いたもの）
+    pub fn name(&self) -> &str {
+        let command = self.words.first().map(String::as_str).unwrap_or("");
+        command.rsplit('/').next().unwrap_or(command)
+    }
+
+    pub fn args(&self) -> &[String] {
+        self.words.get(1..).unwrap_or(&[])
+    }
+}
+
+impl ScriptLine {
+    pub fn commands(&self) -> Vec<SimpleCommand> {
+        commands(&self.content)
+    }
+}
+
+// コマンドの前に置かれるキーワード（コマンド名としては扱わない）
+const KEYWORDS: &[&str] = &["if", "then", "else", "elif", "fi", "do", "done", "while", "until", "!", "time", "{", "}"];
+
+#[derive(Debug)]
+enum Token {
+    Word { text: String, range: Range<usize> },
+    Separator,
+}
+
+/// 1 行を単純コマンドに分割する
+///
+/// 行単位の簡易的な字句解析で、コマンド置換 `$(...)` とバッククォートの中は 1 つの単語として扱う。
+pub fn commands(text: &str) -> Vec<SimpleCommand> {
+    let mut result = Vec::new();
+    let mut words = Vec::new();
+    for token in tokenize(text).into_iter().chain([Token::Separator]) {
+        match token {
+            Token::Word { text, range } => words.push((text, range)),
+            Token::Separator => {
+                if let Some(command) = simple_command(&words) {
+                    result.push(command);
+                }
+                words.clear();
+            }
+        }
+    }
+    result
+}
+
+/// クォートを取り除いた単語に分割する（区切り記号は無視する）
+pub fn split_words(text: &str) -> Vec<String> {
+    tokenize(text)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Word { text, .. } => Some(text),
+            Token::Separator => None,
+        })
+        .collect()
+}
+
+fn simple_command(words: &[(String, Range<usize>)]) -> Option<SimpleCommand> {
+    let mut rest = words
+        .iter()
+        .skip_while(|(word, _)| KEYWORDS.contains(&word.as_str()) || is_assignment(word))
+        .peekable();
+
+    let mut command: Vec<&(String, Range<usize>)> = Vec::new();
+    while let Some(word) = rest.next() {
+        if let Some(target) = redirection_target(&word.0) {
+            // `> file` のように対象が次の単語に分かれている場合は対象も読み飛ばす
+            if target.is_empty() {
+                rest.next();
+            }
+            continue;
+        }
+        command.push(word);
+    }
+
+    let first = command.first()?;
+    let last = command.last()?;
+    Some(SimpleCommand {
+        words: command.iter().map(|(word, _)| word.clone()).collect(),
+        range: first.1.start..last.1.end,
+        args_start: command.get(1).map_or(last.1.end, |(_, range)| range.start),
+    })
+}
+
+fn is_assignment(word: &str) -> bool {
+    match word.split_once('=') {
+        Some((name, _)) => {
+            let name = name.strip_suffix('+').unwrap_or(name);
+            name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        None => false,
+    }
+}
+
+/// リダイレクトであればその対象（`2>&1` なら `1`、`>` のみなら空文字列）を返す
+fn redirection_target(word: &str) -> Option<&str> {
+    let rest = word.trim_start_matches(|c: char| c.is_ascii_digit());
+    let rest = rest.strip_prefix('&').filter(|r| r.starts_with('>')).unwrap_or(rest);
+    if !rest.starts_with(['<', '>']) {
+        return None;
+    }
+    Some(rest.trim_start_matches(['<', '>', '&', '|']))
+}
+
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut word: Option<(String, Range<usize>)> = None;
+    let mut chars = text.char_indices().peekable();
+
+    // 単語に文字を追加し、範囲をその文字の後ろまで広げる
+    fn push(word: &mut Option<(String, Range<usize>)>, at: usize, text: &str, end: usize) {
+        let (current, range) = word.get_or_insert_with(|| (String::new(), at..at));
+        current.push_str(text);
+        range.end = end;
+    }
+
+    fn finish(tokens: &mut Vec<Token>, word: &mut Option<(String, Range<usize>)>) {
+        if let Some((text, range)) = word.take() {
+            tokens.push(Token::Word { text, range });
+        }
+    }
+
+    // コマンド置換は対応する括弧までを 1 つの単語にする（内側の引用符の中の括弧は数えない）
+    fn substitution(chars: &mut Peekable<CharIndices>, word: &mut Option<(String, Range<usize>)>, at: usize) {
+        let mut depth = 0;
+        let mut quote = None;
+        push(word, at, "$", at + 1);
+        while let Some((j, c)) = chars.next() {
+            push(word, at, c.encode_utf8(&mut [0; 4]), j + c.len_utf8());
+            match (quote, c) {
+                (Some('\''), '\'') => quote = None,
+                (Some('\''), _) => {}
+                (_, '\\') => {
+                    if let Some((k, escaped)) = chars.next() {
+                        push(word, at, escaped.encode_utf8(&mut [0; 4]), k + escaped.len_utf8());
+                    }
+                }
+                (Some('"'), '"') => quote = None,
+                (Some(_), _) => {}
+                (None, '\'' | '"') => quote = Some(c),
+                (None, '(') => depth += 1,
+                (None, ')') => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                break;
+            }
+        }
+    }
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            ' ' | '\t' => finish(&mut tokens, &mut word),
+            '#' if word.is_none() => break,
+            '\'' => {
+                push(&mut word, i, "", i + 1);
+                for (j, c) in chars.by_ref() {
+                    if c == '\'' {
+                        push(&mut word, i, "", j + 1);
+                        break;
+                    }
+                    push(&mut word, i, c.encode_utf8(&mut [0; 4]), j + c.len_utf8());
+                }
+            }
+            '"' => {
+                push(&mut word, i, "", i + 1);
+                while let Some((j, c)) = chars.next() {
+                    match c {
+                        '"' => {
+                            push(&mut word, i, "", j + 1);
+                            break;
+                        }
+                        '$' if chars.peek().is_some_and(|&(_, next)| next == '(') => {
+                            substitution(&mut chars, &mut word, j)
+                        }
+                        '\\' => match chars.next() {
+                            Some((k, escaped @ ('$' | '`' | '"' | '\\'))) => {
+                                push(&mut word, i, escaped.encode_utf8(&mut [0; 4]), k + 1)
+                            }
+                            Some((k, other)) => {
+                                push(&mut word, i, &format!("\\{}", other), k + other.len_utf8())
+                            }
+                            None => push(&mut word, i, "\\", j + 1),
+                        },
+                        _ => push(&mut word, i, c.encode_utf8(&mut [0; 4]), j + c.len_utf8()),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                Some((j, escaped)) => push(&mut word, i, escaped.encode_utf8(&mut [0; 4]), j + escaped.len_utf8()),
+                None => push(&mut word, i, "", i + 1),
+            },
+            '$' if chars.peek().is_some_and(|&(_, next)| next == '(') => substitution(&mut chars, &mut word, i),
+            '`' => {
+                push(&mut word, i, "`", i + 1);
+                for (j, c) in chars.by_ref() {
+                    push(&mut word, i, c.encode_utf8(&mut [0; 4]), j + c.len_utf8());
+                    if c == '`' {
+                        break;
+                    }
+                }
+            }
+            // `2>&1` や `>&2` の `&` はリダイレクトの一部
+            '&' if word.as_ref().is_some_and(|(text, _)| text.ends_with(['<', '>'])) => {
+                push(&mut word, i, "&", i + 1)
+            }
+            ';' | '|' | '&' | '(' | ')' => {
+                finish(&mut tokens, &mut word);
+                tokens.push(Token::Separator);
+            }
+            _ => push(&mut word, i, c.encode_utf8(&mut [0; 4]), i + c.len_utf8()),
+        }
+    }
+    finish(&mut tokens, &mut word);
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> Vec<Vec<String>> {
+        commands(text).into_iter().map(|command| command.words).collect()
+    }
+
+    #[test]
+    fn quotes_and_escapes_are_removed() {
+        assert_eq!(
+            split_words(r#"echo 'a b' "c \"d\" \$e \n" f\ g"#),
+            ["echo", "a b", r#"c "d" $e \n"#, "f g"]
+        );
+    }
+
+    #[test]
+    fn command_substitution_is_one_word() {
+        assert_eq!(
+            split_words(r#"echo $(dirname "$(pwd)") "$(echo "a b" ')')" `date +%s`"#),
+            ["echo", r#"$(dirname "$(pwd)")"#, r#"$(echo "a b" ')')"#, "`date +%s`"]
+        );
+    }
+
+    #[test]
+    fn trailing_comment_is_ignored() {
+        assert_eq!(words("rm -rf tmp # clean up; rm -rf /"), [["rm", "-rf", "tmp"]]);
+        assert_eq!(words("echo a#b '# c'"), [["echo", "a#b", "# c"]]);
+    }
+
+    #[test]
+    fn separators_keywords_assignments_and_redirections() {
+        assert_eq!(
+            words("if LANG=C grep -q x f 2>&1 >/dev/null; then sudo rm a & (cd b || exit) | tee > log; fi"),
+            [
+                vec!["grep", "-q", "x", "f"],
+                vec!["sudo", "rm", "a"],
+                vec!["cd", "b"],
+                vec!["exit"],
+                vec!["tee"],
+            ]
+        );
+        assert!(commands("A=1 B+=2 > out").is_empty());
+    }
+
+    #[test]
+    fn ranges_are_byte_positions_in_the_line() {
+        let line = "x=1 ü && /usr/bin/aws s3 'rm' --recursive >&2";
+        let commands = commands(line);
+        assert_eq!(commands[0].range, 4..6);
+        assert_eq!(commands[0].args_start, 6);
+
+        let aws = &commands[1];
+        assert_eq!(aws.name(), "aws");
+        assert_eq!(aws.args(), ["s3", "rm", "--recursive"]);
+        assert_eq!(&line[aws.range.clone()], "/usr/bin/aws s3 'rm' --recursive");
+        assert_eq!(&line[aws.args_start..aws.range.end], "s3 'rm' --recursive");
+    }
+}
@@ -0,0 +1,57 @@
+// src/profile.rs
+// 組み込みのルールプロファイル（`--profile` / 設定ファイルの `extends`）
+use crate::checker::CustomRule;
+use crate::config::Config;
+use crate::report::{Category, Severity};
+use crate::rules;
+use clap::ValueEnum;
+use serde::Deserialize;
+
+/// ルールの組み合わせと重大度をまとめたプロファイル
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Profile {
+    /// すべてのルールを報告し、情報レベルのルールを警告に引き上げる
+    Strict,
+    /// 既定の重大度ですべてのルールを報告する
+    Recommended,
+    /// エラーのみを報告する
+    Minimal,
+    /// セキュリティのルールと危険なベストプラクティス違反のみを報告する
+    Security,
+}
+
+/// 危険な動作につながるため `security` プロファイルに含めるベストプラクティスのルール
+const DANGEROUS_BEST_PRACTICES: &[&str] = &[rules::UNQUOTED_VARIABLE, rules::CD_WITHOUT_CHECK];
+
+impl Profile {
+    /// プロファイルの設定（設定ファイルとコマンドライン引数はこの上に重ね合わせる）
+    ///
+    /// ユーザー定義のルールも分類と重大度に従って組み込みのルールと同じように扱う。
+    pub fn config(self, custom_rules: &[CustomRule]) -> Config {
+        let all_rules = rules::ALL
+            .iter()
+            .filter_map(|rule| rules::metadata(rule).map(|(category, severity)| (rule.to_string(), category, severity)))
+            .chain(custom_rules.iter().map(|rule| (rule.id.clone(), rule.category, rule.severity.clone())));
+        match self {
+            Profile::Strict => Config {
+                rule_severity: all_rules
+                    .filter(|(_, _, severity)| *severity == Severity::Info)
+                    .map(|(rule, _, _)| (rule, Severity::Warning))
+                    .collect(),
+                ..Config::default()
+            },
+            Profile::Recommended => Config::default(),
+            Profile::Minimal => Config { severity: Some(Severity::Error), ..Config::default() },
+            Profile::Security => Config {
+                rules: all_rules
+                    .filter(|(rule, category, _)| {
+                        *category == Category::Security || DANGEROUS_BEST_PRACTICES.contains(&rule.as_str())
+                    })
+                    .map(|(rule, _, _)| rule)
+                    .collect(),
+                ..Config::default()
+            },
+        }
+    }
+}
@@ -1,8 +1,17 @@
 // src/report.rs
+use clap::ValueEnum;
+use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::fmt;
-use crate::i18n::{Language, SEVERITY_ERROR, SEVERITY_WARNING, SEVERITY_INFO, NO_ISSUES, SUMMARY, ERRORS, WARNINGS};
+use std::str::FromStr;
+use crate::i18n::{
+    Language, SEVERITY_ERROR, SEVERITY_WARNING, SEVERITY_INFO, NO_ISSUES, SUMMARY, CAT_SYNTAX, CAT_BEST_PRACTICE,
+    CAT_SECURITY, CAT_STYLE,
+};
 
-#[derive(Debug, Clone, PartialEq)]
+/// 問題の重大度
+#[derive(Debug, Clone, PartialEq, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
 pub enum Severity {
     Error,
     Warning,
@@ -10,29 +19,133 @@ pub enum Severity {
 }
 
 impl Severity {
-    pub fn as_str(&self, lang: &Language) -> &str {
+    pub fn label(&self, lang: &Language) -> String {
         match self {
             Severity::Error => SEVERITY_ERROR.get(lang),
             Severity::Warning => SEVERITY_WARNING.get(lang),
             Severity::Info => SEVERITY_INFO.get(lang),
         }
     }
+
+    /// 重大度の大小比較用の順位 (Info < Warning < Error)
+    pub fn rank(&self) -> u8 {
+        match self {
+            Severity::Error => 2,
+            Severity::Warning => 1,
+            Severity::Info => 0,
+        }
+    }
 }
 
 impl fmt::Display for Severity {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.as_str(&Language::English))
+        write!(f, "{}", self.label(&Language::ENGLISH))
+    }
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Severity::Error),
+            "warning" => Ok(Severity::Warning),
+            "info" => Ok(Severity::Info),
+            _ => Err(format!("invalid severity '{}' (expected error, warning or info)", s)),
+        }
+    }
+}
+
+/// ルールの分類
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Category {
+    Syntax,
+    BestPractice,
+    Security,
+    Style,
+}
+
+impl Category {
+    pub fn label(&self, lang: &Language) -> String {
+        match self {
+            Category::Syntax => CAT_SYNTAX.get(lang),
+            Category::BestPractice => CAT_BEST_PRACTICE.get(lang),
+            Category::Security => CAT_SECURITY.get(lang),
+            Category::Style => CAT_STYLE.get(lang),
+        }
+    }
+}
+
+impl FromStr for Category {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "syntax" => Ok(Category::Syntax),
+            "best-practice" => Ok(Category::BestPractice),
+            "security" => Ok(Category::Security),
+            "style" => Ok(Category::Style),
+            _ => Err(format!(
+                "invalid category '{}' (expected syntax, best-practice, security or style)",
+                s
+            )),
+        }
     }
 }
 
+/// 検出された問題
 #[derive(Debug, Clone)]
 pub struct Issue {
+    /// 1 から始まる行番号
     pub line: usize,
+    /// ルールID (`rules` モジュールの定数)
+    pub rule: String,
     pub severity: Severity,
-    pub category: String,
+    pub category: Category,
     pub message: String,
+    pub fix: Option<Fix>,
+}
+
+/// 問題の行を置き換える修正案（置き換え後の内容には改行を含められる）
+#[derive(Debug, Clone)]
+pub struct Fix {
+    pub title: String,
+    pub replacement: String,
+}
+
+/// レポート出力・集計前に適用する問題のフィルタ
+pub struct Filter {
+    /// ルールごとに変更する重大度（絞り込みより前に適用する）
+    pub severities: BTreeMap<String, Severity>,
+    pub min_severity: Severity,
+    pub categories: Vec<Category>,
+    pub exclude_categories: Vec<Category>,
+    pub rules: Vec<String>,
+    pub exclude_rules: Vec<String>,
 }
 
+impl Filter {
+    pub fn matches(&self, issue: &Issue) -> bool {
+        issue.severity.rank() >= self.min_severity.rank()
+            && (self.categories.is_empty() || self.categories.contains(&issue.category))
+            && !self.exclude_categories.contains(&issue.category)
+            && (self.rules.is_empty() || self.rules.contains(&issue.rule))
+            && !self.exclude_rules.contains(&issue.rule)
+    }
+
+    pub fn apply(&self, report: &mut Report) {
+        for issue in report.iter_mut() {
+            if let Some(severity) = self.severities.get(&issue.rule) {
+                issue.severity = severity.clone();
+            }
+        }
+        report.retain(|issue| self.matches(issue));
+    }
+}
+
+/// チェック結果（`sort` するまではルールごとに検出された順に並ぶ）
+#[derive(Clone, Default)]
 pub struct Report {
     issues: Vec<Issue>,
 }
@@ -42,50 +155,122 @@ impl Report {
         Report { issues: Vec::new() }
     }
 
-    pub fn add_issue(&mut self, line: usize, severity: Severity, category: &str, message: &str) {
+    pub fn add_issue(&mut self, line: usize, rule: &str, severity: Severity, category: Category, message: &str) {
         self.issues.push(Issue {
             line,
+            rule: rule.to_string(),
             severity,
-            category: category.to_string(),
+            category,
             message: message.to_string(),
+            fix: None,
         });
     }
 
-    pub fn has_errors(&self) -> bool {
-        self.issues.iter().any(|i| i.severity == Severity::Error)
+    pub fn add_issue_with_fix(
+        &mut self,
+        line: usize,
+        rule: &str,
+        severity: Severity,
+        category: Category,
+        message: &str,
+        fix: Fix,
+    ) {
+        self.issues.push(Issue {
+            line,
+            rule: rule.to_string(),
+            severity,
+            category,
+            message: message.to_string(),
+            fix: Some(fix),
+        });
     }
 
-    pub fn print(&self, errors_only: bool, language: &Language) {
+    /// 行番号とルールIDの順に並べ替える（チェックを実行する順序に出力が左右されないようにする）
+    pub fn sort(&mut self) {
+        self.issues.sort_by(|a, b| (a.line, &a.rule).cmp(&(b.line, &b.rule)));
+    }
+
+    pub fn push(&mut self, issue: Issue) {
+        self.issues.push(issue);
+    }
+
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Issue> {
+        self.issues.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Issue> {
+        self.issues.iter_mut()
+    }
+
+    pub fn len(&self) -> usize {
+        self.issues.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn retain<F: FnMut(&Issue) -> bool>(&mut self, f: F) {
+        self.issues.retain(f);
+    }
+
+    pub fn count(&self, severity: &Severity) -> usize {
+        self.issues.iter().filter(|i| &i.severity == severity).count()
+    }
+
+    pub fn print(&self, language: &Language) {
         if self.issues.is_empty() {
             println!("{}", NO_ISSUES.get(language));
             return;
         }
 
         for issue in &self.issues {
-            if errors_only && issue.severity != Severity::Error {
-                continue;
-            }
-
             println!(
                 "L{}: [{}] {} - {}",
                 issue.line,
-                issue.severity.as_str(language),
-                issue.category,
+                issue.severity.label(language),
+                issue.category.label(language),
                 issue.message
             );
         }
 
-        let error_count = self.issues.iter().filter(|i| i.severity == Severity::Error).count();
-        let warning_count = self.issues.iter().filter(|i| i.severity == Severity::Warning).count();
+        let error_count = self.count(&Severity::Error);
+        let warning_count = self.count(&Severity::Warning);
+        let info_count = self.count(&Severity::Info);
 
         println!();
         println!(
-            "{}: {} {}, {} {}",
-            SUMMARY.get(language),
-            error_count,
-            ERRORS.get(language),
-            warning_count,
-            WARNINGS.get(language)
+            "{}",
+            SUMMARY.format(
+                language,
+                &[
+                    ("errors", error_count.into()),
+                    ("warnings", warning_count.into()),
+                    ("infos", info_count.into()),
+                ]
+            )
         );
     }
-}
\ No newline at end of file
+}
+
+impl<'a> IntoIterator for &'a Report {
+    type Item = &'a Issue;
+    type IntoIter = std::slice::Iter<'a, Issue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.issues.iter()
+    }
+}
+
+impl IntoIterator for Report {
+    type Item = Issue;
+    type IntoIter = std::vec::IntoIter<Issue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.issues.into_iter()
+    }
+}
@@ -0,0 +1,90 @@
+// src/rules.rs
+// ルールID - ベースラインや抑制で使用する安定した識別子
+use crate::report::{Category, Severity};
+
+// Syntax
+pub const MISSING_SHEBANG: &str = "missing-shebang";
+pub const INVALID_SHEBANG: &str = "invalid-shebang";
+pub const UNMATCHED_BRACKET: &str = "unmatched-bracket";
+pub const UNCLOSED_BRACKET: &str = "unclosed-bracket";
+pub const UNCLOSED_BRACE: &str = "unclosed-brace";
+pub const UNCLOSED_PAREN: &str = "unclosed-paren";
+pub const UNCLOSED_SINGLE_QUOTE: &str = "unclosed-single-quote";
+pub const UNCLOSED_DOUBLE_QUOTE: &str = "unclosed-double-quote";
+pub const UNCLOSED_VAR_EXPANSION: &str = "unclosed-var-expansion";
+pub const UNCLOSED_CMD_SUBST: &str = "unclosed-cmd-subst";
+pub const BASHISM: &str = "bashism";
+
+// Best Practice
+pub const MISSING_SET_E: &str = "missing-set-e";
+pub const MISSING_SET_U: &str = "missing-set-u";
+pub const MISSING_PIPEFAIL: &str = "missing-pipefail";
+pub const UNQUOTED_VARIABLE: &str = "unquoted-variable";
+pub const CD_WITHOUT_CHECK: &str = "cd-without-check";
+pub const BACKTICK_SUBSTITUTION: &str = "backtick-substitution";
+
+// Security
+pub const EVAL_USAGE: &str = "eval-usage";
+pub const CURL_PIPE_SHELL: &str = "curl-pipe-shell";
+pub const DANGEROUS_RM: &str = "dangerous-rm";
+pub const USER_INPUT_IN_COMMAND: &str = "user-input-in-command";
+pub const EXPRESSION_INJECTION: &str = "expression-injection";
+
+// Style
+pub const TAB_INDENTATION: &str = "tab-indentation";
+pub const INCONSISTENT_INDENTATION: &str = "inconsistent-indentation";
+pub const LINE_TOO_LONG: &str = "line-too-long";
+pub const FUNCTION_NAMING: &str = "function-naming";
+pub const VARIABLE_NAMING: &str = "variable-naming";
+
+pub const ALL: &[&str] = &[
+    MISSING_SHEBANG,
+    INVALID_SHEBANG,
+    UNMATCHED_BRACKET,
+    UNCLOSED_BRACKET,
+    UNCLOSED_BRACE,
+    UNCLOSED_PAREN,
+    UNCLOSED_SINGLE_QUOTE,
+    UNCLOSED_DOUBLE_QUOTE,
+    UNCLOSED_VAR_EXPANSION,
+    UNCLOSED_CMD_SUBST,
+    BASHISM,
+    MISSING_SET_E,
+    MISSING_SET_U,
+    MISSING_PIPEFAIL,
+    UNQUOTED_VARIABLE,
+    CD_WITHOUT_CHECK,
+    BACKTICK_SUBSTITUTION,
+    EVAL_USAGE,
+    CURL_PIPE_SHELL,
+    DANGEROUS_RM,
+    USER_INPUT_IN_COMMAND,
+    EXPRESSION_INJECTION,
+    TAB_INDENTATION,
+    INCONSISTENT_INDENTATION,
+    LINE_TOO_LONG,
+    FUNCTION_NAMING,
+    VARIABLE_NAMING,
+];
+
+/// ルールの分類と既定の重大度
+pub fn metadata(rule: &str) -> Option<(Category, Severity)> {
+    let metadata = match rule {
+        MISSING_SHEBANG | UNMATCHED_BRACKET | UNCLOSED_BRACKET | UNCLOSED_BRACE | UNCLOSED_PAREN
+        | UNCLOSED_SINGLE_QUOTE | UNCLOSED_DOUBLE_QUOTE | UNCLOSED_VAR_EXPANSION | UNCLOSED_CMD_SUBST => {
+            (Category::Syntax, Severity::Error)
+        }
+        INVALID_SHEBANG | BASHISM => (Category::Syntax, Severity::Warning),
+        MISSING_SET_E | MISSING_SET_U | MISSING_PIPEFAIL | UNQUOTED_VARIABLE | CD_WITHOUT_CHECK => {
+            (Category::BestPractice, Severity::Warning)
+        }
+        BACKTICK_SUBSTITUTION => (Category::BestPractice, Severity::Info),
+        EVAL_USAGE | CURL_PIPE_SHELL | DANGEROUS_RM | EXPRESSION_INJECTION => (Category::Security, Severity::Error),
+        USER_INPUT_IN_COMMAND => (Category::Security, Severity::Warning),
+        TAB_INDENTATION | INCONSISTENT_INDENTATION | LINE_TOO_LONG | FUNCTION_NAMING | VARIABLE_NAMING => {
+            (Category::Style, Severity::Info)
+        }
+        _ => return None,
+    };
+    Some(metadata)
+}
@@ -0,0 +1,131 @@
+// src/runner.rs
+use crate::config::Config;
+use crate::detect::Detection;
+use crate::i18n::Language;
+use crate::report::Report;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// チェック対象の1ファイル分の作業
+pub struct Job {
+    pub path: PathBuf,
+    // 標準入力など、読み込み済みの内容
+    pub content: Option<String>,
+    // ディレクトリ走査で見つかったファイルはスクリプトかどうか判定する
+    pub detect: bool,
+    // ファイルに適用する設定（`[[overrides]]` とコマンドライン引数を反映したもの）
+    pub config: Config,
+}
+
+pub enum Outcome {
+    Skipped,
+    Detected(Detection),
+    Checked { content: String, report: Report },
+    Failed(io::Error),
+}
+
+/// ワーカースレッドでジョブを並列に実行し、結果をジョブと同じ順序で返す
+///
+/// `list_only` の場合はチェックを行わず、判定結果のみを返す。
+pub fn run(jobs: Vec<Job>, threads: usize, language: &Language, list_only: bool) -> Vec<Outcome> {
+    let threads = threads.clamp(1, jobs.len().max(1));
+    let next = AtomicUsize::new(0);
+    let jobs: Vec<Mutex<Option<Job>>> = jobs.into_iter().map(|job| Mutex::new(Some(job))).collect();
+    let results: Vec<Mutex<Option<Outcome>>> = jobs.iter().map(|_| Mutex::new(None)).collect();
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(slot) = jobs.get(idx) else { break };
+                    let job = slot.lock().unwrap().take().unwrap();
+                    let outcome = check_job(job, language, list_only);
+                    *results[idx].lock().unwrap() = Some(outcome);
+                }
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|slot| slot.into_inner().unwrap().unwrap())
+        .collect()
+}
+
+fn check_job(job: Job, language: &Language, list_only: bool) -> Outcome {
+    // ファイルは一度だけ読み込み、判定とチェックの両方に使用する
+    let content = match job.content {
+        Some(content) => content,
+        None => match fs::read(&job.path) {
+            Ok(bytes) => match String::from_utf8(bytes) {
+                Ok(content) => content,
+                // ディレクトリ走査で見つかったバイナリファイルは対象外
+                Err(_) if job.detect => return Outcome::Skipped,
+                Err(e) => return Outcome::Failed(io::Error::new(io::ErrorKind::InvalidData, e)),
+            },
+            Err(e) => return Outcome::Failed(e),
+        },
+    };
+
+    if list_only {
+        let detection = crate::detect_content(&job.path, &content, &job.config, job.detect);
+        // 一覧表示ではシェルと判定されたがチェック対象外のもの (zsh) も表示する
+        return if job.detect && detection.dialect.is_none() {
+            Outcome::Skipped
+        } else {
+            Outcome::Detected(detection)
+        };
+    }
+
+    match crate::check_content(&job.path, &content, language, &job.config, job.detect) {
+        (_, Some(mut report)) => {
+            job.config.filter().apply(&mut report);
+            Outcome::Checked { content, report }
+        }
+        (_, None) => Outcome::Skipped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ZSH: &str = "#!/usr/bin/env zsh\necho $HOME\n";
+
+    fn outcome(path: PathBuf, content: Option<String>, detect: bool) -> Outcome {
+        let job = Job { path, content, detect, config: Config::default() };
+        run(vec![job], 1, &Language::ENGLISH, false).pop().unwrap()
+    }
+
+    #[test]
+    fn explicit_zsh_file_is_skipped() {
+        let dir = std::env::temp_dir().join(format!("shellchecker-runner-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("z.sh");
+        fs::write(&path, ZSH).unwrap();
+        let result = outcome(path, None, false);
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(result, Outcome::Skipped));
+    }
+
+    #[test]
+    fn zsh_from_stdin_is_skipped() {
+        assert!(matches!(outcome(PathBuf::from("z.sh"), Some(ZSH.to_string()), false), Outcome::Skipped));
+        assert!(matches!(outcome(PathBuf::from("<stdin>"), Some(ZSH.to_string()), false), Outcome::Skipped));
+    }
+
+    #[test]
+    fn explicit_file_without_shebang_is_checked_as_bash() {
+        let content = Some("echo $HOME\n".to_string());
+        let Outcome::Checked { report, .. } = outcome(PathBuf::from("<stdin>"), content.clone(), false) else {
+            panic!("not checked");
+        };
+        assert!(report.iter().any(|issue| issue.rule == "unquoted-variable"));
+        assert!(matches!(outcome(PathBuf::from("notes"), content, true), Outcome::Skipped));
+    }
+}
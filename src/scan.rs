@@ -0,0 +1,117 @@
+// src/scan.rs
+use crate::extract;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::WalkBuilder;
+use std::path::{Path, PathBuf};
+
+/// ディレクトリ走査の設定
+pub struct ScanOptions {
+    pub recursive: bool,
+    // .gitignore / .ignore を無視せずに全ファイルを走査する
+    pub no_ignore: bool,
+    pub hidden: bool,
+    pub include: Patterns,
+    pub exclude: Patterns,
+}
+
+/// `--include` / `--exclude` で指定されたグロブパターン
+///
+/// `/` を含まないパターンは任意の階層のファイル名・ディレクトリ名に一致する。
+#[derive(Debug, Clone)]
+pub struct Patterns {
+    anchored: GlobSet,
+    basename: GlobSet,
+    empty: bool,
+}
+
+impl Patterns {
+    pub fn new(patterns: &[String]) -> Result<Self, globset::Error> {
+        let mut anchored = GlobSetBuilder::new();
+        let mut basename = GlobSetBuilder::new();
+        for pattern in patterns {
+            let pattern = pattern.trim_start_matches("./");
+            if pattern.contains('/') {
+                anchored.add(Glob::new(pattern.trim_start_matches('/'))?);
+            } else {
+                basename.add(Glob::new(pattern)?);
+            }
+        }
+
+        Ok(Patterns {
+            anchored: anchored.build()?,
+            basename: basename.build()?,
+            empty: patterns.is_empty(),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.empty
+    }
+
+    /// 走査ルートからの相対パスがパターンに一致するか
+    pub fn matches(&self, relative: &Path) -> bool {
+        self.anchored.is_match(relative)
+            || relative
+                .file_name()
+                .is_some_and(|name| self.basename.is_match(name))
+    }
+}
+
+/// ディレクトリを走査し、チェック候補のファイルをパス順に返す
+pub fn scan(root: &Path, options: &ScanOptions) -> (Vec<PathBuf>, Vec<ignore::Error>) {
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .max_depth(if options.recursive { None } else { Some(1) })
+        // 隠しファイルは下の filter_entry で除外する
+        .hidden(false)
+        .git_ignore(!options.no_ignore)
+        .git_global(!options.no_ignore)
+        .git_exclude(!options.no_ignore)
+        .ignore(!options.no_ignore)
+        .parents(!options.no_ignore)
+        .require_git(false)
+        .sort_by_file_name(|a, b| a.cmp(b));
+
+    // 除外パターンに一致するディレクトリは配下ごと走査しない (.git は常に除外)
+    // 隠しファイルは .github などの埋め込みスクリプトを含むものだけを走査する
+    let exclude_root = root.to_path_buf();
+    let exclude = options.exclude.clone();
+    let hidden = options.hidden;
+    builder.filter_entry(move |entry| {
+        if entry.depth() == 0 {
+            return true;
+        }
+        let is_hidden = entry.file_name().to_string_lossy().starts_with('.');
+        let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+        if is_hidden && !hidden && !extract::wants_hidden(entry.path(), is_dir) {
+            return false;
+        }
+        let relative = entry.path().strip_prefix(&exclude_root).unwrap_or(entry.path());
+        entry.file_name() != ".git" && !exclude.matches(relative)
+    });
+
+    let mut files = Vec::new();
+    let mut errors = Vec::new();
+    for entry in builder.build() {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                errors.push(e);
+                continue;
+            }
+        };
+
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+
+        let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+        if !options.include.is_empty() && !options.include.matches(relative) {
+            continue;
+        }
+
+        files.push(entry.into_path());
+    }
+
+    (files, errors)
+}
@@ -0,0 +1,62 @@
+// src/suppress.rs
+// 抑制コメント
+//   # shellchecker disable=rule-a,rule-b      次の行（行末に書いた場合はその行）で無効化
+//   # shellchecker disable-file=rule-a        ファイル全体で無効化
+//   <!-- shellchecker disable-file=rule-a --> Markdown では HTML コメントでも記述できる
+use crate::parser::ScriptParser;
+use crate::report::Report;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+lazy_static! {
+    static ref DIRECTIVE: Regex =
+        Regex::new(r"(?:#|<!--)\s*shellchecker\s+(disable|disable-file)=([A-Za-z0-9_,-]+)").unwrap();
+}
+
+const ALL_RULES: &str = "all";
+
+/// 抑制コメントで無効化された問題をレポートから取り除く
+pub fn apply(parser: &ScriptParser, report: &mut Report) {
+    let mut file_rules: Vec<String> = Vec::new();
+    let mut line_rules: HashMap<usize, Vec<String>> = HashMap::new();
+
+    for line in parser.lines() {
+        for caps in DIRECTIVE.captures_iter(&line.content) {
+            let rules = caps[2].split(',').map(|r| r.trim().to_string()).filter(|r| !r.is_empty());
+            if &caps[1] == "disable-file" {
+                file_rules.extend(rules);
+            } else {
+                // コメントのみの行は次の行、行末コメントはその行に適用する
+                let comment_only = line.trimmed.starts_with('#') || line.trimmed.starts_with("<!--");
+                let target = if comment_only { line.number + 1 } else { line.number };
+                line_rules.entry(target).or_default().extend(rules);
+            }
+        }
+    }
+
+    if file_rules.is_empty() && line_rules.is_empty() {
+        return;
+    }
+
+    let disabled = |rules: &[String], rule: &str| rules.iter().any(|r| r == rule || r == ALL_RULES);
+    report.retain(|issue| {
+        !disabled(&file_rules, &issue.rule)
+            && !line_rules.get(&issue.line).is_some_and(|rules| disabled(rules, &issue.rule))
+    });
+}
+
+/// 指定した行の直前に挿入する抑制コメント
+pub fn line_directive(rule: &str, indent: &str) -> String {
+    format!("{}# shellchecker disable={}", indent, rule)
+}
+
+/// ファイル全体で無効化する抑制コメント
+pub fn file_directive(rule: &str) -> String {
+    format!("# shellchecker disable-file={}", rule)
+}
+
+/// HTML コメントで書いたファイル全体の抑制コメント（Markdown 用）
+pub fn html_file_directive(rule: &str) -> String {
+    format!("<!-- shellchecker disable-file={} -->", rule)
+}
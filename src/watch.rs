@@ -0,0 +1,89 @@
+// src/watch.rs
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// 保存時に連続して発生するイベントをまとめる待ち時間
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// ファイルシステムの変更を監視する (Linux では inotify を使用)
+pub struct FileWatcher {
+    _watcher: RecommendedWatcher,
+    rx: Receiver<notify::Result<Event>>,
+}
+
+impl FileWatcher {
+    pub fn new(paths: &[PathBuf], recursive: bool) -> notify::Result<Self> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx)?;
+
+        for path in paths {
+            let mode = if path.is_dir() && recursive {
+                RecursiveMode::Recursive
+            } else {
+                RecursiveMode::NonRecursive
+            };
+            watcher.watch(path, mode)?;
+        }
+
+        Ok(FileWatcher { _watcher: watcher, rx })
+    }
+
+    /// 変更があるまで待機し、変更されたパス（絶対パス）を返す
+    pub fn wait(&self) -> BTreeSet<PathBuf> {
+        let mut changed = BTreeSet::new();
+
+        // 最初のイベントを待ち、その後に続くイベントをまとめて受け取る
+        let Ok(first) = self.rx.recv() else { return changed };
+        collect(first, &mut changed);
+        while let Ok(event) = self.rx.recv_timeout(DEBOUNCE) {
+            collect(event, &mut changed);
+        }
+
+        changed
+    }
+}
+
+fn collect(event: notify::Result<Event>, changed: &mut BTreeSet<PathBuf>) {
+    let Ok(event) = event else { return };
+    if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)) {
+        changed.extend(event.paths.iter().map(|p| absolute(p)));
+    }
+}
+
+pub fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+pub fn clear_screen() {
+    print!("\x1b[2J\x1b[H");
+}
+
+/// 現在時刻 (UTC) を `YYYY-MM-DD HH:MM:SS UTC` 形式で返す
+pub fn timestamp() -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, rem) = (secs / 86400, secs % 86400);
+
+    // 1970-01-01 からの日数を年月日に変換する
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}